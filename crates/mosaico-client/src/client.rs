@@ -0,0 +1,136 @@
+use std::time::Duration;
+
+use mosaico_core::ipc::{self, DEFAULT_TIMEOUT, HelloInfo, IpcError, IpcTransport, ResponseStatus};
+use mosaico_core::{Action, Command, DaemonEvent, EventTopic, Response, StateSnapshot};
+
+use crate::error::ClientError;
+use crate::retry::Retrying;
+
+/// The transport [`Client::new`] uses on this platform.
+#[cfg(windows)]
+pub type DefaultTransport = mosaico_windows::ipc::NamedPipeTransport;
+
+/// The transport [`Client::new`] uses on this platform.
+#[cfg(unix)]
+pub type DefaultTransport = ipc::UnixSocketTransport;
+
+/// How many times a connection is retried while no daemon is listening.
+pub const DEFAULT_RETRIES: u32 = 3;
+
+/// Pause between connection attempts.
+pub const DEFAULT_RETRY_DELAY: Duration = Duration::from_millis(50);
+
+/// A connection to the Mosaico daemon.
+///
+/// Each call opens its own connection, sends one request and waits up
+/// to the timeout for the answer. Connecting is retried a few times, so
+/// a call made just as the daemon starts, or while it is between two
+/// clients, still gets through.
+#[derive(Debug, Clone)]
+pub struct Client<T = DefaultTransport> {
+    transport: Retrying<T>,
+    timeout: Duration,
+}
+
+impl Client {
+    /// Creates a client for the daemon on this platform's default endpoint.
+    pub fn new() -> Self {
+        Self::with_transport(DefaultTransport::default())
+    }
+}
+
+impl Default for Client {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: IpcTransport> Client<T> {
+    /// Creates a client that talks to the daemon over `transport`.
+    pub fn with_transport(transport: T) -> Self {
+        Self {
+            transport: Retrying {
+                inner: transport,
+                retries: DEFAULT_RETRIES,
+                delay: DEFAULT_RETRY_DELAY,
+            },
+            timeout: DEFAULT_TIMEOUT,
+        }
+    }
+
+    /// Sets how long to wait for the daemon to accept and to answer.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Sets how many times to retry connecting, and the pause between
+    /// attempts, while no daemon is listening.
+    pub fn retries(mut self, retries: u32, delay: Duration) -> Self {
+        self.transport.retries = retries;
+        self.transport.delay = delay;
+        self
+    }
+
+    /// Returns whether a daemon is listening, without sending anything.
+    pub fn is_running(&self) -> bool {
+        self.transport.is_listening()
+    }
+
+    /// Sends `command` and returns the daemon's response.
+    ///
+    /// An error response becomes [`ClientError::Daemon`].
+    pub fn send(&self, command: &Command) -> Result<Response, ClientError> {
+        let response = ipc::send_command(&self.transport, command, self.timeout)?;
+        match response.status {
+            ResponseStatus::Ok => Ok(response),
+            ResponseStatus::Error => Err(ClientError::from_response(response)),
+        }
+    }
+
+    /// Asks the daemon how it is doing, e.g. how many windows it manages.
+    pub fn status(&self) -> Result<String, ClientError> {
+        let response = self.send(&Command::Status)?;
+        Ok(response.message.unwrap_or_default())
+    }
+
+    /// Asks the daemon for its release, protocol version and capabilities.
+    pub fn hello(&self) -> Result<HelloInfo, ClientError> {
+        Ok(ipc::hello(&self.transport, self.timeout)?)
+    }
+
+    /// Runs `action` in the daemon, returning its message if it sent one.
+    pub fn action(&self, action: Action) -> Result<Option<String>, ClientError> {
+        Ok(self.send(&Command::Action { action })?.message)
+    }
+
+    /// Returns the daemon's monitors, workspaces and windows.
+    pub fn query(&self) -> Result<StateSnapshot, ClientError> {
+        self.send(&Command::Query)?
+            .state
+            .ok_or_else(|| IpcError::Protocol("query response without state".into()).into())
+    }
+
+    /// Asks the daemon to shut down, returning its farewell message.
+    pub fn stop(&self) -> Result<Option<String>, ClientError> {
+        Ok(self.send(&Command::Stop)?.message)
+    }
+
+    /// Subscribes to daemon events and calls `on_event` for each one.
+    ///
+    /// An empty `topics` list subscribes to every topic. Blocks until the
+    /// daemon closes the stream (e.g. on shutdown) or `on_event` returns
+    /// `false`.
+    pub fn subscribe(
+        &self,
+        topics: Vec<EventTopic>,
+        on_event: impl FnMut(DaemonEvent) -> bool,
+    ) -> Result<(), ClientError> {
+        Ok(ipc::subscribe(
+            &self.transport,
+            topics,
+            self.timeout,
+            on_event,
+        )?)
+    }
+}
@@ -0,0 +1,52 @@
+use std::fmt;
+
+use mosaico_core::ipc::{ErrorCode, IpcError, Response};
+
+/// An error from a [`Client`](crate::Client) call.
+#[derive(Debug)]
+pub enum ClientError {
+    /// The request did not reach the daemon, or its answer did not come
+    /// back intact.
+    Ipc(IpcError),
+    /// The daemon answered the request with an error.
+    Daemon {
+        /// Machine-readable reason, if the daemon sent one.
+        code: Option<ErrorCode>,
+        /// The daemon's explanation.
+        message: String,
+    },
+}
+
+impl ClientError {
+    /// Builds the error for a response whose status is `Error`.
+    pub(crate) fn from_response(response: Response) -> Self {
+        Self::Daemon {
+            code: response.code,
+            message: response.message.unwrap_or("unknown error".into()),
+        }
+    }
+}
+
+impl fmt::Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Ipc(e) => e.fmt(f),
+            Self::Daemon { message, .. } => f.write_str(message),
+        }
+    }
+}
+
+impl std::error::Error for ClientError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Ipc(e) => Some(e),
+            Self::Daemon { .. } => None,
+        }
+    }
+}
+
+impl From<IpcError> for ClientError {
+    fn from(e: IpcError) -> Self {
+        Self::Ipc(e)
+    }
+}
@@ -0,0 +1,29 @@
+#![deny(missing_docs)]
+
+//! Typed client for the Mosaico daemon's IPC protocol.
+//!
+//! [`Client`] connects to a running daemon, sends one request per call
+//! and turns the answer into a typed result, so tools that drive Mosaico
+//! do not have to reimplement the pipe framing or the `Command` JSON.
+//!
+//! ```no_run
+//! use mosaico_client::{Action, Client};
+//!
+//! let client = Client::new();
+//! client.action(Action::ToggleMonocle)?;
+//! let state = client.query()?;
+//! println!("{} windows", state.windows().len());
+//! # Ok::<(), mosaico_client::ClientError>(())
+//! ```
+
+/// The client and its connection settings.
+pub mod client;
+/// Errors returned by client calls.
+pub mod error;
+/// Connection retry around an `IpcTransport`.
+mod retry;
+
+pub use client::{Client, DefaultTransport};
+pub use error::ClientError;
+pub use mosaico_core::ipc::{ErrorCode, HelloInfo, IpcError};
+pub use mosaico_core::{Action, DaemonEvent, EventTopic, StateSnapshot};
@@ -0,0 +1,46 @@
+use std::thread;
+use std::time::Duration;
+
+use mosaico_core::ipc::{IpcError, IpcTransport};
+
+/// Wraps a transport so that `connect()` retries while no daemon is
+/// listening.
+///
+/// Only connecting is retried: once a request has been written the
+/// daemon may have acted on it, so a failure after that is final.
+#[derive(Debug, Clone)]
+pub(crate) struct Retrying<T> {
+    pub(crate) inner: T,
+    /// Extra attempts after the first one fails.
+    pub(crate) retries: u32,
+    /// Pause between attempts.
+    pub(crate) delay: Duration,
+}
+
+impl<T: IpcTransport> IpcTransport for Retrying<T> {
+    type Connection = T::Connection;
+    type Listener = T::Listener;
+
+    fn bind(&self) -> Result<Self::Listener, IpcError> {
+        self.inner.bind()
+    }
+
+    /// The named pipe briefly has no instance between two clients, so a
+    /// daemon that is up can still look absent for a moment.
+    fn connect(&self, timeout: Duration) -> Result<Self::Connection, IpcError> {
+        let mut attempt = 0;
+        loop {
+            match self.inner.connect(timeout) {
+                Err(IpcError::NotRunning) if attempt < self.retries => {
+                    attempt += 1;
+                    thread::sleep(self.delay);
+                }
+                result => return result,
+            }
+        }
+    }
+
+    fn is_listening(&self) -> bool {
+        self.inner.is_listening()
+    }
+}
@@ -0,0 +1,288 @@
+//! The typed client against a scripted daemon over a Unix domain socket.
+#![cfg(unix)]
+
+use std::sync::{Arc, Mutex, mpsc};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use mosaico_client::{Action, Client, ClientError, ErrorCode, IpcError, StateSnapshot};
+use mosaico_core::ipc::{
+    self, IpcConnection, IpcListener, IpcRequest, IpcTransport, Response, UnixSocketTransport,
+};
+use mosaico_core::query::MonitorSnapshot;
+use mosaico_core::{Command, DaemonEvent};
+
+fn transport(name: &str) -> UnixSocketTransport {
+    let file = format!("mosaico-client-{}-{name}.sock", std::process::id());
+    UnixSocketTransport::new(std::env::temp_dir().join(file))
+}
+
+/// A Unix socket transport that records the timeout of every connect.
+#[derive(Clone)]
+struct Recording {
+    inner: UnixSocketTransport,
+    connects: Arc<Mutex<Vec<Duration>>>,
+}
+
+impl Recording {
+    fn new(inner: UnixSocketTransport) -> Self {
+        Self {
+            inner,
+            connects: Arc::default(),
+        }
+    }
+
+    fn connects(&self) -> Vec<Duration> {
+        self.connects.lock().unwrap().clone()
+    }
+}
+
+impl IpcTransport for Recording {
+    type Connection = <UnixSocketTransport as IpcTransport>::Connection;
+    type Listener = <UnixSocketTransport as IpcTransport>::Listener;
+
+    fn bind(&self) -> Result<Self::Listener, IpcError> {
+        self.inner.bind()
+    }
+
+    fn connect(&self, timeout: Duration) -> Result<Self::Connection, IpcError> {
+        self.connects.lock().unwrap().push(timeout);
+        self.inner.connect(timeout)
+    }
+
+    fn is_listening(&self) -> bool {
+        self.inner.is_listening()
+    }
+}
+
+/// Accepts one connection, reads its request, then hangs up after
+/// `linger` without answering.
+fn start_mute_daemon(transport: &UnixSocketTransport, linger: Duration) -> thread::JoinHandle<()> {
+    let mut listener = transport.bind().unwrap();
+    thread::spawn(move || {
+        let mut conn = listener.accept().unwrap();
+        conn.read_line().unwrap();
+        thread::sleep(linger);
+    })
+}
+
+fn state() -> StateSnapshot {
+    StateSnapshot {
+        monitors: vec![MonitorSnapshot {
+            index: 0,
+            id: 7,
+            name: r"\\.\DISPLAY1".into(),
+            active_workspace: 2,
+            ..Default::default()
+        }],
+        focused_monitor: 0,
+        focused_window: Some(42),
+    }
+}
+
+/// Serves requests on its own thread until `Stop`, answering commands
+/// with `answer` and sending each subscriber a single event.
+fn start_daemon(
+    transport: &UnixSocketTransport,
+    answer: fn(&Command) -> Response,
+) -> thread::JoinHandle<()> {
+    let mut listener = transport.bind().unwrap();
+    thread::spawn(move || {
+        let (tx, rx) = mpsc::channel();
+        let server = thread::spawn(move || ipc::serve(&mut listener, |r| tx.send(r).is_ok()));
+        for request in rx {
+            match request {
+                IpcRequest::Subscribe(_, events) => {
+                    let _ = events.send(DaemonEvent::Paused { paused: true });
+                }
+                IpcRequest::Command(command, reply) => {
+                    let _ = reply.send(answer(&command));
+                }
+            }
+        }
+        server.join().unwrap().unwrap();
+    })
+}
+
+fn answer_ok(command: &Command) -> Response {
+    match command {
+        Command::Status => Response::ok_with_message("Daemon is running, managing 2 windows"),
+        Command::Action { .. } => Response::ok_with_message("Retiled 2 windows"),
+        Command::Query => Response::ok_with_state(state()),
+        Command::Stop => Response::ok_with_message("Daemon stopping"),
+        _ => Response::ok(),
+    }
+}
+
+fn answer_failed(command: &Command) -> Response {
+    match command {
+        Command::Stop => Response::ok(),
+        _ => Response::error(ErrorCode::Failed, "no window to move"),
+    }
+}
+
+#[test]
+fn typed_calls_return_the_daemon_answers() {
+    // Arrange
+    let transport = transport("typed");
+    let daemon = start_daemon(&transport, answer_ok);
+    let client = Client::with_transport(transport);
+
+    // Act
+    let status = client.status().unwrap();
+    let hello = client.hello().unwrap();
+    let action = client.action(Action::Retile).unwrap();
+    let state = client.query().unwrap();
+    let stop = client.stop().unwrap();
+    daemon.join().unwrap();
+
+    // Assert
+    assert_eq!(status, "Daemon is running, managing 2 windows");
+    assert!(hello.supports("Action"));
+    assert_eq!(action.as_deref(), Some("Retiled 2 windows"));
+    assert_eq!(state, self::state());
+    assert_eq!(stop.as_deref(), Some("Daemon stopping"));
+}
+
+#[test]
+fn subscribe_streams_events_until_the_callback_stops() {
+    // Arrange
+    let transport = transport("subscribe");
+    let daemon = start_daemon(&transport, answer_ok);
+    let client = Client::with_transport(transport);
+    let mut events = Vec::new();
+
+    // Act
+    client
+        .subscribe(Vec::new(), |event| {
+            events.push(event);
+            false
+        })
+        .unwrap();
+    client.stop().unwrap();
+    daemon.join().unwrap();
+
+    // Assert
+    assert_eq!(events, vec![DaemonEvent::Paused { paused: true }]);
+}
+
+#[test]
+fn error_responses_become_daemon_errors() {
+    // Arrange
+    let transport = transport("failed");
+    let daemon = start_daemon(&transport, answer_failed);
+    let client = Client::with_transport(transport);
+
+    // Act
+    let result = client.action(Action::Retile);
+    client.stop().unwrap();
+    daemon.join().unwrap();
+
+    // Assert
+    let Err(ClientError::Daemon { code, message }) = result else {
+        panic!("expected a daemon error, got {result:?}");
+    };
+    assert_eq!(code, Some(ErrorCode::Failed));
+    assert_eq!(message, "no window to move");
+}
+
+#[test]
+fn connecting_is_retried_until_the_daemon_listens() {
+    // Arrange
+    let transport = transport("retry");
+    let client = Client::with_transport(transport.clone()).retries(50, Duration::from_millis(20));
+    let late = thread::spawn(move || {
+        thread::sleep(Duration::from_millis(100));
+        start_daemon(&transport, answer_ok).join().unwrap();
+    });
+
+    // Act
+    let status = client.status();
+    client.stop().unwrap();
+    late.join().unwrap();
+
+    // Assert
+    assert!(status.is_ok(), "{status:?}");
+}
+
+#[test]
+fn missing_daemon_fails_once_retries_run_out() {
+    let client = Client::with_transport(transport("missing")).retries(2, Duration::ZERO);
+
+    let result = client.status();
+
+    assert!(matches!(
+        result,
+        Err(ClientError::Ipc(IpcError::NotRunning))
+    ));
+    assert!(!client.is_running());
+}
+
+#[test]
+fn not_running_is_retried_the_configured_number_of_times() {
+    // Arrange
+    let transport = Recording::new(transport("give-up"));
+    let client = Client::with_transport(transport.clone()).retries(3, Duration::ZERO);
+
+    // Act
+    let result = client.query();
+
+    // Assert
+    assert!(matches!(
+        result,
+        Err(ClientError::Ipc(IpcError::NotRunning))
+    ));
+    assert_eq!(transport.connects().len(), 4);
+}
+
+#[test]
+fn timeout_applies_to_every_connect() {
+    // Arrange
+    let transport = Recording::new(transport("timeout"));
+    let timeout = Duration::from_millis(250);
+    let client = Client::with_transport(transport.clone())
+        .timeout(timeout)
+        .retries(2, Duration::ZERO);
+
+    // Act
+    let _ = client.status();
+
+    // Assert
+    assert_eq!(transport.connects(), vec![timeout; 3]);
+}
+
+#[test]
+fn silent_daemon_times_out() {
+    // Arrange
+    let transport = transport("silent");
+    let daemon = start_mute_daemon(&transport, Duration::from_millis(500));
+    let client = Client::with_transport(transport).timeout(Duration::from_millis(100));
+    let start = Instant::now();
+
+    // Act
+    let result = client.status();
+
+    // Assert
+    assert!(matches!(result, Err(ClientError::Ipc(IpcError::Timeout))));
+    assert!(start.elapsed() < Duration::from_millis(500));
+    daemon.join().unwrap();
+}
+
+#[test]
+fn failure_after_the_request_was_written_is_not_retried() {
+    // Arrange
+    let recording = Recording::new(transport("hang-up"));
+    let daemon = start_mute_daemon(&recording.inner, Duration::ZERO);
+    let client = Client::with_transport(recording.clone()).retries(5, Duration::ZERO);
+
+    // Act
+    let result = client.action(Action::Retile);
+    daemon.join().unwrap();
+
+    // Assert
+    assert!(matches!(
+        result,
+        Err(ClientError::Ipc(IpcError::NoResponse))
+    ));
+    assert_eq!(recording.connects().len(), 1);
+}
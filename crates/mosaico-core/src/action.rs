@@ -3,8 +3,10 @@ use std::str::FromStr;
 
 use serde::{Deserialize, Serialize};
 
+use crate::LayoutKind;
+
 /// Spatial direction for focus and move actions.
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Direction {
     /// Left.
     Left,
@@ -41,38 +43,207 @@ impl fmt::Display for Direction {
     }
 }
 
+/// A workspace named by an action: its number or its configured name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WorkspaceRef {
+    /// 1-based workspace number.
+    Number(u8),
+    /// A name from `[workspaces] names`.
+    Name(String),
+}
+
+/// Words that `goto-workspace-` reserves for relative navigation.
+const RESERVED_WORKSPACE_NAMES: [&str; 3] = ["next", "prev", "last"];
+
+impl WorkspaceRef {
+    /// Returns whether `s` can be used as a workspace name in actions:
+    /// a leading letter followed by letters, digits, `-` or `_`, and not
+    /// one of the reserved words `next`, `prev` or `last`.
+    pub fn is_valid_name(s: &str) -> bool {
+        !RESERVED_WORKSPACE_NAMES.contains(&s)
+            && s.starts_with(|c: char| c.is_alphabetic())
+            && s.chars()
+                .all(|c| c.is_alphanumeric() || c == '-' || c == '_')
+    }
+}
+
+impl From<u8> for WorkspaceRef {
+    fn from(n: u8) -> Self {
+        WorkspaceRef::Number(n)
+    }
+}
+
+impl FromStr for WorkspaceRef {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if !s.is_empty() && s.chars().all(|c| c.is_ascii_digit()) {
+            return parse_workspace_num(s).map(WorkspaceRef::Number);
+        }
+        if WorkspaceRef::is_valid_name(s) {
+            Ok(WorkspaceRef::Name(s.to_string()))
+        } else {
+            Err(format!("invalid workspace: {s:?}"))
+        }
+    }
+}
+
+impl fmt::Display for WorkspaceRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WorkspaceRef::Number(n) => write!(f, "{n}"),
+            WorkspaceRef::Name(name) => write!(f, "{name}"),
+        }
+    }
+}
+
+/// Serializes as an integer for numbers and a string for names, so that
+/// config files can write `workspace = 2` or `workspace = "web"`.
+impl Serialize for WorkspaceRef {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            WorkspaceRef::Number(n) => serializer.serialize_u8(*n),
+            WorkspaceRef::Name(name) => serializer.serialize_str(name),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for WorkspaceRef {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Raw {
+            Number(i64),
+            Name(String),
+        }
+        let s = match Raw::deserialize(deserializer)? {
+            Raw::Number(n) => n.to_string(),
+            Raw::Name(s) => s,
+        };
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// A monitor named by an action: a direction from the focused monitor,
+/// or a 1-based monitor number counted left to right.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MonitorTarget {
+    /// The nearest monitor in this direction.
+    Direction(Direction),
+    /// 1-based monitor number.
+    Number(u8),
+}
+
+impl FromStr for MonitorTarget {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Ok(dir) = s.parse() {
+            return Ok(MonitorTarget::Direction(dir));
+        }
+        match s.parse::<u8>() {
+            Ok(n) if n >= 1 => Ok(MonitorTarget::Number(n)),
+            _ => Err(format!("invalid monitor: {s:?}")),
+        }
+    }
+}
+
+impl fmt::Display for MonitorTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MonitorTarget::Direction(dir) => write!(f, "{dir}"),
+            MonitorTarget::Number(n) => write!(f, "{n}"),
+        }
+    }
+}
+
 /// An action that can be triggered by a hotkey or CLI command.
 ///
 /// Focus and Move each take a spatial [`Direction`], keeping the
 /// direction logic in one place instead of duplicating it across
 /// separate Next/Prev variants.
-#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(try_from = "String", into = "String")]
 pub enum Action {
     /// Move focus in the given direction.
     ///
-    /// Left/Right: spatial horizontal neighbor, overflows to adjacent monitor.
-    /// Up/Down: spatial vertical neighbor, stops at boundary.
+    /// Picks the spatial neighbor on the same monitor, overflowing to the
+    /// adjacent monitor in that direction (see [`crate::topology`]).
     Focus(Direction),
     /// Move (swap) the focused window in the given direction.
     ///
-    /// Left/Right: spatial horizontal swap, overflows to adjacent monitor.
-    /// Up/Down: spatial vertical swap, stops at boundary.
+    /// Swaps with the spatial neighbor on the same monitor, overflowing
+    /// to the adjacent monitor in that direction.
     Move(Direction),
+    /// Move the divider next to the focused window in the given direction.
+    ///
+    /// BSP: shifts the nearest split of matching orientation (Left/Right
+    /// for side-by-side, Up/Down for stacked) by `layout.resize_step`.
+    /// VerticalStack / ThreeColumn: Right grows and Left shrinks the
+    /// master pane; Up/Down do nothing.
+    /// HorizontalStack: Down grows and Up shrinks the master pane;
+    /// Left/Right do nothing.
+    /// Grid, Columns, Rows and custom layouts ignore resizing.
+    ///
+    /// The ratio sizes the whole master pane, however many windows
+    /// [`Action::IncreaseMaster`] / [`Action::DecreaseMaster`] put in it.
+    Resize(Direction),
     /// Re-apply the current layout to all managed windows.
     Retile,
     /// Toggle monocle mode (focused window fills the monitor).
     ToggleMonocle,
+    /// Toggle the focused window between tiled and floating.
+    ToggleFloat,
     /// Close the currently focused window.
     CloseFocused,
-    /// Switch to workspace N (1-8) on the focused monitor.
-    GoToWorkspace(u8),
-    /// Send the focused window to workspace N (1-8) on the same monitor.
-    SendToWorkspace(u8),
+    /// Switch to a workspace on the focused monitor, by number or name.
+    ///
+    /// With `[workspaces] back_and_forth`, naming the visible workspace
+    /// switches to the previously visible one instead.
+    GoToWorkspace(WorkspaceRef),
+    /// Switch to the next workspace on the focused monitor, wrapping
+    /// around. Skips empty workspaces with `[workspaces] skip_empty`.
+    GoToWorkspaceNext,
+    /// Switch to the previous workspace on the focused monitor, wrapping
+    /// around. Skips empty workspaces with `[workspaces] skip_empty`.
+    GoToWorkspacePrev,
+    /// Switch back to the previously visible workspace on the focused
+    /// monitor.
+    GoToWorkspaceLast,
+    /// Send the focused window to a workspace on the same monitor, by
+    /// number or name.
+    SendToWorkspace(WorkspaceRef),
+    /// Move the focused monitor's visible workspace (windows, layout and
+    /// monocle state) to another monitor, where it becomes visible.
+    MoveWorkspaceToMonitor(MonitorTarget),
+    /// Swap the visible workspaces of the focused monitor and its
+    /// neighbor in the given direction.
+    SwapWorkspacesWithMonitor(Direction),
+    /// Send the focused window straight to another monitor.
+    SendToMonitor {
+        /// Destination monitor.
+        monitor: MonitorTarget,
+        /// Workspace on that monitor; its visible one when `None`.
+        workspace: Option<WorkspaceRef>,
+        /// Whether focus goes along with the window.
+        follow: bool,
+    },
     /// Minimize the currently focused window.
     MinimizeFocused,
     /// Cycle to the next layout on the focused workspace.
     CycleLayout,
+    /// Cycle to the previous layout on the focused workspace.
+    CycleLayoutPrev,
+    /// Switch the focused workspace straight to the given layout.
+    SetLayout(LayoutKind),
+    /// Add one window to the master pane of the focused workspace.
+    IncreaseMaster,
+    /// Remove one window from the master pane of the focused workspace.
+    DecreaseMaster,
+    /// Swap the focused window into the master slot.
+    ///
+    /// The master itself trades places with the next window.
+    PromoteToMaster,
     /// Toggle hotkey pause: unregister all hotkeys (paused) or re-register them (unpaused).
     TogglePause,
 }
@@ -81,24 +252,65 @@ impl FromStr for Action {
     type Err = String;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // Checked before "move-", which would claim them.
+        if let Some(target) = s.strip_prefix("move-workspace-to-monitor-") {
+            return Ok(Action::MoveWorkspaceToMonitor(target.parse()?));
+        }
+        if let Some(dir) = s.strip_prefix("swap-workspaces-with-monitor-") {
+            return Ok(Action::SwapWorkspacesWithMonitor(dir.parse()?));
+        }
+        let send = s
+            .strip_prefix("send-to-monitor-")
+            .map(|rest| (rest, false))
+            .or_else(|| {
+                s.strip_prefix("send-and-follow-to-monitor-")
+                    .map(|rest| (rest, true))
+            });
+        if let Some((rest, follow)) = send {
+            let (monitor, workspace) = match rest.split_once("-workspace-") {
+                Some((monitor, ws)) => (monitor, Some(ws.parse()?)),
+                None => (rest, None),
+            };
+            return Ok(Action::SendToMonitor {
+                monitor: monitor.parse()?,
+                workspace,
+                follow,
+            });
+        }
         if let Some(dir) = s.strip_prefix("focus-") {
             return Ok(Action::Focus(dir.parse()?));
         }
         if let Some(dir) = s.strip_prefix("move-") {
             return Ok(Action::Move(dir.parse()?));
         }
-        if let Some(n) = s.strip_prefix("goto-workspace-") {
-            return Ok(Action::GoToWorkspace(parse_workspace_num(n)?));
+        if let Some(dir) = s.strip_prefix("resize-") {
+            return Ok(Action::Resize(dir.parse()?));
+        }
+        if let Some(ws) = s.strip_prefix("goto-workspace-") {
+            return match ws {
+                "next" => Ok(Action::GoToWorkspaceNext),
+                "prev" => Ok(Action::GoToWorkspacePrev),
+                "last" => Ok(Action::GoToWorkspaceLast),
+                _ => Ok(Action::GoToWorkspace(ws.parse()?)),
+            };
         }
-        if let Some(n) = s.strip_prefix("send-to-workspace-") {
-            return Ok(Action::SendToWorkspace(parse_workspace_num(n)?));
+        if let Some(ws) = s.strip_prefix("send-to-workspace-") {
+            return Ok(Action::SendToWorkspace(ws.parse()?));
+        }
+        if let Some(kind) = s.strip_prefix("set-layout-") {
+            return Ok(Action::SetLayout(kind.parse()?));
         }
         match s {
             "retile" => Ok(Action::Retile),
             "toggle-monocle" => Ok(Action::ToggleMonocle),
+            "toggle-float" => Ok(Action::ToggleFloat),
             "close-focused" => Ok(Action::CloseFocused),
             "minimize-focused" => Ok(Action::MinimizeFocused),
             "cycle-layout" => Ok(Action::CycleLayout),
+            "cycle-layout-prev" => Ok(Action::CycleLayoutPrev),
+            "increase-master" => Ok(Action::IncreaseMaster),
+            "decrease-master" => Ok(Action::DecreaseMaster),
+            "promote-to-master" => Ok(Action::PromoteToMaster),
             "toggle-pause" => Ok(Action::TogglePause),
             _ => Err(format!("unknown action: {s}")),
         }
@@ -110,20 +322,54 @@ impl fmt::Display for Action {
         match self {
             Action::Focus(dir) => write!(f, "focus-{dir}"),
             Action::Move(dir) => write!(f, "move-{dir}"),
+            Action::Resize(dir) => write!(f, "resize-{dir}"),
             Action::Retile => write!(f, "retile"),
             Action::ToggleMonocle => write!(f, "toggle-monocle"),
+            Action::ToggleFloat => write!(f, "toggle-float"),
             Action::CloseFocused => write!(f, "close-focused"),
             Action::MinimizeFocused => write!(f, "minimize-focused"),
             Action::CycleLayout => write!(f, "cycle-layout"),
+            Action::CycleLayoutPrev => write!(f, "cycle-layout-prev"),
+            Action::SetLayout(kind) => write!(f, "set-layout-{kind}"),
+            Action::IncreaseMaster => write!(f, "increase-master"),
+            Action::DecreaseMaster => write!(f, "decrease-master"),
+            Action::PromoteToMaster => write!(f, "promote-to-master"),
             Action::TogglePause => write!(f, "toggle-pause"),
-            Action::GoToWorkspace(n) => write!(f, "goto-workspace-{n}"),
-            Action::SendToWorkspace(n) => write!(f, "send-to-workspace-{n}"),
+            Action::GoToWorkspace(ws) => write!(f, "goto-workspace-{ws}"),
+            Action::GoToWorkspaceNext => write!(f, "goto-workspace-next"),
+            Action::GoToWorkspacePrev => write!(f, "goto-workspace-prev"),
+            Action::GoToWorkspaceLast => write!(f, "goto-workspace-last"),
+            Action::SendToWorkspace(ws) => write!(f, "send-to-workspace-{ws}"),
+            Action::MoveWorkspaceToMonitor(target) => {
+                write!(f, "move-workspace-to-monitor-{target}")
+            }
+            Action::SwapWorkspacesWithMonitor(dir) => {
+                write!(f, "swap-workspaces-with-monitor-{dir}")
+            }
+            Action::SendToMonitor {
+                monitor,
+                workspace,
+                follow,
+            } => {
+                let verb = if *follow { "send-and-follow" } else { "send" };
+                write!(f, "{verb}-to-monitor-{monitor}")?;
+                match workspace {
+                    Some(ws) => write!(f, "-workspace-{ws}"),
+                    None => Ok(()),
+                }
+            }
         }
     }
 }
 
 /// Maximum number of workspaces per monitor.
-pub const MAX_WORKSPACES: u8 = 8;
+///
+/// Only bounded by the `u8` workspace numbers used in actions and config
+/// keys; internally workspaces are indexed by `usize`.
+pub const MAX_WORKSPACES: u8 = u8::MAX;
+
+/// Workspaces per monitor when `[workspaces] count` is not set.
+pub const DEFAULT_WORKSPACES: u8 = 8;
 
 fn parse_workspace_num(s: &str) -> Result<u8, String> {
     let n: u8 = s
@@ -165,15 +411,45 @@ mod tests {
             Action::Move(Direction::Right),
             Action::Move(Direction::Up),
             Action::Move(Direction::Down),
+            Action::Resize(Direction::Left),
+            Action::Resize(Direction::Right),
+            Action::Resize(Direction::Up),
+            Action::Resize(Direction::Down),
             Action::Retile,
             Action::ToggleMonocle,
+            Action::ToggleFloat,
             Action::CloseFocused,
             Action::MinimizeFocused,
-            Action::GoToWorkspace(1),
-            Action::GoToWorkspace(8),
-            Action::SendToWorkspace(1),
-            Action::SendToWorkspace(8),
+            Action::GoToWorkspace(1.into()),
+            Action::GoToWorkspace(16.into()),
+            Action::GoToWorkspace(WorkspaceRef::Name("web".into())),
+            Action::SendToWorkspace(1.into()),
+            Action::SendToWorkspace(16.into()),
+            Action::SendToWorkspace(WorkspaceRef::Name("chat".into())),
+            Action::GoToWorkspaceNext,
+            Action::GoToWorkspacePrev,
+            Action::GoToWorkspaceLast,
+            Action::MoveWorkspaceToMonitor(MonitorTarget::Direction(Direction::Right)),
+            Action::MoveWorkspaceToMonitor(MonitorTarget::Number(2)),
+            Action::SwapWorkspacesWithMonitor(Direction::Left),
+            Action::SendToMonitor {
+                monitor: MonitorTarget::Number(3),
+                workspace: None,
+                follow: false,
+            },
+            Action::SendToMonitor {
+                monitor: MonitorTarget::Direction(Direction::Right),
+                workspace: Some(WorkspaceRef::Name("chat-logs".into())),
+                follow: true,
+            },
             Action::CycleLayout,
+            Action::CycleLayoutPrev,
+            Action::SetLayout(LayoutKind::Bsp),
+            Action::SetLayout(LayoutKind::ThreeColumn),
+            Action::SetLayout(LayoutKind::Rows),
+            Action::IncreaseMaster,
+            Action::DecreaseMaster,
+            Action::PromoteToMaster,
             Action::TogglePause,
         ];
         for action in &actions {
@@ -204,11 +480,70 @@ mod tests {
         assert_eq!(parsed, action);
     }
 
+    #[test]
+    fn invalid_layout_name_returns_error() {
+        let result: Result<Action, _> = "set-layout-Spiral!".parse();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn set_layout_parses_custom_name() {
+        let result: Action = "set-layout-spiral".parse().unwrap();
+        assert_eq!(
+            result,
+            Action::SetLayout(LayoutKind::Custom("spiral".into()))
+        );
+    }
+
     #[test]
     fn workspace_out_of_range() {
         assert!("goto-workspace-0".parse::<Action>().is_err());
-        assert!("goto-workspace-9".parse::<Action>().is_err());
+        assert!("goto-workspace-256".parse::<Action>().is_err());
         assert!("send-to-workspace-0".parse::<Action>().is_err());
-        assert!("send-to-workspace-9".parse::<Action>().is_err());
+        assert!("send-to-workspace-256".parse::<Action>().is_err());
+    }
+
+    #[test]
+    fn workspace_names_parse() {
+        assert_eq!(
+            "goto-workspace-web".parse::<Action>(),
+            Ok(Action::GoToWorkspace(WorkspaceRef::Name("web".into())))
+        );
+        assert!("goto-workspace-".parse::<Action>().is_err());
+        assert!("goto-workspace-2web".parse::<Action>().is_err());
+        assert!("send-to-workspace-my web".parse::<Action>().is_err());
+    }
+
+    #[test]
+    fn monitor_targets_parse() {
+        assert_eq!(
+            "move-workspace-to-monitor-3".parse::<Action>(),
+            Ok(Action::MoveWorkspaceToMonitor(MonitorTarget::Number(3)))
+        );
+        assert!("move-workspace-to-monitor-0".parse::<Action>().is_err());
+        assert!("swap-workspaces-with-monitor-2".parse::<Action>().is_err());
+        assert_eq!(
+            "move-left".parse::<Action>(),
+            Ok(Action::Move(Direction::Left))
+        );
+        assert_eq!(
+            "send-to-monitor-2-workspace-4".parse::<Action>(),
+            Ok(Action::SendToMonitor {
+                monitor: MonitorTarget::Number(2),
+                workspace: Some(4.into()),
+                follow: false,
+            })
+        );
+        assert!("send-to-monitor-2-workspace-".parse::<Action>().is_err());
+    }
+
+    #[test]
+    fn relative_workspace_words_are_reserved() {
+        assert_eq!(
+            "goto-workspace-next".parse::<Action>(),
+            Ok(Action::GoToWorkspaceNext)
+        );
+        assert!("send-to-workspace-next".parse::<Action>().is_err());
+        assert!(!WorkspaceRef::is_valid_name("last"));
     }
 }
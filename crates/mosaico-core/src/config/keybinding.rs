@@ -32,11 +32,13 @@ pub enum Modifier {
 ///
 /// Focus: Alt + H/J/K/L (left/down/up/right)
 /// Move/Swap: Alt + Shift + H/J/K/L
+/// Resize: Alt + Ctrl + H/J/K/L
 /// Workspaces: Alt + 1..8 (switch), Alt + Shift + 1..8 (send)
 /// Monocle: Alt + T
+/// Float: Alt + Shift + F
 /// Retile: Alt + Shift + R
 pub fn defaults() -> Vec<Keybinding> {
-    use Modifier::{Alt, Shift};
+    use Modifier::{Alt, Ctrl, Shift};
 
     let mut bindings = vec![
         // Focus: spatial navigation
@@ -49,22 +51,33 @@ pub fn defaults() -> Vec<Keybinding> {
         bind(Action::Move(Direction::Up), "K", &[Alt, Shift]),
         bind(Action::Move(Direction::Right), "L", &[Alt, Shift]),
         bind(Action::Move(Direction::Left), "H", &[Alt, Shift]),
+        // Resize: move the divider next to the focused window
+        bind(Action::Resize(Direction::Down), "J", &[Alt, Ctrl]),
+        bind(Action::Resize(Direction::Up), "K", &[Alt, Ctrl]),
+        bind(Action::Resize(Direction::Right), "L", &[Alt, Ctrl]),
+        bind(Action::Resize(Direction::Left), "H", &[Alt, Ctrl]),
         // Layout
         bind(Action::Retile, "R", &[Alt, Shift]),
         bind(Action::ToggleMonocle, "T", &[Alt]),
+        bind(Action::ToggleFloat, "F", &[Alt, Shift]),
         // Close window
         bind(Action::CloseFocused, "Q", &[Alt]),
         // Minimize window
         bind(Action::MinimizeFocused, "M", &[Alt]),
         // Cycle layout
         bind(Action::CycleLayout, "N", &[Alt]),
+        bind(Action::CycleLayoutPrev, "N", &[Alt, Shift]),
+        // Master pane
+        bind(Action::IncreaseMaster, "Comma", &[Alt]),
+        bind(Action::DecreaseMaster, "Period", &[Alt]),
+        bind(Action::PromoteToMaster, "Enter", &[Alt, Shift]),
     ];
 
     // Workspaces: Alt+1..8 to switch, Alt+Shift+1..8 to send
     for n in 1..=8u8 {
         let key = n.to_string();
-        bindings.push(bind(Action::GoToWorkspace(n), &key, &[Alt]));
-        bindings.push(bind(Action::SendToWorkspace(n), &key, &[Alt, Shift]));
+        bindings.push(bind(Action::GoToWorkspace(n.into()), &key, &[Alt]));
+        bindings.push(bind(Action::SendToWorkspace(n.into()), &key, &[Alt, Shift]));
     }
 
     bindings
@@ -40,6 +40,27 @@ action = "move-left"
 key = "H"
 modifiers = ["alt", "shift"]
 
+# Resize: Alt + Ctrl + H/J/K/L (move the divider next to the focused window)
+[[keybinding]]
+action = "resize-down"
+key = "J"
+modifiers = ["alt", "ctrl"]
+
+[[keybinding]]
+action = "resize-up"
+key = "K"
+modifiers = ["alt", "ctrl"]
+
+[[keybinding]]
+action = "resize-right"
+key = "L"
+modifiers = ["alt", "ctrl"]
+
+[[keybinding]]
+action = "resize-left"
+key = "H"
+modifiers = ["alt", "ctrl"]
+
 # Re-apply layout: Alt + Shift + R
 [[keybinding]]
 action = "retile"
@@ -52,6 +73,12 @@ action = "toggle-monocle"
 key = "T"
 modifiers = ["alt"]
 
+# Toggle floating for the focused window: Alt + Shift + F
+[[keybinding]]
+action = "toggle-float"
+key = "F"
+modifiers = ["alt", "shift"]
+
 # Close focused window: Alt + Q
 [[keybinding]]
 action = "close-focused"
@@ -69,5 +96,29 @@ modifiers = ["alt"]
 action = "cycle-layout"
 key = "N"
 modifiers = ["alt"]
+
+# Cycle layout backwards: Alt + Shift + N
+[[keybinding]]
+action = "cycle-layout-prev"
+key = "N"
+modifiers = ["alt", "shift"]
+
+# One more window in the master pane: Alt + Comma
+[[keybinding]]
+action = "increase-master"
+key = "Comma"
+modifiers = ["alt"]
+
+# One fewer window in the master pane: Alt + Period
+[[keybinding]]
+action = "decrease-master"
+key = "Period"
+modifiers = ["alt"]
+
+# Swap the focused window into the master slot: Alt + Shift + Enter
+[[keybinding]]
+action = "promote-to-master"
+key = "Enter"
+modifiers = ["alt", "shift"]
 "##
 }
@@ -7,9 +7,14 @@ pub(super) fn header() -> &'static str {
 # Available actions:
 #   focus-left, focus-right, focus-up, focus-down,
 #   move-left, move-right, move-up, move-down,
+#   resize-left, resize-right, resize-up, resize-down,
 #   goto-workspace-1 .. goto-workspace-8,
 #   send-to-workspace-1 .. send-to-workspace-8,
-#   retile, toggle-monocle, close-focused, minimize-focused
+#   retile, toggle-monocle, toggle-float, close-focused, minimize-focused,
+#   cycle-layout, cycle-layout-prev,
+#   increase-master, decrease-master, promote-to-master,
+#   set-layout-<layout> (bsp, vertical-stack, horizontal-stack,
+#     three-column, grid, columns, rows)
 #
 # Available modifiers: alt, shift, ctrl, win
 #
@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+
+use crate::layout::LayoutDefinition;
+
+/// Wrapper for deserializing the layouts file.
+///
+/// The file contains a top-level `[[layout]]` array of tables.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(crate) struct LayoutsFile {
+    #[serde(default)]
+    pub(crate) layout: Vec<LayoutDefinition>,
+}
+
+/// Parses and validates a TOML string as a layouts file.
+///
+/// Every definition is checked with [`LayoutDefinition::validate`] and
+/// names must be unique.
+///
+/// # Errors
+///
+/// Returns `Err` if the TOML content is malformed, does not match the
+/// layouts schema, or any definition is invalid.
+pub fn validate_layouts(content: &str) -> Result<Vec<LayoutDefinition>, String> {
+    let file: LayoutsFile = toml::from_str(content).map_err(|e| e.to_string())?;
+    for (i, def) in file.layout.iter().enumerate() {
+        def.validate()?;
+        if file.layout[..i].iter().any(|d| d.name == def.name) {
+            return Err(format!("layout {:?} is defined twice", def.name));
+        }
+    }
+    Ok(file.layout)
+}
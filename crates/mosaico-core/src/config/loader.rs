@@ -2,8 +2,11 @@ use std::path::PathBuf;
 
 use super::bar::BarConfig;
 use super::keybinding;
+use super::layouts::validate_layouts;
+use super::rule_explain::{RuleSource, SourcedRule, sourced_rules};
 use super::rules::{KeybindingsFile, RulesFile, UserRulesFile};
 use super::{Config, Keybinding, WindowRule, default_rules};
+use crate::layout::LayoutDefinition;
 
 /// Returns the config directory: `~/.config/mosaico/`.
 pub fn config_dir() -> Option<PathBuf> {
@@ -335,6 +338,14 @@ pub fn load_merged_rules() -> Vec<WindowRule> {
     rules
 }
 
+/// Loads both rule sets in the order of [`load_merged_rules`], tagging
+/// each rule with its source file and position.
+pub fn load_sourced_rules() -> Vec<SourcedRule> {
+    let mut rules = sourced_rules(RuleSource::User, load_user_rules());
+    rules.extend(sourced_rules(RuleSource::Community, load_rules()));
+    rules
+}
+
 /// Tries to load and parse `bar.toml`.
 ///
 /// Returns the parsed bar config or an error string. Colors are **not**
@@ -361,6 +372,33 @@ pub fn load_bar() -> BarConfig {
     load_or_default(bar_path(), try_load_bar, BarConfig::default)
 }
 
+/// Returns the custom layouts file path: `~/.config/mosaico/layouts.toml`.
+pub fn layouts_path() -> Option<PathBuf> {
+    config_dir().map(|d| d.join("layouts.toml"))
+}
+
+/// Tries to load and validate `layouts.toml`.
+///
+/// Returns the layout definitions or an error string.
+///
+/// # Errors
+///
+/// Returns `Err` if the layouts path cannot be determined, the file
+/// cannot be read, the TOML content is invalid, or a definition fails
+/// validation.
+pub fn try_load_layouts() -> Result<Vec<LayoutDefinition>, String> {
+    let path = layouts_path().ok_or("could not determine layouts path")?;
+    let content = std::fs::read_to_string(&path).map_err(|e| format!("{}: {e}", path.display()))?;
+    validate_layouts(&content).map_err(|e| format!("{}: {e}", path.display()))
+}
+
+/// Loads custom layouts from `~/.config/mosaico/layouts.toml`.
+///
+/// Returns an empty vec if the file is missing or invalid.
+pub fn load_layouts() -> Vec<LayoutDefinition> {
+    load_or_default(layouts_path(), try_load_layouts, Vec::new)
+}
+
 /// Loads a config value from disk, falling back to defaults.
 ///
 /// Non-existent files silently return defaults; other IO errors are logged.
@@ -2,10 +2,18 @@
 pub mod bar;
 /// Keybinding definitions and defaults.
 pub mod keybinding;
+/// Custom layout definitions (`layouts.toml`).
+pub mod layouts;
 /// Configuration file loading and path resolution.
 mod loader;
+/// Per-monitor overrides (`[[monitor]]` blocks).
+pub mod monitors;
 /// Color palette definitions for themes.
 mod palette;
+/// Explaining which rule matched a window, and rule test fixtures.
+pub mod rule_explain;
+/// Window rule conditions and the properties they match against.
+pub mod rule_match;
 /// Window management rules.
 pub mod rules;
 /// Default configuration file templates.
@@ -19,13 +27,21 @@ use serde::{Deserialize, Serialize};
 
 pub use bar::{BarColors, BarConfig, WidgetConfig};
 pub use keybinding::{Keybinding, Modifier};
+pub use layouts::validate_layouts;
 pub use loader::{
-    bar_path, config_dir, config_path, keybindings_path, load, load_bar, load_keybindings,
-    load_merged_rules, load_rules, load_user_rules, merge_missing_bar_widgets,
-    merge_missing_keybindings, rules_path, try_load, try_load_bar, try_load_keybindings,
-    try_load_rules, try_load_user_rules, user_rules_path,
+    bar_path, config_dir, config_path, keybindings_path, layouts_path, load, load_bar,
+    load_keybindings, load_layouts, load_merged_rules, load_rules, load_sourced_rules,
+    load_user_rules, merge_missing_bar_widgets, merge_missing_keybindings, rules_path, try_load,
+    try_load_bar, try_load_keybindings, try_load_layouts, try_load_rules, try_load_user_rules,
+    user_rules_path,
 };
-pub use rules::{WindowRule, default_rules, should_manage, validate_rules};
+pub use monitors::{MonitorConfig, MonitorOverrides, monitor_overrides};
+pub use rule_explain::{
+    RuleExplanation, RuleSource, RuleTestCase, SourcedRule, explain_rules, parse_rule_tests,
+    sourced_rules,
+};
+pub use rule_match::{Pattern, RuleMatch, WindowProps};
+pub use rules::{WindowRule, default_rules, find_rule, should_manage, validate_rules};
 pub use theme::{Theme, ThemeConfig};
 pub use types::*;
 
@@ -40,6 +56,10 @@ pub struct Config {
     pub theme: ThemeConfig,
     /// Layout algorithm parameters.
     pub layout: LayoutConfig,
+    /// Workspace count and names.
+    pub workspaces: WorkspacesConfig,
+    /// Per-monitor overrides, one `[[monitor]]` block each.
+    pub monitor: Vec<MonitorConfig>,
     /// Border appearance settings.
     pub borders: BorderConfig,
     /// Mouse integration settings.
@@ -55,6 +75,18 @@ impl Config {
         self.resolve_borders();
         self.layout.gap = self.layout.gap.clamp(0, 200);
         self.layout.ratio = self.layout.ratio.clamp(0.1, 0.9);
+        self.layout.resize_step = self.layout.resize_step.clamp(0.01, 0.5);
+        self.layout.master_count = self.layout.master_count.clamp(1, 16);
+        if self.layout.cycle.is_empty() {
+            self.layout.cycle = crate::layout::default_cycle();
+        }
+        self.layout
+            .workspace_cycles
+            .retain(|_, cycle| !cycle.is_empty());
+        self.workspaces.validate();
+        for monitor in &mut self.monitor {
+            monitor.validate();
+        }
         self.borders.width = self.borders.width.clamp(0, 32);
     }
 
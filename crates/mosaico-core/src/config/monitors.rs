@@ -0,0 +1,175 @@
+use serde::{Deserialize, Serialize};
+
+use crate::Rect;
+use crate::action::MAX_WORKSPACES;
+use crate::layout::LayoutKind;
+
+/// A `[[monitor]]` block: overrides applied to the monitors it matches.
+///
+/// Every match field that is set must match. A block without match
+/// fields matches no monitor. When several blocks match the same
+/// monitor, later blocks override earlier ones field by field.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MonitorConfig {
+    /// Match the monitor at this position, counting from 1 in
+    /// left-to-right order like the other monitor numbers in the config.
+    /// Note that `bar.toml`'s `monitors` list counts from 0 instead.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub index: Option<usize>,
+    /// Match the monitor with this device name (e.g. `\\.\DISPLAY2`),
+    /// case-insensitive.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Match monitors with this resolution, as `"WIDTHxHEIGHT"`
+    /// (e.g. `"1080x1920"`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resolution: Option<String>,
+    /// Settings that replace the global ones on matching monitors.
+    #[serde(flatten)]
+    pub overrides: MonitorOverrides,
+}
+
+/// Per-monitor settings. Unset fields keep the global value.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MonitorOverrides {
+    /// Gap in pixels between windows and screen edges.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gap: Option<i32>,
+    /// Ratio of space given to the first window in each split.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ratio: Option<f64>,
+    /// Default layout for this monitor's workspaces. Per-workspace
+    /// overrides from `[layout.workspaces]` still apply.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub layout: Option<LayoutKind>,
+    /// Number of workspaces on this monitor. Takes precedence over
+    /// `[workspaces.monitors]`, which takes precedence over
+    /// `workspaces.count`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub workspaces: Option<u8>,
+    /// Whether the status bar is shown on this monitor, overriding the
+    /// `monitors` list in `bar.toml`. Only switches the bar on or off:
+    /// its height, colors and widgets are the same on every monitor.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bar: Option<bool>,
+}
+
+impl MonitorConfig {
+    /// Returns true if this block applies to the monitor at 0-based `index`
+    /// with device `name` and full-screen `bounds`.
+    pub fn matches(&self, index: usize, name: &str, bounds: &Rect) -> bool {
+        if self.index.is_none() && self.name.is_none() && self.resolution.is_none() {
+            return false;
+        }
+        self.index.is_none_or(|i| i == index + 1)
+            && self
+                .name
+                .as_ref()
+                .is_none_or(|n| n.eq_ignore_ascii_case(name))
+            && self
+                .resolution
+                .as_deref()
+                .is_none_or(|r| parse_resolution(r) == Some((bounds.width, bounds.height)))
+    }
+
+    /// Clamps override values to the same ranges as the global settings.
+    pub(crate) fn validate(&mut self) {
+        let o = &mut self.overrides;
+        o.gap = o.gap.map(|g| g.clamp(0, 200));
+        o.ratio = o.ratio.map(|r| r.clamp(0.1, 0.9));
+        o.workspaces = o.workspaces.map(|n| n.clamp(1, MAX_WORKSPACES));
+    }
+}
+
+impl MonitorOverrides {
+    /// Overlays the fields `other` sets on top of `self`.
+    fn merge(&mut self, other: &Self) {
+        self.gap = other.gap.or(self.gap);
+        self.ratio = other.ratio.or(self.ratio);
+        if other.layout.is_some() {
+            self.layout.clone_from(&other.layout);
+        }
+        self.workspaces = other.workspaces.or(self.workspaces);
+        self.bar = other.bar.or(self.bar);
+    }
+}
+
+/// Merges the overrides of every block matching a monitor, in order.
+pub fn monitor_overrides(
+    configs: &[MonitorConfig],
+    index: usize,
+    name: &str,
+    bounds: &Rect,
+) -> MonitorOverrides {
+    let mut overrides = MonitorOverrides::default();
+    for config in configs.iter().filter(|c| c.matches(index, name, bounds)) {
+        overrides.merge(&config.overrides);
+    }
+    overrides
+}
+
+/// Parses `"WIDTHxHEIGHT"` into its two dimensions.
+fn parse_resolution(s: &str) -> Option<(i32, i32)> {
+    let (w, h) = s.trim().split_once(['x', 'X'])?;
+    Some((w.trim().parse().ok()?, h.trim().parse().ok()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn block(toml_str: &str) -> MonitorConfig {
+        toml::from_str(toml_str).unwrap()
+    }
+
+    const PORTRAIT: Rect = Rect {
+        x: 0,
+        y: 0,
+        width: 1080,
+        height: 1920,
+    };
+
+    #[test]
+    fn matches_by_index_name_and_resolution() {
+        assert!(block("index = 2").matches(1, "", &PORTRAIT));
+        assert!(!block("index = 2").matches(0, "", &PORTRAIT));
+        assert!(block(r#"name = '\\.\display2'"#).matches(0, r"\\.\DISPLAY2", &PORTRAIT));
+        assert!(block(r#"resolution = "1080x1920""#).matches(3, "", &PORTRAIT));
+        assert!(!block(r#"resolution = "1920x1080""#).matches(3, "", &PORTRAIT));
+    }
+
+    #[test]
+    fn all_match_fields_must_match() {
+        let config = block("index = 1\nresolution = \"1080x1920\"");
+
+        assert!(config.matches(0, "", &PORTRAIT));
+        assert!(!config.matches(1, "", &PORTRAIT));
+    }
+
+    #[test]
+    fn index_counts_from_one() {
+        assert!(block("index = 1").matches(0, "", &PORTRAIT));
+        assert!(!block("index = 0").matches(0, "", &PORTRAIT));
+    }
+
+    #[test]
+    fn block_without_match_fields_matches_nothing() {
+        assert!(!block("gap = 0").matches(0, "", &PORTRAIT));
+    }
+
+    #[test]
+    fn later_blocks_override_earlier_ones() {
+        let configs = [
+            block("resolution = \"1080x1920\"\ngap = 4\nlayout = \"vertical-stack\""),
+            block("index = 1\ngap = 12"),
+        ];
+
+        let overrides = monitor_overrides(&configs, 0, "", &PORTRAIT);
+
+        assert_eq!(overrides.gap, Some(12));
+        assert_eq!(overrides.layout, Some(LayoutKind::VerticalStack));
+        assert_eq!(overrides.workspaces, None);
+    }
+}
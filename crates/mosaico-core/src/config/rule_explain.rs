@@ -0,0 +1,234 @@
+use std::fmt;
+
+use serde::Deserialize;
+
+use super::rule_match::WindowProps;
+use super::rules::WindowRule;
+
+/// The file a window rule was loaded from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RuleSource {
+    /// The user's `user-rules.toml`.
+    User,
+    /// The community `rules.toml`.
+    Community,
+}
+
+impl fmt::Display for RuleSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RuleSource::User => f.write_str("user-rules.toml"),
+            RuleSource::Community => f.write_str("rules.toml"),
+        }
+    }
+}
+
+/// A window rule together with where it was loaded from.
+#[derive(Debug, Clone)]
+pub struct SourcedRule {
+    /// The file the rule came from.
+    pub source: RuleSource,
+    /// Position of the rule in its file (0-based).
+    pub index: usize,
+    /// The rule itself.
+    pub rule: WindowRule,
+}
+
+/// Tags the rules of one file with their source and position.
+pub fn sourced_rules(source: RuleSource, rules: Vec<WindowRule>) -> Vec<SourcedRule> {
+    rules
+        .into_iter()
+        .enumerate()
+        .map(|(index, rule)| SourcedRule {
+            source,
+            index,
+            rule,
+        })
+        .collect()
+}
+
+/// How the rules were evaluated for one window.
+#[derive(Debug)]
+pub struct RuleExplanation<'a> {
+    /// The first rule that matched, if any.
+    pub matched: Option<&'a SourcedRule>,
+    /// The rules checked before the match (all of them without one).
+    pub checked: &'a [SourcedRule],
+}
+
+impl RuleExplanation<'_> {
+    /// Returns whether the window is managed, as [`super::should_manage`]
+    /// decides.
+    pub fn manage(&self) -> bool {
+        self.matched.is_none_or(|r| r.rule.manage)
+    }
+}
+
+/// Evaluates `rules` in order like [`super::find_rule`], keeping track
+/// of which rule matched and which were passed over.
+pub fn explain_rules<'a>(window: &WindowProps, rules: &'a [SourcedRule]) -> RuleExplanation<'a> {
+    match rules.iter().position(|r| r.rule.matcher.matches(window)) {
+        Some(i) => RuleExplanation {
+            matched: Some(&rules[i]),
+            checked: &rules[..i],
+        },
+        None => RuleExplanation {
+            matched: None,
+            checked: rules,
+        },
+    }
+}
+
+/// One `[[case]]` of a `mosaico rules test` fixture: a window and the
+/// outcome the rules are expected to produce for it.
+#[derive(Debug, Clone, Deserialize)]
+pub struct RuleTestCase {
+    /// Window class name.
+    #[serde(default)]
+    pub class: String,
+    /// Window title.
+    #[serde(default)]
+    pub title: String,
+    /// Executable name. Derived from `exe_path` when omitted.
+    #[serde(default)]
+    pub process: Option<String>,
+    /// Full executable path.
+    #[serde(default)]
+    pub exe_path: String,
+    /// Whether the window is expected to be managed.
+    pub manage: bool,
+    /// Whether the window is expected to float, if checked.
+    #[serde(default)]
+    pub float: Option<bool>,
+}
+
+impl RuleTestCase {
+    /// Returns the window this case describes.
+    pub fn window(&self) -> WindowProps {
+        let mut window = WindowProps::new(&self.class, &self.title, &self.exe_path);
+        if let Some(process) = &self.process {
+            window.process.clone_from(process);
+        }
+        window
+    }
+
+    /// Checks the case against `rules`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` describing the mismatch if the outcome differs
+    /// from the expected one.
+    pub fn check(&self, rules: &[SourcedRule]) -> Result<(), String> {
+        let explanation = explain_rules(&self.window(), rules);
+        let by = match explanation.matched {
+            Some(r) => format!("{} rule #{}", r.source, r.index + 1),
+            None => "no rule".to_string(),
+        };
+        if explanation.manage() != self.manage {
+            return Err(format!(
+                "expected manage = {}, got {} ({by})",
+                self.manage,
+                explanation.manage()
+            ));
+        }
+        let floating = explanation.matched.is_some_and(|r| r.rule.float);
+        match self.float {
+            Some(expected) if self.manage && expected != floating => Err(format!(
+                "expected float = {expected}, got {floating} ({by})"
+            )),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Wrapper for deserializing a rule test fixture.
+#[derive(Debug, Deserialize)]
+struct RuleTestFile {
+    #[serde(default)]
+    case: Vec<RuleTestCase>,
+}
+
+/// Parses a rule test fixture with a top-level `[[case]]` array.
+///
+/// # Errors
+///
+/// Returns `Err` if the TOML content is malformed or does not match
+/// the fixture schema.
+pub fn parse_rule_tests(content: &str) -> Result<Vec<RuleTestCase>, String> {
+    let file: RuleTestFile = toml::from_str(content).map_err(|e| e.to_string())?;
+    Ok(file.case)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::validate_rules;
+
+    fn rules() -> Vec<SourcedRule> {
+        let user = validate_rules("[[rule]]\nmatch_process = \"code.exe\"\nmanage = true").unwrap();
+        let community = validate_rules(
+            r#"
+            [[rule]]
+            match_class = "Chrome_WidgetWin_1"
+            match_title = "Picture-in-Picture"
+            manage = false
+
+            [[rule]]
+            match_class = "Chrome_WidgetWin_1"
+            manage = false
+            "#,
+        )
+        .unwrap();
+        let mut rules = sourced_rules(RuleSource::User, user);
+        rules.extend(sourced_rules(RuleSource::Community, community));
+        rules
+    }
+
+    #[test]
+    fn explains_matching_rule_and_the_rules_before_it() {
+        let rules = rules();
+        let window = WindowProps::new("Chrome_WidgetWin_1", "Slack", r"C:\Slack\slack.exe");
+
+        let explanation = explain_rules(&window, &rules);
+
+        let matched = explanation.matched.unwrap();
+        assert_eq!(matched.source, RuleSource::Community);
+        assert_eq!(matched.index, 1);
+        assert_eq!(explanation.checked.len(), 2);
+        assert!(!explanation.manage());
+    }
+
+    #[test]
+    fn no_match_checks_every_rule_and_manages() {
+        let rules = rules();
+        let window = WindowProps::new("Notepad", "a.txt", "");
+
+        let explanation = explain_rules(&window, &rules);
+
+        assert!(explanation.matched.is_none());
+        assert_eq!(explanation.checked.len(), 3);
+        assert!(explanation.manage());
+    }
+
+    #[test]
+    fn fixture_cases_report_mismatches() {
+        let cases = parse_rule_tests(
+            r#"
+            [[case]]
+            class = "Chrome_WidgetWin_1"
+            process = "code.exe"
+            manage = true
+
+            [[case]]
+            class = "Chrome_WidgetWin_1"
+            title = "Picture-in-Picture"
+            manage = true
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(cases[0].check(&rules()), Ok(()));
+        let err = cases[1].check(&rules()).unwrap_err();
+        assert!(err.contains("rules.toml rule #1"), "{err}");
+    }
+}
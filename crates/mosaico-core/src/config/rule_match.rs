@@ -0,0 +1,231 @@
+use std::fmt;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// The window properties that rules match against.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct WindowProps {
+    /// Window class name.
+    pub class: String,
+    /// Window title.
+    pub title: String,
+    /// File name of the owning process's executable (e.g. `firefox.exe`).
+    pub process: String,
+    /// Full path of the owning process's executable.
+    pub exe_path: String,
+}
+
+impl WindowProps {
+    /// Creates the properties of a window, deriving the process name
+    /// from `exe_path`.
+    pub fn new(class: &str, title: &str, exe_path: &str) -> Self {
+        Self {
+            class: class.to_string(),
+            title: title.to_string(),
+            process: crate::window::process_name(exe_path).to_string(),
+            exe_path: exe_path.to_string(),
+        }
+    }
+}
+
+/// A compiled regular expression from a `*_regex` rule field.
+///
+/// Matching is case-sensitive unless the pattern starts with `(?i)`.
+#[derive(Debug, Clone)]
+pub struct Pattern(regex_lite::Regex);
+
+impl Pattern {
+    /// Returns whether the pattern matches anywhere in `text`.
+    pub fn is_match(&self, text: &str) -> bool {
+        self.0.is_match(text)
+    }
+}
+
+impl Serialize for Pattern {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.0.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for Pattern {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let source = String::deserialize(deserializer)?;
+        regex_lite::Regex::new(&source)
+            .map(Pattern)
+            .map_err(|e| serde::de::Error::custom(format!("invalid regex {source:?}: {e}")))
+    }
+}
+
+/// The conditions of a rule.
+///
+/// Every condition that is set must hold. A matcher without any
+/// condition matches no window, so an empty rule never applies.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RuleMatch {
+    /// Match windows with this exact class name (case-insensitive).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub match_class: Option<String>,
+    /// Match windows whose title contains this string (case-insensitive).
+    /// An empty string matches only windows without a title.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub match_title: Option<String>,
+    /// Match windows of this executable name, e.g. `"firefox.exe"`
+    /// (case-insensitive).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub match_process: Option<String>,
+    /// Match windows whose executable path contains this string
+    /// (case-insensitive).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub match_exe_path: Option<String>,
+    /// Match windows whose class name matches this regex.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub match_class_regex: Option<Pattern>,
+    /// Match windows whose title matches this regex.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub match_title_regex: Option<Pattern>,
+    /// Match windows whose executable name matches this regex.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub match_process_regex: Option<Pattern>,
+    /// Match windows whose executable path matches this regex.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub match_exe_path_regex: Option<Pattern>,
+    /// Match only if every one of these matchers matches.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub all: Vec<RuleMatch>,
+    /// Match only if at least one of these matchers matches.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub any: Vec<RuleMatch>,
+    /// Match only if this matcher does not match.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub not: Option<Box<RuleMatch>>,
+}
+
+impl RuleMatch {
+    /// Returns whether no condition is set.
+    pub fn is_empty(&self) -> bool {
+        self.match_class.is_none()
+            && self.match_title.is_none()
+            && self.match_process.is_none()
+            && self.match_exe_path.is_none()
+            && self.match_class_regex.is_none()
+            && self.match_title_regex.is_none()
+            && self.match_process_regex.is_none()
+            && self.match_exe_path_regex.is_none()
+            && self.all.is_empty()
+            && self.any.is_empty()
+            && self.not.is_none()
+    }
+
+    /// Returns whether the window satisfies every condition.
+    pub fn matches(&self, window: &WindowProps) -> bool {
+        !self.is_empty()
+            && equals(self.match_class.as_deref(), &window.class)
+            && title_contains(self.match_title.as_deref(), &window.title)
+            && equals(self.match_process.as_deref(), &window.process)
+            && contains(self.match_exe_path.as_deref(), &window.exe_path)
+            && regex(self.match_class_regex.as_ref(), &window.class)
+            && regex(self.match_title_regex.as_ref(), &window.title)
+            && regex(self.match_process_regex.as_ref(), &window.process)
+            && regex(self.match_exe_path_regex.as_ref(), &window.exe_path)
+            && self.all.iter().all(|m| m.matches(window))
+            && (self.any.is_empty() || self.any.iter().any(|m| m.matches(window)))
+            && self.not.as_ref().is_none_or(|m| !m.matches(window))
+    }
+}
+
+/// Formats the conditions as an inline TOML table, as in `rules explain`.
+impl fmt::Display for RuleMatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match toml::Value::try_from(self) {
+            Ok(value) => write!(f, "{value}"),
+            Err(_) => f.write_str("{ ? }"),
+        }
+    }
+}
+
+fn equals(expected: Option<&str>, actual: &str) -> bool {
+    expected.is_none_or(|e| actual.eq_ignore_ascii_case(e))
+}
+
+fn contains(needle: Option<&str>, haystack: &str) -> bool {
+    needle.is_none_or(|n| {
+        haystack
+            .to_ascii_lowercase()
+            .contains(&n.to_ascii_lowercase())
+    })
+}
+
+/// Like [`contains`], except that an empty needle requires an empty title.
+fn title_contains(needle: Option<&str>, title: &str) -> bool {
+    match needle {
+        Some("") => title.is_empty(),
+        other => contains(other, title),
+    }
+}
+
+fn regex(pattern: Option<&Pattern>, text: &str) -> bool {
+    pattern.is_none_or(|p| p.is_match(text))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matcher(toml_str: &str) -> RuleMatch {
+        toml::from_str(toml_str).unwrap()
+    }
+
+    fn firefox() -> WindowProps {
+        WindowProps::new(
+            "MozillaWindowClass",
+            "Inbox - Mozilla Firefox",
+            r"C:\Program Files\Mozilla Firefox\firefox.exe",
+        )
+    }
+
+    #[test]
+    fn matches_process_and_exe_path() {
+        assert!(matcher(r#"match_process = "FIREFOX.EXE""#).matches(&firefox()));
+        assert!(!matcher(r#"match_process = "firefox""#).matches(&firefox()));
+        assert!(matcher(r#"match_exe_path = "mozilla firefox\\""#).matches(&firefox()));
+    }
+
+    #[test]
+    fn matches_regex_fields() {
+        assert!(matcher(r#"match_title_regex = "^Inbox - ""#).matches(&firefox()));
+        assert!(!matcher(r#"match_title_regex = "^inbox""#).matches(&firefox()));
+        assert!(matcher(r#"match_title_regex = "(?i)^inbox""#).matches(&firefox()));
+        assert!(matcher(r#"match_class_regex = "^Mozilla.*Class$""#).matches(&firefox()));
+    }
+
+    #[test]
+    fn composes_all_any_and_not() {
+        let rule = matcher(
+            r#"
+            match_process = "firefox.exe"
+            any = [{ match_title = "inbox" }, { match_title = "calendar" }]
+            not = { match_title_regex = "Private" }
+            "#,
+        );
+        let mut private = firefox();
+        private.title = "Inbox - Private Browsing".into();
+
+        assert!(rule.matches(&firefox()));
+        assert!(!rule.matches(&private));
+        assert!(matcher(r#"all = [{ match_class = "MozillaWindowClass" }]"#).matches(&firefox()));
+    }
+
+    #[test]
+    fn empty_matcher_and_empty_groups_match_nothing() {
+        assert!(!matcher("").matches(&firefox()));
+        assert!(!matcher("all = [{}]").matches(&firefox()));
+    }
+
+    #[test]
+    fn invalid_regex_is_a_parse_error() {
+        let err = toml::from_str::<RuleMatch>(r#"match_title_regex = "(unclosed""#).unwrap_err();
+
+        assert!(err.to_string().contains("invalid regex"));
+    }
+}
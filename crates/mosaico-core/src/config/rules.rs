@@ -1,26 +1,101 @@
 /// Window rule types and evaluation logic.
 ///
-/// Rules determine which windows Mosaico should manage (tile) and
-/// which should be left floating. They are evaluated in order — the
-/// first matching rule wins.
+/// Rules determine which windows Mosaico should manage (tile), which
+/// should be left alone, and where managed windows are placed. They are
+/// evaluated in order — the first matching rule wins.
 use serde::{Deserialize, Serialize};
 
+use crate::Rect;
+use crate::action::WorkspaceRef;
+
 use super::keybinding::{self, Keybinding};
+use super::rule_match::{RuleMatch, WindowProps};
 
-/// A rule that determines whether a window should be managed (tiled).
+/// A rule that determines how a window is managed.
 ///
 /// Rules are evaluated in order. The first matching rule wins.
 /// If no rule matches, the window is managed by default.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WindowRule {
-    /// Match windows with this exact class name (case-insensitive).
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub match_class: Option<String>,
-    /// Match windows whose title contains this string (case-insensitive).
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub match_title: Option<String>,
+    /// The conditions a window must meet for the rule to apply.
+    #[serde(flatten)]
+    pub matcher: RuleMatch,
     /// Whether matching windows should be managed (tiled).
+    #[serde(default = "default_true")]
     pub manage: bool,
+    /// Whether matching managed windows float above the tiles instead.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub float: bool,
+    /// Initial `[width, height]` of a floating window.
+    ///
+    /// Defaults to the window's own size, or half the work area.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<[i32; 2]>,
+    /// Initial `[x, y]` of a floating window, relative to the work area.
+    ///
+    /// Defaults to the window's own position, or centered when `size`
+    /// is set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<[i32; 2]>,
+    /// Workspace a new matching window opens on, by number or name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub workspace: Option<WorkspaceRef>,
+    /// Monitor a new matching window opens on (1-based, left to right).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub monitor: Option<u8>,
+    /// Whether the matching window's workspace switches to monocle.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub monocle: bool,
+    /// Layout position a matching window is inserted at (0 is the
+    /// master). Clamped to the number of windows on the workspace.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slot: Option<usize>,
+    /// Whether the rule is also applied to managed windows whose title
+    /// changes to match it, tiling, floating or releasing them.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub reevaluate_on_title_change: bool,
+}
+
+impl Default for WindowRule {
+    fn default() -> Self {
+        Self {
+            matcher: RuleMatch::default(),
+            manage: true,
+            float: false,
+            size: None,
+            position: None,
+            workspace: None,
+            monitor: None,
+            monocle: false,
+            slot: None,
+            reevaluate_on_title_change: false,
+        }
+    }
+}
+
+fn default_true() -> bool {
+    true
+}
+
+impl WindowRule {
+    /// Returns where a floating window matched by this rule starts out.
+    ///
+    /// `current` is the window's rect when it is known.
+    pub fn float_rect(&self, work_area: &Rect, current: Option<Rect>) -> Rect {
+        let [width, height] = self
+            .size
+            .or(current.map(|r| [r.width, r.height]))
+            .unwrap_or([work_area.width / 2, work_area.height / 2]);
+        let (x, y) = match (self.position, current) {
+            (Some([x, y]), _) => (work_area.x + x, work_area.y + y),
+            (None, Some(r)) if self.size.is_none() => (r.x, r.y),
+            _ => (
+                work_area.x + (work_area.width - width) / 2,
+                work_area.y + (work_area.height - height) / 2,
+            ),
+        };
+        Rect::new(x, y, width.max(1), height.max(1))
+    }
 }
 
 /// Returns the default window rules (empty).
@@ -35,35 +110,13 @@ pub fn default_rules() -> Vec<WindowRule> {
 ///
 /// Returns `true` if the window should be tiled. When no rule matches,
 /// defaults to `true`.
-pub fn should_manage(class: &str, title: &str, rules: &[WindowRule]) -> bool {
-    for rule in rules {
-        if matches_rule(class, title, rule) {
-            return rule.manage;
-        }
-    }
-    true
+pub fn should_manage(window: &WindowProps, rules: &[WindowRule]) -> bool {
+    find_rule(window, rules).is_none_or(|rule| rule.manage)
 }
 
-fn matches_rule(class: &str, title: &str, rule: &WindowRule) -> bool {
-    if let Some(ref mc) = rule.match_class
-        && !class.eq_ignore_ascii_case(mc)
-    {
-        return false;
-    }
-    if let Some(ref mt) = rule.match_title {
-        if mt.is_empty() {
-            // match_title = "" means the window title must be empty.
-            if !title.is_empty() {
-                return false;
-            }
-        } else if !title
-            .to_ascii_lowercase()
-            .contains(&mt.to_ascii_lowercase())
-        {
-            return false;
-        }
-    }
-    rule.match_class.is_some() || rule.match_title.is_some()
+/// Returns the first rule matching the window, if any.
+pub fn find_rule<'a>(window: &WindowProps, rules: &'a [WindowRule]) -> Option<&'a WindowRule> {
+    rules.iter().find(|rule| rule.matcher.matches(window))
 }
 
 /// Wrapper for deserializing the keybindings file.
@@ -17,18 +17,60 @@ flavor = "mocha"
 gap = 8
 # Ratio of space given to the first window in each split (0.0 to 1.0).
 ratio = 0.5
+# How much resize-left/right/up/down change a split ratio (0.01 to 0.5).
+resize_step = 0.05
+# Windows sharing the master pane in the stack and three-column layouts.
+master_count = 1
 # How windows are hidden during workspace switches.
 # "cloak" (recommended): invisible via DWM, keeps taskbar icon.
 # "hide": SW_HIDE, removes taskbar icon.
 # "minimize": SW_MINIMIZE, keeps taskbar icon but shows as minimized.
 hiding = "cloak"
-# Default layout algorithm: "bsp", "vertical-stack", or "three-column".
+# Default layout algorithm: "bsp", "vertical-stack", "horizontal-stack",
+# "three-column", "grid", "columns", or "rows".
 default = "bsp"
+# Layouts cycle-layout steps through, in order.
+cycle = ["bsp", "vertical-stack", "three-column"]
 
-# Per-workspace layout overrides (workspace number 1-8).
+# Per-workspace layout overrides (workspace number 1-255).
 # [layout.workspaces]
 # 1 = "vertical-stack"
 
+# Per-workspace cycle overrides (workspace number 1-255).
+# [layout.workspace_cycles]
+# 2 = ["bsp", "grid"]
+
+[workspaces]
+# Workspaces per monitor (1 to 255).
+count = 8
+# Names for workspaces 1, 2, ... shown in the bar and usable as
+# goto-workspace-<name>. Unnamed workspaces show their number.
+# names = ["web", "code", "chat"]
+# Pressing the visible workspace's goto key returns to the previous one.
+back_and_forth = false
+# goto-workspace-next/prev pass over workspaces without windows.
+skip_empty = false
+
+# Per-monitor workspace counts (monitor number 1, 2, ...).
+# [workspaces.monitors]
+# 2 = 4
+
+# Per-monitor overrides. A block matches by index (1-based, left to
+# right), device name (see `mosaico doctor`) or resolution; every match
+# field given must match. It can override gap, ratio, layout, the
+# workspace count (taking precedence over [workspaces.monitors]) and
+# whether the status bar is shown (its look comes from bar.toml).
+# [[monitor]]
+# resolution = "1080x1920"
+# layout = "vertical-stack"
+#
+# [[monitor]]
+# name = '\\.\DISPLAY2'
+# layout = "three-column"
+# gap = 12
+# workspaces = 4
+# bar = false
+
 [borders]
 # Border width in pixels around the focused window.
 width = 4
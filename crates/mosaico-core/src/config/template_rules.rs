@@ -52,6 +52,32 @@ pub fn generate_user_rules() -> String {
 # [[rule]]
 # match_title = "My Private Tool"
 # manage = false
+
+# Example: open Slack on the "chat" workspace of the second monitor
+# [[rule]]
+# match_process = "slack.exe"
+# workspace = "chat"
+# monitor = 2
+
+# Example: float every dialog of an app, matched by regex
+# [[rule]]
+# match_process_regex = '(?i)^paint\.net'
+# match_title_regex = "^(Open|Save)"
+# float = true
+
+# Example: combine conditions with all / any / not
+# [[rule]]
+# match_process = "code.exe"
+# not = { match_title = "Settings" }
+# slot = 0
+# monocle = true
+
+# Example: release a window once its title changes to "Settings"
+# [[rule]]
+# match_process = "myapp.exe"
+# match_title = "Settings"
+# manage = false
+# reevaluate_on_title_change = true
 "#
     .to_string()
 }
@@ -33,6 +33,10 @@ fn config_template_matches_default_values() {
     assert_eq!(config.theme.flavor, "mocha");
     assert_eq!(config.layout.gap, defaults.layout.gap);
     assert_eq!(config.layout.ratio, defaults.layout.ratio);
+    assert_eq!(config.layout.resize_step, defaults.layout.resize_step);
+    assert_eq!(config.layout.master_count, defaults.layout.master_count);
+    assert_eq!(config.layout.cycle, defaults.layout.cycle);
+    assert_eq!(config.workspaces, defaults.workspaces);
     assert_eq!(config.borders.width, defaults.borders.width);
     assert_eq!(config.mouse.follows_focus, defaults.mouse.follows_focus);
     assert_eq!(
@@ -1,4 +1,9 @@
 use super::*;
+use crate::{LayoutKind, Rect, WorkspaceRef};
+
+fn window(class: &str, title: &str) -> WindowProps {
+    WindowProps::new(class, title, "")
+}
 
 #[test]
 fn default_config_has_expected_values() {
@@ -86,34 +91,44 @@ fn partial_toml_uses_defaults_for_missing_sections() {
     // Assert
     assert_eq!(config.layout.gap, 16);
     assert_eq!(config.layout.ratio, 0.5);
+    assert_eq!(config.layout.resize_step, 0.05);
 }
 
 #[test]
 fn rule_excludes_by_class() {
     // Arrange
     let rules = vec![WindowRule {
-        match_class: Some("TaskManager".into()),
-        match_title: None,
+        matcher: RuleMatch {
+            match_class: Some("TaskManager".into()),
+            ..Default::default()
+        },
         manage: false,
+        ..Default::default()
     }];
 
     // Act / Assert
-    assert!(!should_manage("TaskManager", "Task Manager", &rules));
-    assert!(should_manage("Notepad", "Untitled", &rules));
+    assert!(!should_manage(
+        &window("TaskManager", "Task Manager"),
+        &rules
+    ));
+    assert!(should_manage(&window("Notepad", "Untitled"), &rules));
 }
 
 #[test]
 fn rule_excludes_by_title_substring() {
     // Arrange
     let rules = vec![WindowRule {
-        match_class: None,
-        match_title: Some("settings".into()),
+        matcher: RuleMatch {
+            match_title: Some("settings".into()),
+            ..Default::default()
+        },
         manage: false,
+        ..Default::default()
     }];
 
     // Act / Assert
-    assert!(!should_manage("App", "Windows Settings", &rules));
-    assert!(should_manage("App", "My Document", &rules));
+    assert!(!should_manage(&window("App", "Windows Settings"), &rules));
+    assert!(should_manage(&window("App", "My Document"), &rules));
 }
 
 #[test]
@@ -121,25 +136,236 @@ fn first_matching_rule_wins() {
     // Arrange
     let rules = vec![
         WindowRule {
-            match_class: Some("Chrome".into()),
-            match_title: None,
+            matcher: RuleMatch {
+                match_class: Some("Chrome".into()),
+                ..Default::default()
+            },
             manage: false,
+            ..Default::default()
         },
         WindowRule {
-            match_class: Some("Chrome".into()),
-            match_title: None,
+            matcher: RuleMatch {
+                match_class: Some("Chrome".into()),
+                ..Default::default()
+            },
             manage: true,
+            ..Default::default()
         },
     ];
 
     // Act / Assert
-    assert!(!should_manage("Chrome", "Google", &rules));
+    assert!(!should_manage(&window("Chrome", "Google"), &rules));
+}
+
+#[test]
+fn layout_cycles_parse_globally_and_per_workspace() {
+    // Arrange
+    let toml_str = r#"
+        [layout]
+        cycle = ["bsp", "vertical-stack"]
+
+        [layout.workspace_cycles]
+        2 = ["grid", "columns"]
+        3 = []
+    "#;
+
+    // Act
+    let mut config: Config = toml::from_str(toml_str).unwrap();
+    config.validate();
+
+    // Assert
+    assert_eq!(
+        config.layout.cycle,
+        [LayoutKind::Bsp, LayoutKind::VerticalStack]
+    );
+    assert_eq!(
+        config.layout.workspace_cycles[&2],
+        [LayoutKind::Grid, LayoutKind::Columns]
+    );
+    assert!(!config.layout.workspace_cycles.contains_key(&3));
+}
+
+#[test]
+fn workspaces_section_parses_counts_and_names() {
+    // Arrange
+    let toml_str = r#"
+        [workspaces]
+        count = 40
+        names = ["web", "code"]
+
+        [workspaces.monitors]
+        2 = 0
+    "#;
+
+    // Act
+    let mut config: Config = toml::from_str(toml_str).unwrap();
+    config.validate();
+
+    // Assert
+    assert_eq!(config.workspaces.count, 40);
+    assert_eq!(config.workspaces.count_for(0), 40);
+    assert_eq!(config.workspaces.count_for(1), 1);
+    assert_eq!(config.workspaces.label(1), "code");
+    assert_eq!(config.workspaces.label(2), "3");
+    assert_eq!(config.workspaces.index_of("web"), Some(0));
+}
+
+#[test]
+fn workspace_names_actions_cannot_reach_are_dropped() {
+    // Arrange
+    let toml_str = r#"
+        [workspaces]
+        names = ["web", "my code", "2nd", "next", "web", "chat"]
+    "#;
+
+    // Act
+    let mut config: Config = toml::from_str(toml_str).unwrap();
+    config.validate();
+
+    // Assert
+    let labels: Vec<String> = (0..6).map(|i| config.workspaces.label(i)).collect();
+    assert_eq!(labels, ["web", "2", "3", "4", "5", "chat"]);
+    assert_eq!(config.workspaces.index_of("web"), Some(0));
+    assert_eq!(config.workspaces.index_of("chat"), Some(5));
+    assert_eq!(config.workspaces.index_of("next"), None);
+}
+
+#[test]
+fn monitor_blocks_parse_and_clamp() {
+    // Arrange
+    let toml_str = r#"
+        [[monitor]]
+        name = '\\.\DISPLAY2'
+        gap = 500
+        ratio = 0.95
+        workspaces = 0
+
+        [[monitor]]
+        resolution = "3440x1440"
+        layout = "three-column"
+    "#;
+
+    // Act
+    let mut config: Config = toml::from_str(toml_str).unwrap();
+    config.validate();
+
+    // Assert
+    assert_eq!(config.monitor.len(), 2);
+    let first = &config.monitor[0].overrides;
+    assert_eq!(first.gap, Some(200));
+    assert_eq!(first.ratio, Some(0.9));
+    assert_eq!(first.workspaces, Some(1));
+    assert_eq!(config.monitor[0].name.as_deref(), Some(r"\\.\DISPLAY2"));
+    assert_eq!(
+        config.monitor[1].overrides.layout,
+        Some(LayoutKind::ThreeColumn)
+    );
+}
+
+#[test]
+fn layouts_file_parses_and_validates_definitions() {
+    // Arrange
+    let toml_str = r#"
+        [[layout]]
+        name = "focus"
+        root = { split = "horizontal", weights = [3, 1], children = [
+            { slot = 0 }, { rest = "vertical" },
+        ] }
+    "#;
+    let duplicate = format!("{toml_str}{toml_str}");
+
+    // Act
+    let layouts = validate_layouts(toml_str).unwrap();
+
+    // Assert
+    assert_eq!(layouts.len(), 1);
+    assert_eq!(layouts[0].name, "focus");
+    assert!(validate_layouts(&duplicate).is_err());
+    assert!(
+        validate_layouts("[[layout]]\nname = \"bsp\"\nroot = { rest = \"vertical\" }").is_err()
+    );
+    assert!(validate_layouts("").unwrap().is_empty());
+}
+
+#[test]
+fn float_rule_parses_size_and_position() {
+    // Arrange
+    let toml_str = r#"
+        [[rule]]
+        match_class = "Calculator"
+        manage = true
+        float = true
+        size = [400, 600]
+        position = [100, 50]
+    "#;
+
+    // Act
+    let rules = validate_rules(toml_str).unwrap();
+    let rect = rules[0].float_rect(&Rect::new(1920, 0, 1920, 1080), None);
+
+    // Assert
+    assert!(rules[0].float);
+    assert_eq!(rect, Rect::new(2020, 50, 400, 600));
+}
+
+#[test]
+fn rule_parses_placement_actions() {
+    // Arrange
+    let toml_str = r#"
+        [[rule]]
+        match_process = "slack.exe"
+        workspace = "chat"
+        monitor = 2
+
+        [[rule]]
+        match_exe_path_regex = "(?i)jetbrains"
+        workspace = 3
+        slot = 0
+        monocle = true
+    "#;
+
+    // Act
+    let rules = validate_rules(toml_str).unwrap();
+
+    // Assert
+    assert!(rules[0].manage);
+    assert_eq!(rules[0].workspace, Some(WorkspaceRef::Name("chat".into())));
+    assert_eq!(rules[0].monitor, Some(2));
+    assert_eq!(rules[1].workspace, Some(WorkspaceRef::Number(3)));
+    assert_eq!(rules[1].slot, Some(0));
+    assert!(rules[1].monocle);
+    assert!(validate_rules("[[rule]]\nmatch_class = \"A\"\nworkspace = 0").is_err());
+}
+
+#[test]
+fn float_rect_defaults_to_current_rect_or_centered() {
+    // Arrange
+    let area = Rect::new(0, 0, 1920, 1080);
+    let current = Rect::new(10, 20, 800, 600);
+    let sized = WindowRule {
+        size: Some([1000, 500]),
+        ..Default::default()
+    };
+
+    // Act / Assert
+    assert_eq!(
+        WindowRule::default().float_rect(&area, Some(current)),
+        current
+    );
+    assert_eq!(
+        WindowRule::default().float_rect(&area, None),
+        Rect::new(480, 270, 960, 540)
+    );
+    assert_eq!(
+        sized.float_rect(&area, Some(current)),
+        Rect::new(460, 290, 1000, 500)
+    );
 }
 
 #[test]
 fn no_rules_defaults_to_manage() {
     // Act / Assert
-    assert!(should_manage("Any", "Window", &[]));
+    assert!(should_manage(&window("Any", "Window"), &[]));
 }
 
 #[test]
@@ -149,6 +375,8 @@ fn validate_clamps_extreme_values() {
         layout: LayoutConfig {
             gap: -50,
             ratio: 2.0,
+            resize_step: 0.0,
+            cycle: Vec::new(),
             ..Default::default()
         },
         borders: BorderConfig {
@@ -164,6 +392,8 @@ fn validate_clamps_extreme_values() {
     // Assert
     assert_eq!(config.layout.gap, 0);
     assert!((config.layout.ratio - 0.9).abs() < f64::EPSILON);
+    assert!((config.layout.resize_step - 0.01).abs() < f64::EPSILON);
+    assert_eq!(config.layout.cycle, crate::layout::default_cycle());
     assert_eq!(config.borders.width, 32);
 }
 
@@ -171,14 +401,24 @@ fn validate_clamps_extreme_values() {
 fn empty_match_title_only_matches_empty_title() {
     // Arrange
     let rules = vec![WindowRule {
-        match_class: Some("ApplicationFrameWindow".into()),
-        match_title: Some(String::new()),
+        matcher: RuleMatch {
+            match_class: Some("ApplicationFrameWindow".into()),
+            match_title: Some(String::new()),
+            ..Default::default()
+        },
         manage: false,
+        ..Default::default()
     }];
 
     // Act / Assert
-    assert!(!should_manage("ApplicationFrameWindow", "", &rules));
-    assert!(should_manage("ApplicationFrameWindow", "Settings", &rules));
+    assert!(!should_manage(
+        &window("ApplicationFrameWindow", ""),
+        &rules
+    ));
+    assert!(should_manage(
+        &window("ApplicationFrameWindow", "Settings"),
+        &rules
+    ));
 }
 
 #[test]
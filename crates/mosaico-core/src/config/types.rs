@@ -4,9 +4,10 @@
 /// across the configuration subsystem.
 use std::collections::HashMap;
 
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 
-use crate::layout::LayoutKind;
+use crate::action::{DEFAULT_WORKSPACES, MAX_WORKSPACES, WorkspaceRef};
+use crate::layout::{self, LayoutKind};
 
 /// Layout algorithm settings.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -16,13 +17,22 @@ pub struct LayoutConfig {
     pub gap: i32,
     /// Ratio of space given to the first window in each split (0.0–1.0).
     pub ratio: f64,
+    /// How much one `resize-*` action changes a split ratio.
+    pub resize_step: f64,
+    /// Windows in the master pane of the master/stack layouts.
+    pub master_count: usize,
     /// How windows are hidden during workspace switches.
     pub hiding: HidingBehaviour,
     /// Default layout for workspaces without an explicit override.
     pub default: LayoutKind,
-    /// Per-workspace layout overrides (workspace number 1–8 → layout).
-    #[serde(default)]
+    /// Per-workspace layout overrides (workspace number 1–255 → layout).
+    #[serde(default, deserialize_with = "workspace_map")]
     pub workspaces: HashMap<u8, LayoutKind>,
+    /// Layouts `cycle-layout` steps through, in order.
+    pub cycle: Vec<LayoutKind>,
+    /// Per-workspace cycle overrides (workspace number 1–255 → layouts).
+    #[serde(default, deserialize_with = "workspace_map")]
+    pub workspace_cycles: HashMap<u8, Vec<LayoutKind>>,
 }
 
 impl Default for LayoutConfig {
@@ -30,13 +40,139 @@ impl Default for LayoutConfig {
         Self {
             gap: 8,
             ratio: 0.5,
+            resize_step: 0.05,
+            master_count: 1,
             hiding: HidingBehaviour::default(),
             default: LayoutKind::default(),
             workspaces: HashMap::new(),
+            cycle: layout::default_cycle(),
+            workspace_cycles: HashMap::new(),
         }
     }
 }
 
+/// Workspace count and names.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WorkspacesConfig {
+    /// Workspaces on each monitor.
+    pub count: u8,
+    /// Per-monitor count overrides (monitor number 1-based, in
+    /// left-to-right order → count).
+    #[serde(default, deserialize_with = "monitor_map")]
+    pub monitors: HashMap<u8, u8>,
+    /// Workspace names, in order. Workspaces past the end of the list,
+    /// or whose name is empty, are shown by number.
+    pub names: Vec<String>,
+    /// Whether `goto-workspace-N` on the visible workspace switches
+    /// back to the previously visible one (i3's back-and-forth).
+    pub back_and_forth: bool,
+    /// Whether `goto-workspace-next`/`-prev` pass over empty workspaces.
+    pub skip_empty: bool,
+}
+
+impl Default for WorkspacesConfig {
+    fn default() -> Self {
+        Self {
+            count: DEFAULT_WORKSPACES,
+            monitors: HashMap::new(),
+            names: Vec::new(),
+            back_and_forth: false,
+            skip_empty: false,
+        }
+    }
+}
+
+impl WorkspacesConfig {
+    /// Returns how many workspaces the monitor at `monitor_idx` (0-based)
+    /// has.
+    pub fn count_for(&self, monitor_idx: usize) -> usize {
+        let num = u8::try_from(monitor_idx + 1).unwrap_or(u8::MAX);
+        usize::from(*self.monitors.get(&num).unwrap_or(&self.count))
+    }
+
+    /// Returns the display label of the workspace at `idx` (0-based):
+    /// its name, or its number if it has none.
+    pub fn label(&self, idx: usize) -> String {
+        self.names
+            .get(idx)
+            .filter(|name| !name.is_empty())
+            .cloned()
+            .unwrap_or_else(|| (idx + 1).to_string())
+    }
+
+    /// Returns the 0-based index of the workspace called `name`.
+    pub fn index_of(&self, name: &str) -> Option<usize> {
+        self.names.iter().position(|n| n == name)
+    }
+
+    /// Clamps every count to `1..=MAX_WORKSPACES` and drops names that
+    /// actions could not reach.
+    ///
+    /// A name must pass [`WorkspaceRef::is_valid_name`] and be unique;
+    /// otherwise it is logged and cleared, so the workspace is shown and
+    /// addressed by number.
+    pub(crate) fn validate(&mut self) {
+        self.count = self.count.clamp(1, MAX_WORKSPACES);
+        for count in self.monitors.values_mut() {
+            *count = (*count).clamp(1, MAX_WORKSPACES);
+        }
+        for i in 0..self.names.len() {
+            let name = &self.names[i];
+            let problem = if name.is_empty() {
+                continue;
+            } else if !WorkspaceRef::is_valid_name(name) {
+                "is not a valid workspace name"
+            } else if self.names[..i].contains(name) {
+                "is already used by another workspace"
+            } else {
+                continue;
+            };
+            crate::log_warn!(
+                "Workspace {} name {name:?} {problem}; using its number instead",
+                i + 1
+            );
+            self.names[i].clear();
+        }
+    }
+}
+
+/// Deserializes a table keyed by workspace number.
+///
+/// TOML keys are always strings, so `1 = "grid"` arrives as `"1"` and
+/// has to be parsed by hand.
+fn workspace_map<'de, D, V>(deserializer: D) -> Result<HashMap<u8, V>, D::Error>
+where
+    D: Deserializer<'de>,
+    V: Deserialize<'de>,
+{
+    number_map(deserializer, "workspace")
+}
+
+/// Deserializes a table keyed by monitor number.
+fn monitor_map<'de, D, V>(deserializer: D) -> Result<HashMap<u8, V>, D::Error>
+where
+    D: Deserializer<'de>,
+    V: Deserialize<'de>,
+{
+    number_map(deserializer, "monitor")
+}
+
+fn number_map<'de, D, V>(deserializer: D, what: &str) -> Result<HashMap<u8, V>, D::Error>
+where
+    D: Deserializer<'de>,
+    V: Deserialize<'de>,
+{
+    HashMap::<String, V>::deserialize(deserializer)?
+        .into_iter()
+        .map(|(key, value)| {
+            key.parse()
+                .map(|n| (n, value))
+                .map_err(|_| serde::de::Error::custom(format!("invalid {what} number: {key}")))
+        })
+        .collect()
+}
+
 /// How windows are hidden when switching away from their workspace.
 #[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
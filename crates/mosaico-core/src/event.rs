@@ -1,11 +1,17 @@
 use std::fmt;
 
+use serde::{Deserialize, Serialize};
+
 /// A platform-agnostic window event.
 ///
 /// These represent meaningful state changes that the tiling manager
 /// needs to react to. Platform crates translate raw OS events into
 /// these variants.
-#[derive(Debug, Clone, PartialEq)]
+///
+/// Serializes with the variant name in an `event` field, e.g.
+/// `{"event":"Created","hwnd":4096}`, for recorded traces.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "event")]
 pub enum WindowEvent {
     /// A new window was created and made visible.
     Created {
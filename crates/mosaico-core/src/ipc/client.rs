@@ -0,0 +1,166 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+use super::envelope::{ErrorCode, HelloInfo, PROTOCOL_VERSION, Request};
+use super::transport::{IpcConnection, IpcError, IpcTransport};
+use super::{Command, Response, ResponseStatus};
+use crate::{DaemonEvent, EventTopic};
+
+/// How long a client waits for the daemon to accept and to answer.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Source of request ids, unique within the process.
+static NEXT_ID: AtomicU64 = AtomicU64::new(1);
+
+/// Sends `command` to the daemon and returns its response.
+///
+/// Fails with [`IpcError::Timeout`] if the daemon does not answer within
+/// `timeout`, and with [`IpcError::VersionMismatch`] if it rejected the
+/// request because of the protocol version.
+pub fn send_command(
+    transport: &impl IpcTransport,
+    command: &Command,
+    timeout: Duration,
+) -> Result<Response, IpcError> {
+    let mut conn = transport.connect(timeout)?;
+    conn.set_read_timeout(Some(timeout))?;
+    exchange(&mut conn, command)
+}
+
+/// Asks the daemon for its protocol version and capabilities.
+pub fn hello(transport: &impl IpcTransport, timeout: Duration) -> Result<HelloInfo, IpcError> {
+    let response = send_command(transport, &Command::Hello, timeout)?;
+    response
+        .hello
+        .ok_or_else(|| IpcError::Protocol("hello response without daemon info".into()))
+}
+
+/// Subscribes to daemon events and calls `on_event` for each one.
+///
+/// An empty `topics` list subscribes to every topic. Only the
+/// acknowledgement is subject to `timeout`; afterwards this blocks until
+/// the daemon closes the stream (e.g. on shutdown) or `on_event` returns
+/// `false`.
+pub fn subscribe(
+    transport: &impl IpcTransport,
+    topics: Vec<EventTopic>,
+    timeout: Duration,
+    mut on_event: impl FnMut(DaemonEvent) -> bool,
+) -> Result<(), IpcError> {
+    let mut conn = transport.connect(timeout)?;
+    conn.set_read_timeout(Some(timeout))?;
+
+    let ack = exchange(&mut conn, &Command::Subscribe { topics })?;
+    if ack.status != ResponseStatus::Ok {
+        let message = ack.message.unwrap_or("subscription refused".into());
+        return Err(IpcError::Protocol(message));
+    }
+
+    conn.set_read_timeout(None)?;
+    while let Some(event) = conn.recv()? {
+        if !on_event(event) {
+            break;
+        }
+    }
+    Ok(())
+}
+
+/// Sends one request on `conn` and reads its response, checking the
+/// protocol version and request id.
+fn exchange(conn: &mut impl IpcConnection, command: &Command) -> Result<Response, IpcError> {
+    let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+    conn.send(&Request::new(id, command.clone()))?;
+    let response: Response = conn.recv()?.ok_or(IpcError::NoResponse)?;
+
+    // A rejection only means a version mismatch if the versions differ.
+    let mismatch = response.version != PROTOCOL_VERSION
+        && matches!(
+            response.code,
+            Some(ErrorCode::UnsupportedVersion | ErrorCode::UnknownCommand)
+        );
+    if mismatch {
+        return Err(IpcError::VersionMismatch {
+            client: PROTOCOL_VERSION,
+            daemon: response.version,
+        });
+    }
+    if response.id.is_some_and(|r| r != id) {
+        return Err(IpcError::Protocol(format!(
+            "response to request {:?} while waiting for {id}",
+            response.id
+        )));
+    }
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+
+    use super::*;
+
+    /// A connection that answers with scripted lines.
+    struct Scripted(VecDeque<&'static str>);
+
+    impl IpcConnection for Scripted {
+        fn write_line(&mut self, _line: &str) -> Result<(), IpcError> {
+            Ok(())
+        }
+
+        fn read_line(&mut self) -> Result<Option<String>, IpcError> {
+            Ok(self.0.pop_front().map(String::from))
+        }
+
+        fn set_read_timeout(&mut self, _timeout: Option<Duration>) -> Result<(), IpcError> {
+            Ok(())
+        }
+    }
+
+    fn exchange_with(lines: &[&'static str]) -> Result<Response, IpcError> {
+        exchange(
+            &mut Scripted(lines.iter().copied().collect()),
+            &Command::Status,
+        )
+    }
+
+    #[test]
+    fn legacy_daemon_hanging_up_is_no_response() {
+        let err = exchange_with(&[]).unwrap_err();
+
+        assert!(matches!(err, IpcError::NoResponse));
+        assert!(err.to_string().contains("older than this client"));
+    }
+
+    #[test]
+    fn version_rejections_become_mismatch_errors() {
+        let err =
+            exchange_with(&[r#"{"version":0,"status":"error","code":"unsupported-version"}"#])
+                .unwrap_err();
+
+        assert!(matches!(
+            err,
+            IpcError::VersionMismatch {
+                client: PROTOCOL_VERSION,
+                daemon: 0
+            }
+        ));
+        assert!(err.to_string().contains("too old"));
+    }
+
+    #[test]
+    fn rejections_from_the_same_version_are_plain_errors() {
+        let response =
+            exchange_with(&[r#"{"version":1,"status":"error","code":"unknown-command"}"#]).unwrap();
+
+        assert_eq!(response.code, Some(ErrorCode::UnknownCommand));
+    }
+
+    #[test]
+    fn unversioned_responses_are_still_accepted() {
+        let response =
+            exchange_with(&[r#"{"status":"ok","message":"Daemon is running"}"#]).unwrap();
+
+        assert_eq!(response.version, 0);
+        assert_eq!(response.message.as_deref(), Some("Daemon is running"));
+    }
+}
@@ -0,0 +1,178 @@
+use serde::{Deserialize, Serialize};
+
+use super::{Command, Response};
+
+/// Version of the IPC protocol spoken by this build.
+///
+/// Bump it when a change would make an older peer misread a message.
+/// Version `0` stands for peers that predate the [`Request`] envelope.
+pub const PROTOCOL_VERSION: u32 = 1;
+
+/// The commands this build understands, as advertised by
+/// [`Command::Hello`]. Names match the `"command"` tag on the wire.
+pub const CAPABILITIES: &[&str] = &[
+    "Stop",
+    "Status",
+    "Action",
+    "Inspect",
+    "Query",
+    "Subscribe",
+    "PauseHotkeys",
+    "UnpauseHotkeys",
+    "Hello",
+];
+
+/// A command together with its protocol version and request id.
+///
+/// On the wire the command's fields sit next to the envelope's:
+/// `{"version":1,"id":7,"command":"Status"}`. A bare command from an
+/// older client parses as version `0` without an id.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Request {
+    /// Protocol version the client speaks.
+    #[serde(default)]
+    pub version: u32,
+    /// Client-chosen id, echoed in [`Response::id`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+    /// The command to run.
+    #[serde(flatten)]
+    pub command: Command,
+}
+
+impl Request {
+    /// Wraps `command` for the current protocol version.
+    pub fn new(id: u64, command: Command) -> Self {
+        Self {
+            version: PROTOCOL_VERSION,
+            id: Some(id),
+            command,
+        }
+    }
+
+    /// Parses one request line.
+    ///
+    /// On failure returns the error response to send back, carrying the
+    /// request id when one could be read: `invalid-request` for malformed
+    /// JSON, `unsupported-version` for a client newer than this build,
+    /// and `unknown-command` for a command this build does not know.
+    pub fn parse(line: &str) -> Result<Self, Box<Response>> {
+        let value: serde_json::Value = serde_json::from_str(line.trim())
+            .map_err(|e| Box::new(Response::error(ErrorCode::InvalidRequest, e.to_string())))?;
+        let field = |name| value.get(name).and_then(serde_json::Value::as_u64);
+        let id = field("id");
+        let version = field("version").map_or(0, |v| u32::try_from(v).unwrap_or(u32::MAX));
+
+        let fail = |code, message: String| {
+            Box::new(Response {
+                id,
+                ..Response::error(code, message)
+            })
+        };
+        if version > PROTOCOL_VERSION {
+            return Err(fail(
+                ErrorCode::UnsupportedVersion,
+                format!("daemon speaks protocol {PROTOCOL_VERSION}, client {version}"),
+            ));
+        }
+        let command = Command::deserialize(&value)
+            .map_err(|e| fail(ErrorCode::UnknownCommand, e.to_string()))?;
+
+        Ok(Self {
+            version,
+            id,
+            command,
+        })
+    }
+}
+
+/// Machine-readable reason for an error [`Response`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ErrorCode {
+    /// The request was not valid JSON.
+    InvalidRequest,
+    /// The client speaks a newer protocol than the daemon.
+    UnsupportedVersion,
+    /// The daemon does not know the command, or its fields are invalid.
+    UnknownCommand,
+    /// The daemon understood the command but could not carry it out.
+    Failed,
+}
+
+/// What a daemon reports about itself in answer to [`Command::Hello`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HelloInfo {
+    /// Protocol version the daemon speaks.
+    pub protocol: u32,
+    /// The daemon's release version, e.g. `0.7.0`.
+    pub daemon_version: String,
+    /// Commands the daemon understands; see [`CAPABILITIES`].
+    pub capabilities: Vec<String>,
+}
+
+impl HelloInfo {
+    /// Describes this build.
+    pub fn current() -> Self {
+        Self {
+            protocol: PROTOCOL_VERSION,
+            daemon_version: env!("CARGO_PKG_VERSION").to_string(),
+            capabilities: CAPABILITIES.iter().map(|c| c.to_string()).collect(),
+        }
+    }
+
+    /// Returns whether the daemon understands `command`.
+    pub fn supports(&self, command: &str) -> bool {
+        self.capabilities.iter().any(|c| c == command)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Action;
+
+    #[test]
+    fn request_puts_envelope_next_to_command() {
+        let json = serde_json::to_string(&Request::new(7, Command::Status)).unwrap();
+
+        assert_eq!(json, r#"{"version":1,"id":7,"command":"Status"}"#);
+    }
+
+    #[test]
+    fn parses_versioned_and_legacy_requests() {
+        let request =
+            Request::parse(r#"{"version":1,"id":3,"command":"Action","action":"retile"}"#).unwrap();
+        assert_eq!((request.version, request.id), (1, Some(3)));
+        assert!(matches!(
+            request.command,
+            Command::Action {
+                action: Action::Retile
+            }
+        ));
+
+        let legacy = Request::parse(r#"{"command":"Stop"}"#).unwrap();
+        assert_eq!((legacy.version, legacy.id), (0, None));
+    }
+
+    #[test]
+    fn rejects_newer_versions_and_unknown_commands_with_codes() {
+        let newer = Request::parse(r#"{"version":99,"id":1,"command":"Status"}"#).unwrap_err();
+        let unknown = Request::parse(r#"{"version":1,"id":2,"command":"Teleport"}"#).unwrap_err();
+        let garbage = Request::parse("not json").unwrap_err();
+
+        assert_eq!(newer.code, Some(ErrorCode::UnsupportedVersion));
+        assert_eq!(newer.id, Some(1));
+        assert_eq!(unknown.code, Some(ErrorCode::UnknownCommand));
+        assert_eq!(unknown.id, Some(2));
+        assert_eq!(garbage.code, Some(ErrorCode::InvalidRequest));
+    }
+
+    #[test]
+    fn every_capability_is_a_command_tag() {
+        for name in CAPABILITIES {
+            let line = format!(r#"{{"command":"{name}","action":"retile"}}"#);
+            assert!(Request::parse(&line).is_ok(), "{name}");
+        }
+    }
+}
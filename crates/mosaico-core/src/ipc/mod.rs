@@ -0,0 +1,149 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{Action, EventTopic, StateSnapshot};
+
+/// Sending commands and subscribing to events from the client side.
+pub mod client;
+/// Protocol versioning: the request envelope, error codes and handshake.
+pub mod envelope;
+/// The daemon's accept loop.
+pub mod server;
+/// The transport abstraction and its error type.
+pub mod transport;
+/// Unix domain socket transport.
+#[cfg(unix)]
+pub mod unix;
+
+pub use client::{DEFAULT_TIMEOUT, hello, send_command, subscribe};
+pub use envelope::{CAPABILITIES, ErrorCode, HelloInfo, PROTOCOL_VERSION, Request};
+pub use server::{IpcRequest, serve};
+pub use transport::{IpcConnection, IpcError, IpcListener, IpcTransport};
+#[cfg(unix)]
+pub use unix::UnixSocketTransport;
+
+/// A command sent from the CLI to the daemon.
+///
+/// These are serialized as JSON and sent over an IPC channel, wrapped
+/// in a [`Request`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "command")]
+pub enum Command {
+    /// Request the daemon to stop.
+    Stop,
+    /// Request the daemon's current status.
+    Status,
+    /// Execute a tiling action (focus, swap, retile, etc.).
+    Action {
+        /// The action to execute.
+        action: Action,
+    },
+    /// Dump the daemon's internal workspace state for debugging.
+    Inspect,
+    /// Request a typed snapshot of monitors, workspaces and windows.
+    Query,
+    /// Keep the connection open and stream [`DaemonEvent`](crate::DaemonEvent)s.
+    ///
+    /// The daemon acknowledges with a [`Response`], then writes one event
+    /// per line until the client disconnects.
+    Subscribe {
+        /// Topics to receive. Empty means every topic.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        topics: Vec<EventTopic>,
+    },
+    /// Pause all hotkeys (unregister all except toggle-pause).
+    PauseHotkeys,
+    /// Re-register all hotkeys that were paused.
+    UnpauseHotkeys,
+    /// Ask for the daemon's protocol version and capabilities.
+    ///
+    /// Answered by the accept loop itself with [`Response::hello`].
+    Hello,
+}
+
+/// A response sent from the daemon back to the CLI.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Response {
+    /// Protocol version the daemon speaks; `0` from daemons that
+    /// predate versioning.
+    #[serde(default)]
+    pub version: u32,
+    /// The [`Request::id`] this answers, if the request had one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+    /// Whether the command succeeded.
+    pub status: ResponseStatus,
+    /// Machine-readable reason for an error response.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub code: Option<ErrorCode>,
+    /// Optional human-readable message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// Tiling state snapshot, for [`Command::Query`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<StateSnapshot>,
+    /// Daemon version and capabilities, for [`Command::Hello`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hello: Option<HelloInfo>,
+}
+
+/// Status of a daemon response.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ResponseStatus {
+    /// Command succeeded.
+    Ok,
+    /// Command failed.
+    Error,
+}
+
+impl Response {
+    fn new(status: ResponseStatus) -> Self {
+        Self {
+            version: PROTOCOL_VERSION,
+            id: None,
+            status,
+            code: None,
+            message: None,
+            state: None,
+            hello: None,
+        }
+    }
+
+    /// Creates a successful response with no message.
+    pub fn ok() -> Self {
+        Self::new(ResponseStatus::Ok)
+    }
+
+    /// Creates a successful response with a message.
+    pub fn ok_with_message(message: impl Into<String>) -> Self {
+        Self {
+            message: Some(message.into()),
+            ..Self::ok()
+        }
+    }
+
+    /// Creates a successful response carrying a state snapshot.
+    pub fn ok_with_state(state: StateSnapshot) -> Self {
+        Self {
+            state: Some(state),
+            ..Self::ok()
+        }
+    }
+
+    /// Creates the answer to [`Command::Hello`].
+    pub fn ok_with_hello(hello: HelloInfo) -> Self {
+        Self {
+            hello: Some(hello),
+            ..Self::ok()
+        }
+    }
+
+    /// Creates an error response.
+    pub fn error(code: ErrorCode, message: impl Into<String>) -> Self {
+        Self {
+            code: Some(code),
+            message: Some(message.into()),
+            ..Self::new(ResponseStatus::Error)
+        }
+    }
+}
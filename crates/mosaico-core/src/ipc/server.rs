@@ -0,0 +1,107 @@
+use std::sync::mpsc;
+use std::thread;
+
+use super::client::DEFAULT_TIMEOUT;
+use super::envelope::{HelloInfo, Request};
+use super::transport::{IpcConnection, IpcError, IpcListener};
+use super::{Command, Response};
+use crate::{DaemonEvent, EventTopic};
+
+/// A client request accepted by [`serve`], for the daemon's main thread.
+pub enum IpcRequest {
+    /// A command; its response goes back through the sender.
+    Command(Command, mpsc::Sender<Response>),
+    /// A subscription; events sent through the sender are streamed to
+    /// the client until it disconnects.
+    Subscribe(Vec<EventTopic>, mpsc::Sender<DaemonEvent>),
+}
+
+/// Accepts clients on `listener` and hands their requests to `forward`.
+///
+/// Each connection carries one [`Request`] and its response, which
+/// echoes the request id. Malformed, unknown or too-new requests are
+/// answered with an error code, and [`Command::Hello`] is answered here
+/// without involving the daemon. Subscriptions are moved to a thread of
+/// their own so the loop can keep accepting clients while events stream
+/// out. Returns after answering [`Command::Stop`], when `forward` returns
+/// `false` (the daemon is gone), or when the listener fails.
+pub fn serve<L: IpcListener>(
+    listener: &mut L,
+    mut forward: impl FnMut(IpcRequest) -> bool,
+) -> Result<(), IpcError> {
+    loop {
+        let mut conn = listener.accept()?;
+
+        // A client that never sends its command must not block the loop.
+        conn.set_read_timeout(Some(DEFAULT_TIMEOUT))?;
+        let line = match conn.read_line() {
+            Ok(Some(line)) => line,
+            // Probes such as `is_listening()` connect without sending.
+            Ok(None) => continue,
+            Err(e) => {
+                crate::log_warn!("Error reading IPC command: {e}");
+                continue;
+            }
+        };
+        let Request { id, command, .. } = match Request::parse(&line) {
+            Ok(request) => request,
+            Err(response) => {
+                crate::log_warn!("Rejected IPC request: {:?}", response.message);
+                let _ = conn.send(&*response);
+                continue;
+            }
+        };
+
+        let is_stop = matches!(command, Command::Stop);
+        match command {
+            Command::Hello => {
+                let _ = conn.send(&Response {
+                    id,
+                    ..Response::ok_with_hello(HelloInfo::current())
+                });
+            }
+            Command::Subscribe { topics } => {
+                let (event_tx, event_rx) = mpsc::channel();
+                if !forward(IpcRequest::Subscribe(topics, event_tx)) {
+                    return Ok(());
+                }
+                thread::spawn(move || stream_events(conn, id, event_rx));
+            }
+            command => {
+                let (reply_tx, reply_rx) = mpsc::channel();
+                if !forward(IpcRequest::Command(command, reply_tx)) {
+                    return Ok(());
+                }
+                if let Ok(response) = reply_rx.recv() {
+                    let _ = conn.send(&Response { id, ..response });
+                }
+            }
+        }
+        if is_stop {
+            return Ok(());
+        }
+    }
+}
+
+/// Acknowledges a subscription, then writes each event as a JSON line.
+///
+/// Ends when the client disconnects or the daemon drops the sender on
+/// shutdown.
+fn stream_events(
+    mut conn: impl IpcConnection,
+    id: Option<u64>,
+    events: mpsc::Receiver<DaemonEvent>,
+) {
+    let ack = Response {
+        id,
+        ..Response::ok_with_message("Subscribed")
+    };
+    if conn.send(&ack).is_err() {
+        return;
+    }
+    for event in events {
+        if conn.send(&event).is_err() {
+            return;
+        }
+    }
+}
@@ -0,0 +1,178 @@
+use std::fmt;
+use std::io::{self, BufRead, Write};
+use std::time::Duration;
+
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+/// An error from an IPC transport or the message framing on top of it.
+#[derive(Debug)]
+pub enum IpcError {
+    /// No daemon is listening on the endpoint.
+    NotRunning,
+    /// The peer did not answer within the timeout.
+    Timeout,
+    /// The peer closed the connection in the middle of an exchange.
+    Disconnected,
+    /// The daemon closed the connection without answering. Daemons that
+    /// predate protocol versioning do this for commands they don't know.
+    NoResponse,
+    /// Client and daemon speak different protocol versions. The two
+    /// versions are never equal.
+    VersionMismatch {
+        /// Protocol version of this build.
+        client: u32,
+        /// Protocol version the daemon reported.
+        daemon: u32,
+    },
+    /// A message was not valid JSON for the expected type.
+    Protocol(String),
+    /// Any other I/O failure.
+    Io(io::Error),
+}
+
+impl fmt::Display for IpcError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotRunning => f.write_str("the daemon is not running"),
+            Self::Timeout => f.write_str("timed out waiting for the daemon"),
+            Self::Disconnected => f.write_str("the connection was closed unexpectedly"),
+            Self::NoResponse => f.write_str(
+                "the daemon closed the connection without answering; \
+                 it may be older than this client, so try restarting it",
+            ),
+            Self::VersionMismatch { client, daemon } if daemon < client => write!(
+                f,
+                "the running daemon is too old (protocol {daemon}, client {client}); \
+                 restart it to use the updated version"
+            ),
+            Self::VersionMismatch { client, daemon } => write!(
+                f,
+                "the running daemon is too new (protocol {daemon}, client {client}); \
+                 update this client or restart the daemon from the same version"
+            ),
+            Self::Protocol(e) => write!(f, "invalid message: {e}"),
+            Self::Io(e) => write!(f, "I/O error: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for IpcError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for IpcError {
+    fn from(e: io::Error) -> Self {
+        match e.kind() {
+            io::ErrorKind::NotFound | io::ErrorKind::ConnectionRefused => Self::NotRunning,
+            io::ErrorKind::TimedOut | io::ErrorKind::WouldBlock => Self::Timeout,
+            io::ErrorKind::BrokenPipe
+            | io::ErrorKind::ConnectionReset
+            | io::ErrorKind::UnexpectedEof => Self::Disconnected,
+            _ => Self::Io(e),
+        }
+    }
+}
+
+impl From<serde_json::Error> for IpcError {
+    fn from(e: serde_json::Error) -> Self {
+        Self::Protocol(e.to_string())
+    }
+}
+
+/// One end of an IPC connection carrying newline-delimited JSON.
+///
+/// Implementations only move lines; [`send`](Self::send) and
+/// [`recv`](Self::recv) add the JSON framing on top.
+pub trait IpcConnection: Send {
+    /// Writes `line` followed by a newline and flushes it.
+    fn write_line(&mut self, line: &str) -> Result<(), IpcError>;
+
+    /// Reads the next line without its terminator. Returns `None` once
+    /// the peer has closed the connection.
+    fn read_line(&mut self) -> Result<Option<String>, IpcError>;
+
+    /// Bounds how long [`read_line`](Self::read_line) waits; `None`
+    /// waits forever.
+    fn set_read_timeout(&mut self, timeout: Option<Duration>) -> Result<(), IpcError>;
+
+    /// Serializes `message` as one JSON line.
+    fn send<T: Serialize>(&mut self, message: &T) -> Result<(), IpcError>
+    where
+        Self: Sized,
+    {
+        self.write_line(&serde_json::to_string(message)?)
+    }
+
+    /// Reads and deserializes the next JSON line. Returns `None` once
+    /// the peer has closed the connection.
+    fn recv<T: DeserializeOwned>(&mut self) -> Result<Option<T>, IpcError>
+    where
+        Self: Sized,
+    {
+        match self.read_line()? {
+            Some(line) => Ok(Some(serde_json::from_str(line.trim())?)),
+            None => Ok(None),
+        }
+    }
+}
+
+/// The server half of a transport, bound to its endpoint.
+pub trait IpcListener {
+    /// The connection type handed out for each client.
+    type Connection: IpcConnection + 'static;
+
+    /// Blocks until a client connects.
+    fn accept(&mut self) -> Result<Self::Connection, IpcError>;
+}
+
+/// A way for the CLI and the daemon to reach each other, such as a
+/// Windows named pipe or a Unix domain socket.
+pub trait IpcTransport {
+    /// The client connection type.
+    type Connection: IpcConnection + 'static;
+    /// The server listener type.
+    type Listener: IpcListener;
+
+    /// Claims the endpoint for the daemon.
+    fn bind(&self) -> Result<Self::Listener, IpcError>;
+
+    /// Connects to the daemon, waiting up to `timeout` for it to accept.
+    fn connect(&self, timeout: Duration) -> Result<Self::Connection, IpcError>;
+
+    /// Returns whether a daemon is listening, without sending it anything.
+    fn is_listening(&self) -> bool;
+}
+
+/// Reads one line from `reader`, stripping the terminator.
+///
+/// Shared by transports whose streams implement [`BufRead`].
+pub fn read_line_from(reader: &mut impl BufRead) -> Result<Option<String>, IpcError> {
+    let mut line = String::new();
+    if reader.read_line(&mut line)? == 0 {
+        return Ok(None);
+    }
+    if !line.ends_with('\n') {
+        return Err(IpcError::Disconnected);
+    }
+    line.pop();
+    if line.ends_with('\r') {
+        line.pop();
+    }
+    Ok(Some(line))
+}
+
+/// Writes `line` and a newline to `writer` and flushes it.
+///
+/// Shared by transports whose streams implement [`Write`].
+pub fn write_line_to(writer: &mut impl Write, line: &str) -> Result<(), IpcError> {
+    writer.write_all(line.as_bytes())?;
+    writer.write_all(b"\n")?;
+    writer.flush()?;
+    Ok(())
+}
@@ -0,0 +1,185 @@
+use std::io::{self, BufReader};
+use std::os::unix::net::{UnixListener, UnixStream};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use super::transport::{
+    IpcConnection, IpcError, IpcListener, IpcTransport, read_line_from, write_line_to,
+};
+
+/// File name of the daemon's socket.
+const SOCKET_NAME: &str = "mosaico.sock";
+
+/// IPC over a Unix domain socket at a filesystem path.
+#[derive(Debug, Clone)]
+pub struct UnixSocketTransport {
+    path: PathBuf,
+}
+
+impl UnixSocketTransport {
+    /// Creates a transport for the socket at `path`.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// Returns the socket path.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Default for UnixSocketTransport {
+    /// Uses `$XDG_RUNTIME_DIR/mosaico.sock`, or the temp directory when
+    /// `XDG_RUNTIME_DIR` is unset.
+    fn default() -> Self {
+        let dir = std::env::var_os("XDG_RUNTIME_DIR")
+            .map(PathBuf::from)
+            .unwrap_or_else(std::env::temp_dir);
+        Self::new(dir.join(SOCKET_NAME))
+    }
+}
+
+impl IpcTransport for UnixSocketTransport {
+    type Connection = UnixConnection;
+    type Listener = UnixSocketListener;
+
+    /// Binds the socket, replacing a stale socket file left behind by a
+    /// daemon that did not shut down cleanly.
+    fn bind(&self) -> Result<Self::Listener, IpcError> {
+        if self.path.exists() {
+            if self.is_listening() {
+                return Err(IpcError::Io(io::Error::new(
+                    io::ErrorKind::AddrInUse,
+                    format!("{} is already in use", self.path.display()),
+                )));
+            }
+            std::fs::remove_file(&self.path)?;
+        }
+        Ok(UnixSocketListener {
+            listener: UnixListener::bind(&self.path)?,
+            path: self.path.clone(),
+        })
+    }
+
+    /// Connects to the socket. A listening socket accepts immediately,
+    /// so `timeout` only bounds writes.
+    fn connect(&self, timeout: Duration) -> Result<Self::Connection, IpcError> {
+        let stream = UnixStream::connect(&self.path)?;
+        stream.set_write_timeout(Some(timeout))?;
+        Ok(UnixConnection::new(stream))
+    }
+
+    fn is_listening(&self) -> bool {
+        UnixStream::connect(&self.path).is_ok()
+    }
+}
+
+/// A bound daemon socket. Removes the socket file when dropped.
+pub struct UnixSocketListener {
+    listener: UnixListener,
+    path: PathBuf,
+}
+
+impl IpcListener for UnixSocketListener {
+    type Connection = UnixConnection;
+
+    fn accept(&mut self) -> Result<Self::Connection, IpcError> {
+        let (stream, _) = self.listener.accept()?;
+        Ok(UnixConnection::new(stream))
+    }
+}
+
+impl Drop for UnixSocketListener {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+/// One end of a Unix socket connection.
+pub struct UnixConnection {
+    reader: BufReader<UnixStream>,
+}
+
+impl UnixConnection {
+    fn new(stream: UnixStream) -> Self {
+        Self {
+            reader: BufReader::new(stream),
+        }
+    }
+}
+
+impl IpcConnection for UnixConnection {
+    fn write_line(&mut self, line: &str) -> Result<(), IpcError> {
+        write_line_to(self.reader.get_mut(), line)
+    }
+
+    fn read_line(&mut self) -> Result<Option<String>, IpcError> {
+        read_line_from(&mut self.reader)
+    }
+
+    fn set_read_timeout(&mut self, timeout: Option<Duration>) -> Result<(), IpcError> {
+        Ok(self.reader.get_ref().set_read_timeout(timeout)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ipc::{Command, Response};
+
+    fn transport(name: &str) -> UnixSocketTransport {
+        let file = format!("mosaico-test-{}-{name}.sock", std::process::id());
+        UnixSocketTransport::new(std::env::temp_dir().join(file))
+    }
+
+    #[test]
+    fn messages_round_trip_as_json_lines() {
+        let transport = transport("round-trip");
+        let mut listener = transport.bind().unwrap();
+        let mut client = transport.connect(Duration::from_secs(1)).unwrap();
+        let mut server = listener.accept().unwrap();
+
+        client.send(&Command::Status).unwrap();
+        let command: Command = server.recv().unwrap().unwrap();
+        server.send(&Response::ok_with_message("fine")).unwrap();
+        drop(server);
+        let response: Response = client.recv().unwrap().unwrap();
+
+        assert!(matches!(command, Command::Status));
+        assert_eq!(response.message.as_deref(), Some("fine"));
+        assert!(client.recv::<Response>().unwrap().is_none());
+    }
+
+    #[test]
+    fn read_times_out_without_an_answer() {
+        let transport = transport("timeout");
+        let mut listener = transport.bind().unwrap();
+        let mut client = transport.connect(Duration::from_secs(1)).unwrap();
+        let _server = listener.accept().unwrap();
+        client
+            .set_read_timeout(Some(Duration::from_millis(20)))
+            .unwrap();
+
+        let err = client.recv::<Response>().unwrap_err();
+
+        assert!(matches!(err, IpcError::Timeout), "{err}");
+    }
+
+    #[test]
+    fn bind_replaces_stale_socket_and_cleans_up() {
+        let transport = transport("stale");
+        drop(UnixListener::bind(transport.path()).unwrap());
+        assert!(transport.path().exists() && !transport.is_listening());
+
+        let listener = transport.bind().unwrap();
+        assert!(transport.is_listening());
+        assert!(transport.bind().is_err());
+        drop(listener);
+
+        assert!(!transport.path().exists());
+        assert!(matches!(
+            transport.connect(Duration::from_secs(1)),
+            Err(IpcError::NotRunning)
+        ));
+    }
+}
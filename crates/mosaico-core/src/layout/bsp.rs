@@ -1,4 +1,5 @@
 use crate::Rect;
+use crate::split_tree::SplitTree;
 
 use super::Layout;
 
@@ -6,7 +7,8 @@ use super::Layout;
 ///
 /// Recursively splits the available space in half, alternating between
 /// horizontal and vertical splits. The first window gets the larger
-/// partition.
+/// partition. Workspaces keep a persistent [`SplitTree`] instead; this
+/// computes the layout of a freshly appended tree.
 ///
 /// For example, with 3 windows on a 1920x1080 screen:
 /// ```text
@@ -32,59 +34,6 @@ impl Default for BspLayout {
 
 impl Layout for BspLayout {
     fn apply(&self, handles: &[usize], work_area: &Rect) -> Vec<(usize, Rect)> {
-        if handles.is_empty() {
-            return Vec::new();
-        }
-
-        let mut results = Vec::with_capacity(handles.len());
-        let padded = Rect::new(
-            work_area.x + self.gap,
-            work_area.y + self.gap,
-            (work_area.width - self.gap * 2).max(1),
-            (work_area.height - self.gap * 2).max(1),
-        );
-        self.split(handles, &padded, true, &mut results);
-        results
-    }
-}
-
-impl BspLayout {
-    fn split(
-        &self,
-        handles: &[usize],
-        area: &Rect,
-        horizontal: bool,
-        results: &mut Vec<(usize, Rect)>,
-    ) {
-        if handles.len() == 1 {
-            results.push((handles[0], *area));
-            return;
-        }
-
-        let half = self.gap / 2;
-        let (first_area, rest_area) = if horizontal {
-            let split = (area.width as f64 * self.ratio) as i32;
-            let first = Rect::new(area.x, area.y, (split - half).max(1), area.height);
-            let rest = Rect::new(
-                area.x + split + half,
-                area.y,
-                (area.width - split - half).max(1),
-                area.height,
-            );
-            (first, rest)
-        } else {
-            let split = (area.height as f64 * self.ratio) as i32;
-            let first = Rect::new(area.x, area.y, area.width, (split - half).max(1));
-            let rest = Rect::new(
-                area.x,
-                area.y + split + half,
-                area.width,
-                (area.height - split - half).max(1),
-            );
-            (first, rest)
-        };
-
-        results.push((handles[0], first_area));
-        self.split(&handles[1..], &rest_area, !horizontal, results);
+        SplitTree::from_handles(handles, self.ratio).layout(work_area, self.gap)
     }
 }
@@ -0,0 +1,42 @@
+use crate::Rect;
+
+use super::{Layout, padded, slots};
+
+/// Equal-width columns, one per window, left to right. Suits
+/// ultrawide monitors.
+///
+/// ```text
+/// +-------+-------+-------+
+/// |       |       |       |
+/// |   1   |   2   |   3   |
+/// |       |       |       |
+/// +-------+-------+-------+
+/// ```
+#[derive(Debug, Clone)]
+pub struct ColumnsLayout {
+    /// Gap in pixels between windows.
+    pub gap: i32,
+}
+
+impl Default for ColumnsLayout {
+    fn default() -> Self {
+        Self { gap: 8 }
+    }
+}
+
+impl Layout for ColumnsLayout {
+    fn apply(&self, handles: &[usize], work_area: &Rect) -> Vec<(usize, Rect)> {
+        if handles.is_empty() {
+            return Vec::new();
+        }
+
+        let padded = padded(work_area, self.gap);
+        let spacing = (self.gap / 2) * 2;
+
+        slots(padded.x, padded.width, handles.len(), spacing)
+            .into_iter()
+            .zip(handles)
+            .map(|((x, w), &hwnd)| (hwnd, Rect::new(x, padded.y, w, padded.height)))
+            .collect()
+    }
+}
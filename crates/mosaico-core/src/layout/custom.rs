@@ -0,0 +1,273 @@
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+
+use crate::Rect;
+use crate::split_tree::Orientation;
+
+use super::{Layout, LayoutKind, padded};
+
+/// One node of a custom layout tree.
+///
+/// In TOML each node is an inline table, told apart by its key:
+///
+/// ```toml
+/// { slot = 0 }                          # window 0
+/// { rest = "vertical" }                 # every unassigned window, stacked
+/// { split = "horizontal", weights = [2, 1], children = [...] }
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum LayoutNode {
+    /// The window at this index in the workspace's tiling order.
+    Slot {
+        /// Zero-based window index.
+        slot: usize,
+    },
+    /// Every window no `slot` names, split evenly in one direction.
+    Rest {
+        /// `horizontal` places the windows side by side, `vertical`
+        /// stacks them.
+        rest: Orientation,
+    },
+    /// Divides its area between its children.
+    Split {
+        /// `horizontal` places the children side by side, `vertical`
+        /// stacks them.
+        split: Orientation,
+        /// Relative size of each child. Empty means equal sizes.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        weights: Vec<f64>,
+        /// Child nodes, in order.
+        children: Vec<LayoutNode>,
+    },
+}
+
+/// A named custom layout, as declared in `layouts.toml`.
+///
+/// ```toml
+/// [[layout]]
+/// name = "wide-center"
+/// root = { split = "horizontal", weights = [1, 2, 1], children = [
+///     { rest = "vertical" }, { slot = 0 }, { slot = 1 },
+/// ] }
+/// ```
+///
+/// Nodes that receive no window (a slot past the window count, an empty
+/// rest, a split whose children are all empty) are dropped and their
+/// siblings share the space.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LayoutDefinition {
+    /// Name used in `config.toml` and `set-layout-<name>`.
+    pub name: String,
+    /// Root of the split tree.
+    pub root: LayoutNode,
+}
+
+impl LayoutDefinition {
+    /// Checks that the definition can lay out any number of windows.
+    ///
+    /// The name must be a valid layout name that is not a built-in, the
+    /// tree must contain exactly one `rest` node, no slot may appear
+    /// twice, and every split needs children with positive weights (one
+    /// per child, if given).
+    ///
+    /// # Errors
+    ///
+    /// Returns a message naming the layout and the first problem found.
+    pub fn validate(&self) -> Result<(), String> {
+        match self.name.parse::<LayoutKind>() {
+            Ok(LayoutKind::Custom(_)) => {}
+            Ok(_) => return Err(format!("layout {:?}: name is a built-in layout", self.name)),
+            Err(e) => return Err(format!("layout {:?}: {e}", self.name)),
+        }
+        let mut slots = HashSet::new();
+        let mut rests = 0;
+        check_node(&self.root, &mut slots, &mut rests)
+            .map_err(|e| format!("layout {:?}: {e}", self.name))?;
+        if rests != 1 {
+            return Err(format!(
+                "layout {:?}: needs exactly one rest node, found {rests}",
+                self.name
+            ));
+        }
+        Ok(())
+    }
+}
+
+fn check_node(
+    node: &LayoutNode,
+    slots: &mut HashSet<usize>,
+    rests: &mut usize,
+) -> Result<(), String> {
+    match node {
+        LayoutNode::Slot { slot } => {
+            if !slots.insert(*slot) {
+                return Err(format!("slot {slot} is used twice"));
+            }
+        }
+        LayoutNode::Rest { .. } => *rests += 1,
+        LayoutNode::Split {
+            weights, children, ..
+        } => {
+            if children.is_empty() {
+                return Err("split has no children".into());
+            }
+            if !weights.is_empty() && weights.len() != children.len() {
+                return Err(format!(
+                    "split has {} weights for {} children",
+                    weights.len(),
+                    children.len()
+                ));
+            }
+            if weights.iter().any(|&w| !(w > 0.0 && w.is_finite())) {
+                return Err("split weights must be positive".into());
+            }
+            for child in children {
+                check_node(child, slots, rests)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Evaluates a [`LayoutDefinition`]'s tree.
+///
+/// Window `i` goes to `{ slot = i }`; windows no slot names go to the
+/// `rest` node in order. Uses the same gap rules as the built-in
+/// layouts: a full gap around the work area, half a gap between
+/// siblings.
+#[derive(Debug, Clone)]
+pub struct CustomLayout<'a> {
+    /// Gap in pixels between windows.
+    pub gap: i32,
+    /// Root of the tree to evaluate.
+    pub root: &'a LayoutNode,
+}
+
+/// Per-evaluation window assignment.
+struct Assignment<'h> {
+    handles: &'h [usize],
+    rest: Vec<usize>,
+}
+
+impl Assignment<'_> {
+    /// Returns how many windows the node will hold.
+    fn count(&self, node: &LayoutNode) -> usize {
+        match node {
+            LayoutNode::Slot { slot } => usize::from(*slot < self.handles.len()),
+            LayoutNode::Rest { .. } => self.rest.len(),
+            LayoutNode::Split { children, .. } => children.iter().map(|c| self.count(c)).sum(),
+        }
+    }
+}
+
+impl CustomLayout<'_> {
+    fn place(
+        &self,
+        node: &LayoutNode,
+        area: Rect,
+        assignment: &Assignment,
+        results: &mut Vec<(usize, Rect)>,
+    ) {
+        let half = self.gap / 2;
+        match node {
+            LayoutNode::Slot { slot } => {
+                if let Some(&hwnd) = assignment.handles.get(*slot) {
+                    results.push((hwnd, area));
+                }
+            }
+            LayoutNode::Rest { rest } => {
+                let parts = split_area(&area, *rest, &vec![1.0; assignment.rest.len()], half);
+                results.extend(assignment.rest.iter().copied().zip(parts));
+            }
+            LayoutNode::Split {
+                split,
+                weights,
+                children,
+            } => {
+                // Empty children give up their share to their siblings.
+                let live: Vec<(&LayoutNode, f64)> = children
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, c)| assignment.count(c) > 0)
+                    .map(|(i, c)| (c, weights.get(i).copied().unwrap_or(1.0)))
+                    .collect();
+                let shares: Vec<f64> = live.iter().map(|&(_, w)| w).collect();
+                let parts = split_area(&area, *split, &shares, half);
+                for ((child, _), part) in live.into_iter().zip(parts) {
+                    self.place(child, part, assignment, results);
+                }
+            }
+        }
+    }
+}
+
+impl Layout for CustomLayout<'_> {
+    fn apply(&self, handles: &[usize], work_area: &Rect) -> Vec<(usize, Rect)> {
+        if handles.is_empty() {
+            return Vec::new();
+        }
+        let mut named = HashSet::new();
+        collect_slots(self.root, &mut named);
+        let assignment = Assignment {
+            handles,
+            rest: handles
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| !named.contains(i))
+                .map(|(_, &h)| h)
+                .collect(),
+        };
+
+        let mut results = Vec::with_capacity(handles.len());
+        self.place(
+            self.root,
+            padded(work_area, self.gap),
+            &assignment,
+            &mut results,
+        );
+        results
+    }
+}
+
+fn collect_slots(node: &LayoutNode, out: &mut HashSet<usize>) {
+    match node {
+        LayoutNode::Slot { slot } => {
+            out.insert(*slot);
+        }
+        LayoutNode::Rest { .. } => {}
+        LayoutNode::Split { children, .. } => {
+            for child in children {
+                collect_slots(child, out);
+            }
+        }
+    }
+}
+
+/// Splits `area` along `orientation` into parts sized by `weights`,
+/// separated by `spacing`.
+fn split_area(area: &Rect, orientation: Orientation, weights: &[f64], spacing: i32) -> Vec<Rect> {
+    let (start, len) = match orientation {
+        Orientation::Horizontal => (area.x, area.width),
+        Orientation::Vertical => (area.y, area.height),
+    };
+    let total: f64 = weights.iter().sum();
+    let usable = (len - spacing * (weights.len() as i32 - 1)) as f64;
+
+    let mut pos = start;
+    let mut parts = Vec::with_capacity(weights.len());
+    for (i, &w) in weights.iter().enumerate() {
+        let size = if i == weights.len() - 1 {
+            (start + len - pos).max(1)
+        } else {
+            ((usable * w / total) as i32).max(1)
+        };
+        parts.push(match orientation {
+            Orientation::Horizontal => Rect::new(pos, area.y, size, area.height),
+            Orientation::Vertical => Rect::new(area.x, pos, area.width, size),
+        });
+        pos += size + spacing;
+    }
+    parts
+}
@@ -0,0 +1,53 @@
+use crate::Rect;
+
+use super::{Layout, padded, slots};
+
+/// Grid layout with near-square cells, filled row by row.
+///
+/// Uses `ceil(sqrt(n))` columns; a last row with fewer windows
+/// stretches them to fill the full width.
+///
+/// ```text
+/// +-----------+-----------+
+/// |     1     |     2     |
+/// +-----------+-----------+
+/// |           3           |
+/// +-----------------------+
+/// ```
+#[derive(Debug, Clone)]
+pub struct GridLayout {
+    /// Gap in pixels between windows.
+    pub gap: i32,
+}
+
+impl Default for GridLayout {
+    fn default() -> Self {
+        Self { gap: 8 }
+    }
+}
+
+impl Layout for GridLayout {
+    fn apply(&self, handles: &[usize], work_area: &Rect) -> Vec<(usize, Rect)> {
+        if handles.is_empty() {
+            return Vec::new();
+        }
+
+        let padded = padded(work_area, self.gap);
+        let spacing = (self.gap / 2) * 2;
+
+        let count = handles.len();
+        let cols = (count as f64).sqrt().ceil() as usize;
+        let rows = count.div_ceil(cols);
+
+        let mut results = Vec::with_capacity(count);
+        let row_slots = slots(padded.y, padded.height, rows, spacing);
+        for (row, (y, h)) in handles.chunks(cols).zip(row_slots) {
+            let col_slots = slots(padded.x, padded.width, row.len(), spacing);
+            for (&hwnd, (x, w)) in row.iter().zip(col_slots) {
+                results.push((hwnd, Rect::new(x, y, w, h)));
+            }
+        }
+
+        results
+    }
+}
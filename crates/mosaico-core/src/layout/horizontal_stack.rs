@@ -0,0 +1,76 @@
+use crate::Rect;
+
+use super::{Layout, padded, slots};
+
+/// Master/stack layout with a master pane on top and remaining
+/// windows side by side below it. Suits portrait monitors.
+///
+/// The first `master_count` windows share the master pane, side by
+/// side.
+///
+/// ```text
+/// +-----------------------+
+/// |       1 (master)      |
+/// +-----------+-----------+
+/// |     2     |     3     |
+/// +-----------+-----------+
+/// ```
+#[derive(Debug, Clone)]
+pub struct HorizontalStackLayout {
+    /// Gap in pixels between windows.
+    pub gap: i32,
+    /// Ratio of height given to the master pane (0.0–1.0).
+    pub ratio: f64,
+    /// Number of windows in the master pane (at least 1).
+    pub master_count: usize,
+}
+
+impl Default for HorizontalStackLayout {
+    fn default() -> Self {
+        Self {
+            gap: 8,
+            ratio: 0.5,
+            master_count: 1,
+        }
+    }
+}
+
+impl Layout for HorizontalStackLayout {
+    fn apply(&self, handles: &[usize], work_area: &Rect) -> Vec<(usize, Rect)> {
+        if handles.is_empty() {
+            return Vec::new();
+        }
+
+        let padded = padded(work_area, self.gap);
+        let half = self.gap / 2;
+        let masters = self.master_count.max(1);
+
+        // Not enough windows for a stack: the masters fill the area.
+        if handles.len() <= masters {
+            let columns = slots(padded.x, padded.width, handles.len(), half);
+            return handles
+                .iter()
+                .zip(columns)
+                .map(|(&hwnd, (x, w))| (hwnd, Rect::new(x, padded.y, w, padded.height)))
+                .collect();
+        }
+
+        let master_h = (padded.height as f64 * self.ratio) as i32;
+        let master_y_h = (padded.y, (master_h - half).max(1));
+        let stack_y_h = (
+            padded.y + master_h + half,
+            (padded.height - master_h - half).max(1),
+        );
+
+        let (master_handles, stack_handles) = handles.split_at(masters);
+        let mut results = Vec::with_capacity(handles.len());
+        for (row, (y, h)) in [(master_handles, master_y_h), (stack_handles, stack_y_h)] {
+            let columns = slots(padded.x, padded.width, row.len(), half);
+            for (&hwnd, (x, w)) in row.iter().zip(columns) {
+                results.push((hwnd, Rect::new(x, y, w, h)));
+            }
+        }
+
+        results
+    }
+}
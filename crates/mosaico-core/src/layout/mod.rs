@@ -1,48 +1,172 @@
 mod bsp;
+mod columns;
+mod custom;
+mod grid;
+mod horizontal_stack;
+mod rows;
 mod three_column;
 mod vertical_stack;
 
+use std::fmt;
+use std::str::FromStr;
+
 use serde::{Deserialize, Serialize};
 
 use crate::Rect;
 
 pub use bsp::BspLayout;
+pub use columns::ColumnsLayout;
+pub use custom::{CustomLayout, LayoutDefinition, LayoutNode};
+pub use grid::GridLayout;
+pub use horizontal_stack::HorizontalStackLayout;
+pub use rows::RowsLayout;
 pub use three_column::ThreeColumnLayout;
 pub use vertical_stack::VerticalStackLayout;
 
 /// Available tiling layout algorithms.
-#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "kebab-case")]
+///
+/// Serialized as its kebab-case name (`"bsp"`, `"three-column"`, ...).
+/// Any other valid name refers to a custom layout from `layouts.toml`.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
 pub enum LayoutKind {
     /// Binary Space Partitioning — recursive subdivision.
     #[default]
     Bsp,
     /// Master pane on the left, vertical stack on the right.
     VerticalStack,
+    /// Master pane on top, horizontal stack below.
+    HorizontalStack,
     /// Master pane in the center, stacks on both sides.
     ThreeColumn,
+    /// Near-square cells, filled row by row.
+    Grid,
+    /// One equal-width column per window.
+    Columns,
+    /// One equal-height row per window.
+    Rows,
+    /// A user-defined layout, by name (see [`LayoutDefinition`]).
+    Custom(String),
 }
 
 impl LayoutKind {
-    /// Returns the next layout in the cycle.
-    pub fn next(self) -> Self {
-        match self {
-            Self::Bsp => Self::VerticalStack,
-            Self::VerticalStack => Self::ThreeColumn,
-            Self::ThreeColumn => Self::Bsp,
+    /// Every built-in layout, in declaration order.
+    pub const ALL: [LayoutKind; 7] = [
+        Self::Bsp,
+        Self::VerticalStack,
+        Self::HorizontalStack,
+        Self::ThreeColumn,
+        Self::Grid,
+        Self::Columns,
+        Self::Rows,
+    ];
+
+    /// Returns the layout after this one in `cycle`, wrapping around.
+    ///
+    /// A layout missing from the cycle moves to its first entry; an
+    /// empty cycle keeps the current layout.
+    pub fn next(&self, cycle: &[LayoutKind]) -> Self {
+        match cycle.iter().position(|k| k == self) {
+            Some(i) => cycle[(i + 1) % cycle.len()].clone(),
+            None => cycle.first().unwrap_or(self).clone(),
+        }
+    }
+
+    /// Returns the layout before this one in `cycle`, wrapping around.
+    ///
+    /// A layout missing from the cycle moves to its last entry; an
+    /// empty cycle keeps the current layout.
+    pub fn prev(&self, cycle: &[LayoutKind]) -> Self {
+        match cycle.iter().position(|k| k == self) {
+            Some(i) => cycle[(i + cycle.len() - 1) % cycle.len()].clone(),
+            None => cycle.last().unwrap_or(self).clone(),
         }
     }
 
     /// Short display name for the status bar.
-    pub fn name(self) -> &'static str {
+    ///
+    /// Custom layouts show their own name.
+    pub fn name(&self) -> &str {
         match self {
             Self::Bsp => "BSP",
             Self::VerticalStack => "VStack",
+            Self::HorizontalStack => "HStack",
             Self::ThreeColumn => "3Col",
+            Self::Grid => "Grid",
+            Self::Columns => "Cols",
+            Self::Rows => "Rows",
+            Self::Custom(name) => name,
+        }
+    }
+}
+
+impl FromStr for LayoutKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "bsp" => Ok(Self::Bsp),
+            "vertical-stack" => Ok(Self::VerticalStack),
+            "horizontal-stack" => Ok(Self::HorizontalStack),
+            "three-column" => Ok(Self::ThreeColumn),
+            "grid" => Ok(Self::Grid),
+            "columns" => Ok(Self::Columns),
+            "rows" => Ok(Self::Rows),
+            _ if is_layout_name(s) => Ok(Self::Custom(s.to_string())),
+            _ => Err(format!("invalid layout name: {s:?}")),
         }
     }
 }
 
+impl fmt::Display for LayoutKind {
+    /// Writes the kebab-case name used in config files and actions.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Bsp => "bsp",
+            Self::VerticalStack => "vertical-stack",
+            Self::HorizontalStack => "horizontal-stack",
+            Self::ThreeColumn => "three-column",
+            Self::Grid => "grid",
+            Self::Columns => "columns",
+            Self::Rows => "rows",
+            Self::Custom(name) => name,
+        };
+        f.write_str(name)
+    }
+}
+
+impl TryFrom<String> for LayoutKind {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl From<LayoutKind> for String {
+    fn from(kind: LayoutKind) -> String {
+        kind.to_string()
+    }
+}
+
+/// Returns whether `name` is a valid layout name: lowercase ASCII
+/// letters, digits and dashes, starting with a letter.
+fn is_layout_name(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_lowercase())
+        && name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+}
+
+/// Returns the layouts `cycle-layout` steps through by default.
+pub fn default_cycle() -> Vec<LayoutKind> {
+    vec![
+        LayoutKind::Bsp,
+        LayoutKind::VerticalStack,
+        LayoutKind::ThreeColumn,
+    ]
+}
+
 /// A layout algorithm that computes window positions within a work area.
 ///
 /// Given a list of window handles and the available space, a layout
@@ -55,5 +179,36 @@ pub trait Layout {
     fn apply(&self, handles: &[usize], work_area: &Rect) -> Vec<(usize, Rect)>;
 }
 
+/// Shrinks the work area by `gap` on every side.
+fn padded(work_area: &Rect, gap: i32) -> Rect {
+    Rect::new(
+        work_area.x + gap,
+        work_area.y + gap,
+        (work_area.width - gap * 2).max(1),
+        (work_area.height - gap * 2).max(1),
+    )
+}
+
+/// Splits `len` pixels from `start` into `count` equal slots separated
+/// by `spacing`, returned as `(position, size)`.
+///
+/// The last slot absorbs rounding so the slots end exactly at
+/// `start + len`.
+fn slots(start: i32, len: i32, count: usize, spacing: i32) -> Vec<(i32, i32)> {
+    let count = count.max(1) as i32;
+    let size = (len - spacing * (count - 1)) / count;
+    (0..count)
+        .map(|i| {
+            let pos = start + i * (size + spacing);
+            let size = if i == count - 1 {
+                (start + len - pos).max(1)
+            } else {
+                size.max(1)
+            };
+            (pos, size)
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,44 @@
+use crate::Rect;
+
+use super::{Layout, padded, slots};
+
+/// Equal-height rows, one per window, top to bottom. Suits portrait
+/// monitors.
+///
+/// ```text
+/// +-----------------------+
+/// |           1           |
+/// +-----------------------+
+/// |           2           |
+/// +-----------------------+
+/// |           3           |
+/// +-----------------------+
+/// ```
+#[derive(Debug, Clone)]
+pub struct RowsLayout {
+    /// Gap in pixels between windows.
+    pub gap: i32,
+}
+
+impl Default for RowsLayout {
+    fn default() -> Self {
+        Self { gap: 8 }
+    }
+}
+
+impl Layout for RowsLayout {
+    fn apply(&self, handles: &[usize], work_area: &Rect) -> Vec<(usize, Rect)> {
+        if handles.is_empty() {
+            return Vec::new();
+        }
+
+        let padded = padded(work_area, self.gap);
+        let spacing = (self.gap / 2) * 2;
+
+        slots(padded.y, padded.height, handles.len(), spacing)
+            .into_iter()
+            .zip(handles)
+            .map(|((y, h), &hwnd)| (hwnd, Rect::new(padded.x, y, padded.width, h)))
+            .collect()
+    }
+}
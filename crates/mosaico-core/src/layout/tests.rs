@@ -61,23 +61,68 @@ fn large_gap_never_produces_negative_dimensions() {
 
 #[test]
 fn layout_kind_cycles() {
-    assert_eq!(LayoutKind::Bsp.next(), LayoutKind::VerticalStack);
-    assert_eq!(LayoutKind::VerticalStack.next(), LayoutKind::ThreeColumn);
-    assert_eq!(LayoutKind::ThreeColumn.next(), LayoutKind::Bsp);
+    let cycle = default_cycle();
+    assert_eq!(LayoutKind::Bsp.next(&cycle), LayoutKind::VerticalStack);
+    assert_eq!(
+        LayoutKind::VerticalStack.next(&cycle),
+        LayoutKind::ThreeColumn
+    );
+    assert_eq!(LayoutKind::ThreeColumn.next(&cycle), LayoutKind::Bsp);
+}
+
+#[test]
+fn layout_kind_outside_cycle_jumps_to_first() {
+    let cycle = [LayoutKind::Grid, LayoutKind::Rows];
+    assert_eq!(LayoutKind::Bsp.next(&cycle), LayoutKind::Grid);
+    assert_eq!(LayoutKind::Rows.next(&cycle), LayoutKind::Grid);
+    assert_eq!(LayoutKind::Columns.next(&[]), LayoutKind::Columns);
+}
+
+#[test]
+fn layout_kind_cycles_backwards() {
+    let cycle = default_cycle();
+    assert_eq!(LayoutKind::Bsp.prev(&cycle), LayoutKind::ThreeColumn);
+    assert_eq!(
+        LayoutKind::ThreeColumn.prev(&cycle),
+        LayoutKind::VerticalStack
+    );
+    assert_eq!(LayoutKind::Grid.prev(&cycle), LayoutKind::ThreeColumn);
+    assert_eq!(LayoutKind::Grid.prev(&[]), LayoutKind::Grid);
+}
+
+#[test]
+fn layout_kind_parses_its_display_name() {
+    for kind in LayoutKind::ALL {
+        assert_eq!(kind.to_string().parse::<LayoutKind>(), Ok(kind));
+    }
+    assert_eq!(
+        "spiral".parse::<LayoutKind>(),
+        Ok(LayoutKind::Custom("spiral".into()))
+    );
+    assert!("Not Valid".parse::<LayoutKind>().is_err());
+    assert!("-dash".parse::<LayoutKind>().is_err());
 }
 
 #[test]
 fn layout_kind_names() {
     assert_eq!(LayoutKind::Bsp.name(), "BSP");
     assert_eq!(LayoutKind::VerticalStack.name(), "VStack");
+    assert_eq!(LayoutKind::HorizontalStack.name(), "HStack");
     assert_eq!(LayoutKind::ThreeColumn.name(), "3Col");
+    assert_eq!(LayoutKind::Grid.name(), "Grid");
+    assert_eq!(LayoutKind::Columns.name(), "Cols");
+    assert_eq!(LayoutKind::Rows.name(), "Rows");
 }
 
 // -- VerticalStack tests --
 
 #[test]
 fn vstack_single_window_fills_work_area() {
-    let layout = VerticalStackLayout { gap: 0, ratio: 0.5 };
+    let layout = VerticalStackLayout {
+        gap: 0,
+        ratio: 0.5,
+        master_count: 1,
+    };
     let area = Rect::new(0, 0, 1920, 1080);
     let result = layout.apply(&[1], &area);
 
@@ -87,7 +132,11 @@ fn vstack_single_window_fills_work_area() {
 
 #[test]
 fn vstack_two_windows_master_and_stack() {
-    let layout = VerticalStackLayout { gap: 0, ratio: 0.5 };
+    let layout = VerticalStackLayout {
+        gap: 0,
+        ratio: 0.5,
+        master_count: 1,
+    };
     let area = Rect::new(0, 0, 1920, 1080);
     let result = layout.apply(&[1, 2], &area);
 
@@ -98,7 +147,11 @@ fn vstack_two_windows_master_and_stack() {
 
 #[test]
 fn vstack_three_windows_stack_splits_equally() {
-    let layout = VerticalStackLayout { gap: 0, ratio: 0.5 };
+    let layout = VerticalStackLayout {
+        gap: 0,
+        ratio: 0.5,
+        master_count: 1,
+    };
     let area = Rect::new(0, 0, 1920, 1080);
     let result = layout.apply(&[1, 2, 3], &area);
 
@@ -110,7 +163,11 @@ fn vstack_three_windows_stack_splits_equally() {
 
 #[test]
 fn vstack_five_windows() {
-    let layout = VerticalStackLayout { gap: 0, ratio: 0.5 };
+    let layout = VerticalStackLayout {
+        gap: 0,
+        ratio: 0.5,
+        master_count: 1,
+    };
     let area = Rect::new(0, 0, 1920, 1080);
     let result = layout.apply(&[1, 2, 3, 4, 5], &area);
 
@@ -132,11 +189,44 @@ fn vstack_empty_returns_empty() {
     assert!(layout.apply(&[], &area).is_empty());
 }
 
+#[test]
+fn vstack_two_masters_share_left_column() {
+    let layout = VerticalStackLayout {
+        gap: 0,
+        ratio: 0.5,
+        master_count: 2,
+    };
+    let area = Rect::new(0, 0, 1920, 1080);
+    let result = layout.apply(&[1, 2, 3], &area);
+
+    assert_eq!(result[0], (1, Rect::new(0, 0, 960, 540)));
+    assert_eq!(result[1], (2, Rect::new(0, 540, 960, 540)));
+    assert_eq!(result[2], (3, Rect::new(960, 0, 960, 1080)));
+}
+
+#[test]
+fn vstack_masters_fill_area_without_stack() {
+    let layout = VerticalStackLayout {
+        gap: 0,
+        ratio: 0.5,
+        master_count: 3,
+    };
+    let area = Rect::new(0, 0, 1920, 1080);
+    let result = layout.apply(&[1, 2], &area);
+
+    assert_eq!(result[0], (1, Rect::new(0, 0, 1920, 540)));
+    assert_eq!(result[1], (2, Rect::new(0, 540, 1920, 540)));
+}
+
 // -- ThreeColumn tests --
 
 #[test]
 fn three_col_single_window_fills_area() {
-    let layout = ThreeColumnLayout { gap: 0, ratio: 0.5 };
+    let layout = ThreeColumnLayout {
+        gap: 0,
+        ratio: 0.5,
+        master_count: 1,
+    };
     let area = Rect::new(0, 0, 1920, 1080);
     let result = layout.apply(&[1], &area);
 
@@ -146,7 +236,11 @@ fn three_col_single_window_fills_area() {
 
 #[test]
 fn three_col_two_windows_master_and_right() {
-    let layout = ThreeColumnLayout { gap: 0, ratio: 0.5 };
+    let layout = ThreeColumnLayout {
+        gap: 0,
+        ratio: 0.5,
+        master_count: 1,
+    };
     let area = Rect::new(0, 0, 1920, 1080);
     let result = layout.apply(&[1, 2], &area);
 
@@ -157,7 +251,11 @@ fn three_col_two_windows_master_and_right() {
 
 #[test]
 fn three_col_three_windows_center_master() {
-    let layout = ThreeColumnLayout { gap: 0, ratio: 0.5 };
+    let layout = ThreeColumnLayout {
+        gap: 0,
+        ratio: 0.5,
+        master_count: 1,
+    };
     let area = Rect::new(0, 0, 1920, 1080);
     let result = layout.apply(&[1, 2, 3], &area);
 
@@ -172,7 +270,11 @@ fn three_col_three_windows_center_master() {
 
 #[test]
 fn three_col_five_windows_alternates_sides() {
-    let layout = ThreeColumnLayout { gap: 0, ratio: 0.5 };
+    let layout = ThreeColumnLayout {
+        gap: 0,
+        ratio: 0.5,
+        master_count: 1,
+    };
     let area = Rect::new(0, 0, 1920, 1080);
     let result = layout.apply(&[1, 2, 3, 4, 5], &area);
 
@@ -194,3 +296,328 @@ fn three_col_empty_returns_empty() {
     let area = Rect::new(0, 0, 1920, 1080);
     assert!(layout.apply(&[], &area).is_empty());
 }
+
+#[test]
+fn three_col_two_masters_share_center_column() {
+    let layout = ThreeColumnLayout {
+        gap: 0,
+        ratio: 0.5,
+        master_count: 2,
+    };
+    let area = Rect::new(0, 0, 1920, 1080);
+    let result = layout.apply(&[1, 2, 3, 4], &area);
+
+    assert_eq!(result[0], (1, Rect::new(480, 0, 960, 540)));
+    assert_eq!(result[1], (2, Rect::new(480, 540, 960, 540)));
+    assert_eq!(result[2], (3, Rect::new(0, 0, 480, 1080)));
+    assert_eq!(result[3], (4, Rect::new(1440, 0, 480, 1080)));
+}
+
+#[test]
+fn three_col_one_extra_goes_right_of_masters() {
+    let layout = ThreeColumnLayout {
+        gap: 0,
+        ratio: 0.5,
+        master_count: 2,
+    };
+    let area = Rect::new(0, 0, 1920, 1080);
+    let result = layout.apply(&[1, 2, 3], &area);
+
+    assert_eq!(result[0], (1, Rect::new(0, 0, 960, 540)));
+    assert_eq!(result[1], (2, Rect::new(0, 540, 960, 540)));
+    assert_eq!(result[2], (3, Rect::new(960, 0, 960, 1080)));
+}
+
+// -- HorizontalStack tests --
+
+#[test]
+fn hstack_single_window_fills_work_area() {
+    let layout = HorizontalStackLayout {
+        gap: 0,
+        ratio: 0.5,
+        master_count: 1,
+    };
+    let area = Rect::new(0, 0, 1920, 1080);
+    let result = layout.apply(&[1], &area);
+
+    assert_eq!(result, vec![(1, Rect::new(0, 0, 1920, 1080))]);
+}
+
+#[test]
+fn hstack_master_on_top_stack_below() {
+    let layout = HorizontalStackLayout {
+        gap: 0,
+        ratio: 0.5,
+        master_count: 1,
+    };
+    let area = Rect::new(0, 0, 1920, 1080);
+    let result = layout.apply(&[1, 2, 3], &area);
+
+    assert_eq!(result.len(), 3);
+    assert_eq!(result[0], (1, Rect::new(0, 0, 1920, 540)));
+    assert_eq!(result[1], (2, Rect::new(0, 540, 960, 540)));
+    assert_eq!(result[2], (3, Rect::new(960, 540, 960, 540)));
+}
+
+#[test]
+fn hstack_with_gap_insets_edges() {
+    let layout = HorizontalStackLayout {
+        gap: 10,
+        ratio: 0.5,
+        master_count: 1,
+    };
+    let area = Rect::new(0, 0, 1920, 1080);
+    let result = layout.apply(&[1, 2], &area);
+
+    assert_eq!(result[0].1.x, 10);
+    assert_eq!(result[0].1.y, 10);
+    assert_eq!(result[0].1.width, 1900);
+    let master_bottom = result[0].1.y + result[0].1.height;
+    assert_eq!(result[1].1.y - master_bottom, 10);
+    assert_eq!(result[1].1.y + result[1].1.height, 1070);
+}
+
+#[test]
+fn hstack_empty_returns_empty() {
+    let layout = HorizontalStackLayout::default();
+    let area = Rect::new(0, 0, 1920, 1080);
+    assert!(layout.apply(&[], &area).is_empty());
+}
+
+// -- Grid tests --
+
+#[test]
+fn grid_four_windows_two_by_two() {
+    let layout = GridLayout { gap: 0 };
+    let area = Rect::new(0, 0, 1920, 1080);
+    let result = layout.apply(&[1, 2, 3, 4], &area);
+
+    assert_eq!(result[0], (1, Rect::new(0, 0, 960, 540)));
+    assert_eq!(result[1], (2, Rect::new(960, 0, 960, 540)));
+    assert_eq!(result[2], (3, Rect::new(0, 540, 960, 540)));
+    assert_eq!(result[3], (4, Rect::new(960, 540, 960, 540)));
+}
+
+#[test]
+fn grid_last_row_stretches_to_fill() {
+    let layout = GridLayout { gap: 0 };
+    let area = Rect::new(0, 0, 1920, 1080);
+    let result = layout.apply(&[1, 2, 3], &area);
+
+    assert_eq!(result[0], (1, Rect::new(0, 0, 960, 540)));
+    assert_eq!(result[1], (2, Rect::new(960, 0, 960, 540)));
+    assert_eq!(result[2], (3, Rect::new(0, 540, 1920, 540)));
+}
+
+#[test]
+fn grid_with_gap_keeps_uniform_spacing() {
+    let layout = GridLayout { gap: 10 };
+    let area = Rect::new(0, 0, 1920, 1080);
+    let result = layout.apply(&[1, 2, 3, 4], &area);
+
+    assert_eq!(result[0].1, Rect::new(10, 10, 945, 525));
+    assert_eq!(result[1].1.x - (result[0].1.x + result[0].1.width), 10);
+    assert_eq!(result[2].1.y - (result[0].1.y + result[0].1.height), 10);
+    assert_eq!(result[3].1.x + result[3].1.width, 1910);
+    assert_eq!(result[3].1.y + result[3].1.height, 1070);
+}
+
+#[test]
+fn grid_large_gap_never_produces_negative_dimensions() {
+    let layout = GridLayout { gap: 500 };
+    let area = Rect::new(0, 0, 200, 200);
+    let result = layout.apply(&[1, 2, 3, 4, 5], &area);
+
+    for (_hwnd, rect) in &result {
+        assert!(rect.width > 0, "width was {}", rect.width);
+        assert!(rect.height > 0, "height was {}", rect.height);
+    }
+}
+
+// -- Columns tests --
+
+#[test]
+fn columns_split_width_equally() {
+    let layout = ColumnsLayout { gap: 0 };
+    let area = Rect::new(0, 0, 1920, 1080);
+    let result = layout.apply(&[1, 2, 3], &area);
+
+    assert_eq!(result[0], (1, Rect::new(0, 0, 640, 1080)));
+    assert_eq!(result[1], (2, Rect::new(640, 0, 640, 1080)));
+    assert_eq!(result[2], (3, Rect::new(1280, 0, 640, 1080)));
+}
+
+#[test]
+fn columns_last_column_absorbs_rounding() {
+    let layout = ColumnsLayout { gap: 10 };
+    let area = Rect::new(0, 0, 1000, 500);
+    let result = layout.apply(&[1, 2, 3], &area);
+
+    let last = result[2].1;
+    assert_eq!(last.x + last.width, 990);
+    assert_eq!(result[1].1.x - (result[0].1.x + result[0].1.width), 10);
+}
+
+#[test]
+fn columns_empty_returns_empty() {
+    let layout = ColumnsLayout::default();
+    let area = Rect::new(0, 0, 1920, 1080);
+    assert!(layout.apply(&[], &area).is_empty());
+}
+
+// -- Rows tests --
+
+#[test]
+fn rows_split_height_equally() {
+    let layout = RowsLayout { gap: 0 };
+    let area = Rect::new(0, 0, 1920, 1080);
+    let result = layout.apply(&[1, 2, 3], &area);
+
+    assert_eq!(result[0], (1, Rect::new(0, 0, 1920, 360)));
+    assert_eq!(result[1], (2, Rect::new(0, 360, 1920, 360)));
+    assert_eq!(result[2], (3, Rect::new(0, 720, 1920, 360)));
+}
+
+#[test]
+fn rows_with_gap_insets_edges() {
+    let layout = RowsLayout { gap: 10 };
+    let area = Rect::new(0, 0, 1920, 1080);
+    let result = layout.apply(&[1, 2], &area);
+
+    assert_eq!(result[0].1, Rect::new(10, 10, 1900, 525));
+    assert_eq!(result[1].1, Rect::new(10, 545, 1900, 525));
+}
+
+#[test]
+fn rows_empty_returns_empty() {
+    let layout = RowsLayout::default();
+    let area = Rect::new(0, 0, 1920, 1080);
+    assert!(layout.apply(&[], &area).is_empty());
+}
+
+#[test]
+fn hstack_two_masters_side_by_side_on_top() {
+    let layout = HorizontalStackLayout {
+        gap: 0,
+        ratio: 0.5,
+        master_count: 2,
+    };
+    let area = Rect::new(0, 0, 1920, 1080);
+    let result = layout.apply(&[1, 2, 3], &area);
+
+    assert_eq!(result[0], (1, Rect::new(0, 0, 960, 540)));
+    assert_eq!(result[1], (2, Rect::new(960, 0, 960, 540)));
+    assert_eq!(result[2], (3, Rect::new(0, 540, 1920, 540)));
+}
+
+// -- Custom layout tests --
+
+fn wide_center() -> LayoutDefinition {
+    toml::from_str(
+        r#"
+        name = "wide-center"
+        root = { split = "horizontal", weights = [1, 2, 1], children = [
+            { rest = "vertical" }, { slot = 0 }, { slot = 1 },
+        ] }
+        "#,
+    )
+    .unwrap()
+}
+
+#[test]
+fn custom_places_slots_and_rest() {
+    let def = wide_center();
+    let layout = CustomLayout {
+        gap: 0,
+        root: &def.root,
+    };
+    let area = Rect::new(0, 0, 1920, 1080);
+    let result = layout.apply(&[1, 2, 3, 4], &area);
+
+    assert_eq!(result.len(), 4);
+    assert!(result.contains(&(1, Rect::new(480, 0, 960, 1080))));
+    assert!(result.contains(&(2, Rect::new(1440, 0, 480, 1080))));
+    assert!(result.contains(&(3, Rect::new(0, 0, 480, 540))));
+    assert!(result.contains(&(4, Rect::new(0, 540, 480, 540))));
+}
+
+#[test]
+fn custom_empty_nodes_give_space_to_siblings() {
+    let def = wide_center();
+    let layout = CustomLayout {
+        gap: 0,
+        root: &def.root,
+    };
+    let area = Rect::new(0, 0, 1920, 1080);
+
+    let one = layout.apply(&[1], &area);
+    let two = layout.apply(&[1, 2], &area);
+
+    assert_eq!(one, vec![(1, area)]);
+    assert_eq!(
+        two,
+        vec![
+            (1, Rect::new(0, 0, 1280, 1080)),
+            (2, Rect::new(1280, 0, 640, 1080)),
+        ]
+    );
+}
+
+#[test]
+fn custom_with_gap_insets_edges() {
+    let def = wide_center();
+    let layout = CustomLayout {
+        gap: 10,
+        root: &def.root,
+    };
+    let area = Rect::new(0, 0, 1920, 1080);
+    let result = layout.apply(&[1, 2], &area);
+
+    assert_eq!(result[0], (1, Rect::new(10, 10, 1263, 1060)));
+    assert_eq!(result[1], (2, Rect::new(1278, 10, 632, 1060)));
+}
+
+#[test]
+fn custom_empty_returns_empty() {
+    let def = wide_center();
+    let layout = CustomLayout {
+        gap: 0,
+        root: &def.root,
+    };
+    assert!(layout.apply(&[], &Rect::new(0, 0, 1920, 1080)).is_empty());
+}
+
+#[test]
+fn custom_definition_validates() {
+    let parse = |root: &str| -> LayoutDefinition {
+        toml::from_str(&format!("name = \"mine\"\nroot = {root}")).unwrap()
+    };
+
+    assert_eq!(wide_center().validate(), Ok(()));
+    assert!(parse(r#"{ slot = 0 }"#).validate().is_err());
+    assert!(
+        parse(
+            r#"{ split = "vertical", children = [{ rest = "vertical" }, { rest = "horizontal" }] }"#
+        )
+        .validate()
+        .is_err()
+    );
+    assert!(
+        parse(r#"{ split = "vertical", children = [{ slot = 0 }, { slot = 0 }, { rest = "vertical" }] }"#)
+            .validate()
+            .is_err()
+    );
+    assert!(
+        parse(r#"{ split = "vertical", weights = [1], children = [{ slot = 0 }, { rest = "vertical" }] }"#)
+            .validate()
+            .is_err()
+    );
+    assert!(
+        parse(r#"{ split = "vertical", weights = [1, 0], children = [{ slot = 0 }, { rest = "vertical" }] }"#)
+            .validate()
+            .is_err()
+    );
+    let mut builtin = wide_center();
+    builtin.name = "grid".into();
+    assert!(builtin.validate().is_err());
+}
@@ -1,10 +1,13 @@
 use crate::Rect;
 
-use super::Layout;
+use super::{Layout, padded, slots};
 
 /// Three-column layout with a master pane in the center and stacks on
 /// both sides. Extra windows alternate between the left and right stacks.
 ///
+/// The first `master_count` windows share the center pane, stacked
+/// vertically.
+///
 /// ```text
 /// +------+-----------+------+
 /// |  2   |           |  3   |
@@ -18,11 +21,17 @@ pub struct ThreeColumnLayout {
     pub gap: i32,
     /// Ratio of width given to the center master pane (0.0–1.0).
     pub ratio: f64,
+    /// Number of windows in the master pane (at least 1).
+    pub master_count: usize,
 }
 
 impl Default for ThreeColumnLayout {
     fn default() -> Self {
-        Self { gap: 8, ratio: 0.5 }
+        Self {
+            gap: 8,
+            ratio: 0.5,
+            master_count: 1,
+        }
     }
 }
 
@@ -38,17 +47,9 @@ impl ThreeColumnLayout {
         if handles.is_empty() {
             return;
         }
-        let count = handles.len() as i32;
-        let slot_h = (area.height - half * (count - 1)) / count;
-
-        for (i, &hwnd) in handles.iter().enumerate() {
-            let sy = area.y + (i as i32) * (slot_h + half);
-            let sh = if i as i32 == count - 1 {
-                (area.y + area.height - sy).max(1)
-            } else {
-                slot_h.max(1)
-            };
-            results.push((hwnd, Rect::new(area.x, sy, area.width, sh)));
+        let rows = slots(area.y, area.height, handles.len(), half);
+        for (&hwnd, (y, h)) in handles.iter().zip(rows) {
+            results.push((hwnd, Rect::new(area.x, y, area.width, h)));
         }
     }
 }
@@ -59,22 +60,22 @@ impl Layout for ThreeColumnLayout {
             return Vec::new();
         }
 
-        let padded = Rect::new(
-            work_area.x + self.gap,
-            work_area.y + self.gap,
-            (work_area.width - self.gap * 2).max(1),
-            (work_area.height - self.gap * 2).max(1),
-        );
+        let padded = padded(work_area, self.gap);
+        let half = self.gap / 2;
+        let masters = self.master_count.max(1);
+        let mut results = Vec::with_capacity(handles.len());
 
-        if handles.len() == 1 {
-            return vec![(handles[0], padded)];
+        // Not enough windows for a stack: the masters fill the area.
+        if handles.len() <= masters {
+            self.fill_stack(handles, &padded, half, &mut results);
+            return results;
         }
 
-        let half = self.gap / 2;
+        let (master_handles, extras) = handles.split_at(masters);
+        let master_w = (padded.width as f64 * self.ratio) as i32;
 
-        // 2 windows: master left, second right.
-        if handles.len() == 2 {
-            let master_w = (padded.width as f64 * self.ratio) as i32;
+        // One extra window: masters left, extra right.
+        if extras.len() == 1 {
             let master = Rect::new(padded.x, padded.y, (master_w - half).max(1), padded.height);
             let right = Rect::new(
                 padded.x + master_w + half,
@@ -82,27 +83,24 @@ impl Layout for ThreeColumnLayout {
                 (padded.width - master_w - half).max(1),
                 padded.height,
             );
-            return vec![(handles[0], master), (handles[1], right)];
+            self.fill_stack(master_handles, &master, half, &mut results);
+            results.push((extras[0], right));
+            return results;
         }
 
-        // 3+ windows: master in center, extras alternate left/right.
-        let master_w = (padded.width as f64 * self.ratio) as i32;
+        // Two or more extras: masters in center, extras alternate left/right.
         let side_w = (padded.width - master_w - half * 2) / 2;
         let left_x = padded.x;
         let master_x = padded.x + side_w + half;
         let right_x = master_x + master_w + half;
         let right_w = (padded.x + padded.width - right_x).max(1);
 
-        let mut results = Vec::with_capacity(handles.len());
-
-        results.push((
-            handles[0],
-            Rect::new(master_x, padded.y, master_w, padded.height),
-        ));
+        let master_area = Rect::new(master_x, padded.y, master_w, padded.height);
+        self.fill_stack(master_handles, &master_area, half, &mut results);
 
         let mut left_handles = Vec::new();
         let mut right_handles = Vec::new();
-        for (i, &hwnd) in handles[1..].iter().enumerate() {
+        for (i, &hwnd) in extras.iter().enumerate() {
             if i % 2 == 0 {
                 left_handles.push(hwnd);
             } else {
@@ -1,10 +1,13 @@
 use crate::Rect;
 
-use super::Layout;
+use super::{Layout, padded, slots};
 
-/// Master/stack layout with one master pane on the left and remaining
+/// Master/stack layout with a master pane on the left and remaining
 /// windows stacked vertically on the right.
 ///
+/// The first `master_count` windows share the master pane, stacked
+/// vertically.
+///
 /// ```text
 /// +-----------+-----------+
 /// |           |     2     |
@@ -18,11 +21,17 @@ pub struct VerticalStackLayout {
     pub gap: i32,
     /// Ratio of width given to the master pane (0.0–1.0).
     pub ratio: f64,
+    /// Number of windows in the master pane (at least 1).
+    pub master_count: usize,
 }
 
 impl Default for VerticalStackLayout {
     fn default() -> Self {
-        Self { gap: 8, ratio: 0.5 }
+        Self {
+            gap: 8,
+            ratio: 0.5,
+            master_count: 1,
+        }
     }
 }
 
@@ -32,38 +41,34 @@ impl Layout for VerticalStackLayout {
             return Vec::new();
         }
 
-        let padded = Rect::new(
-            work_area.x + self.gap,
-            work_area.y + self.gap,
-            (work_area.width - self.gap * 2).max(1),
-            (work_area.height - self.gap * 2).max(1),
-        );
+        let padded = padded(work_area, self.gap);
+        let half = self.gap / 2;
+        let masters = self.master_count.max(1);
 
-        if handles.len() == 1 {
-            return vec![(handles[0], padded)];
+        // Not enough windows for a stack: the masters fill the area.
+        if handles.len() <= masters {
+            let rows = slots(padded.y, padded.height, handles.len(), half);
+            return handles
+                .iter()
+                .zip(rows)
+                .map(|(&hwnd, (y, h))| (hwnd, Rect::new(padded.x, y, padded.width, h)))
+                .collect();
         }
 
-        let half = self.gap / 2;
         let master_w = (padded.width as f64 * self.ratio) as i32;
+        let master_x_w = (padded.x, (master_w - half).max(1));
+        let stack_x_w = (
+            padded.x + master_w + half,
+            (padded.width - master_w - half).max(1),
+        );
 
-        let master = Rect::new(padded.x, padded.y, (master_w - half).max(1), padded.height);
-
-        let stack_x = padded.x + master_w + half;
-        let stack_w = (padded.width - master_w - half).max(1);
-        let stack_count = handles.len() - 1;
-        let slot_h = (padded.height - half * (stack_count as i32 - 1)) / stack_count as i32;
-
+        let (master_handles, stack_handles) = handles.split_at(masters);
         let mut results = Vec::with_capacity(handles.len());
-        results.push((handles[0], master));
-
-        for (i, &hwnd) in handles[1..].iter().enumerate() {
-            let y = padded.y + (i as i32) * (slot_h + half);
-            let h = if i == stack_count - 1 {
-                (padded.y + padded.height - y).max(1)
-            } else {
-                slot_h.max(1)
-            };
-            results.push((hwnd, Rect::new(stack_x, y, stack_w, h)));
+        for (column, (x, w)) in [(master_handles, master_x_w), (stack_handles, stack_x_w)] {
+            let rows = slots(padded.y, padded.height, column.len(), half);
+            for (&hwnd, (y, h)) in column.iter().zip(rows) {
+                results.push((hwnd, Rect::new(x, y, w, h)));
+            }
         }
 
         results
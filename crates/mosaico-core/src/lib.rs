@@ -8,7 +8,7 @@ pub mod action;
 pub mod config;
 /// Window lifecycle events from the platform.
 pub mod event;
-/// Inter-process communication protocol types.
+/// Inter-process communication protocol and transports.
 pub mod ipc;
 /// Tiling layout trait and implementations.
 pub mod layout;
@@ -16,10 +16,22 @@ pub mod layout;
 pub mod log;
 /// PID file management for the daemon.
 pub mod pid;
+/// Typed snapshots of the tiling state for IPC queries.
+pub mod query;
 /// Axis-aligned rectangle type.
 pub mod rect;
 /// Spatial direction helpers.
 pub mod spatial;
+/// Binary split tree backing the BSP layout.
+pub mod split_tree;
+/// Event topics and notifications streamed to IPC subscribers.
+pub mod subscription;
+/// Platform-agnostic tiling state machine.
+pub mod tiling;
+/// Monitor arrangement and cross-monitor neighbors.
+pub mod topology;
+/// Recorded window-event traces for replay.
+pub mod trace;
 /// Build-time version information.
 pub mod version;
 /// Platform-agnostic window abstraction.
@@ -27,11 +39,19 @@ pub mod window;
 /// Workspace state and operations.
 pub mod workspace;
 
-pub use action::{Action, Direction};
+pub use action::{Action, Direction, MonitorTarget, WorkspaceRef};
 pub use config::{BarConfig, Config};
 pub use event::WindowEvent;
 pub use ipc::{Command, Response};
-pub use layout::{BspLayout, Layout, LayoutKind, ThreeColumnLayout, VerticalStackLayout};
+pub use layout::{
+    BspLayout, ColumnsLayout, CustomLayout, GridLayout, HorizontalStackLayout, Layout,
+    LayoutDefinition, LayoutKind, LayoutNode, RowsLayout, ThreeColumnLayout, VerticalStackLayout,
+};
+pub use query::StateSnapshot;
 pub use rect::Rect;
+pub use split_tree::{Orientation, SplitTree};
+pub use subscription::{DaemonEvent, EventTopic};
+pub use tiling::{Desktop, Effect, TilingState};
+pub use topology::MonitorTopology;
 pub use window::{Window, WindowResult};
 pub use workspace::Workspace;
@@ -0,0 +1,111 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{LayoutKind, Rect};
+
+/// A snapshot of the daemon's tiling state, returned by
+/// [`Command::Query`](crate::Command::Query).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct StateSnapshot {
+    /// Every monitor, in platform order.
+    pub monitors: Vec<MonitorSnapshot>,
+    /// Index of the focused monitor.
+    pub focused_monitor: usize,
+    /// Handle of the focused window, if any.
+    pub focused_window: Option<usize>,
+}
+
+/// A monitor and its workspaces.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MonitorSnapshot {
+    /// Position in platform order (0-based).
+    pub index: usize,
+    /// Platform monitor identifier.
+    pub id: usize,
+    /// Device name reported by the platform.
+    pub name: String,
+    /// Full monitor area, including the taskbar.
+    pub bounds: Rect,
+    /// Area available for tiling.
+    pub work_area: Rect,
+    /// Number of the visible workspace (1-based).
+    pub active_workspace: usize,
+    /// Every workspace on the monitor.
+    pub workspaces: Vec<WorkspaceSnapshot>,
+}
+
+/// A workspace and its windows.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct WorkspaceSnapshot {
+    /// Index of the monitor the workspace belongs to.
+    pub monitor: usize,
+    /// Workspace number (1-based).
+    pub number: usize,
+    /// Configured name, or the number when it has none.
+    pub label: String,
+    /// Whether the workspace is the visible one on its monitor.
+    pub visible: bool,
+    /// Layout algorithm.
+    pub layout: LayoutKind,
+    /// Whether monocle mode is on.
+    pub monocle: bool,
+    /// Window filling the work area in monocle mode.
+    pub monocle_window: Option<usize>,
+    /// Tiled windows in layout order, then floating windows.
+    pub windows: Vec<WindowSnapshot>,
+}
+
+/// A managed window.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct WindowSnapshot {
+    /// Window handle.
+    pub hwnd: usize,
+    /// Index of the monitor the window is managed on.
+    pub monitor: usize,
+    /// Number of the workspace the window is on (1-based).
+    pub workspace: usize,
+    /// Window title.
+    pub title: String,
+    /// Window class name.
+    pub class: String,
+    /// Executable name of the owning process.
+    pub process: String,
+    /// Current window rectangle, if the platform reports one.
+    pub rect: Option<Rect>,
+    /// Whether the window floats above the tiles.
+    pub floating: bool,
+}
+
+/// The focused monitor and window, as shown by `mosaico query focused`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct FocusSnapshot {
+    /// Index of the focused monitor.
+    pub monitor: usize,
+    /// The focused window, if any.
+    pub window: Option<WindowSnapshot>,
+}
+
+impl StateSnapshot {
+    /// Returns every workspace across all monitors.
+    pub fn workspaces(&self) -> Vec<&WorkspaceSnapshot> {
+        self.monitors.iter().flat_map(|m| &m.workspaces).collect()
+    }
+
+    /// Returns every managed window across all monitors and workspaces.
+    pub fn windows(&self) -> Vec<&WindowSnapshot> {
+        self.workspaces()
+            .into_iter()
+            .flat_map(|ws| &ws.windows)
+            .collect()
+    }
+
+    /// Returns the focused monitor and window.
+    pub fn focused(&self) -> FocusSnapshot {
+        FocusSnapshot {
+            monitor: self.focused_monitor,
+            window: self
+                .focused_window
+                .and_then(|hwnd| self.windows().into_iter().find(|w| w.hwnd == hwnd))
+                .cloned(),
+        }
+    }
+}
@@ -1,5 +1,7 @@
+use serde::{Deserialize, Serialize};
+
 /// A rectangle representing a window's position and size.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Rect {
     /// Left edge (pixels).
     pub x: i32,
@@ -70,20 +70,30 @@ pub fn find_neighbor(
 
 /// Finds the best window to focus when entering a monitor.
 ///
-/// Picks the topmost window first, breaking ties by the edge closest
-/// to the direction of travel (leftmost when entering from the left,
-/// rightmost when entering from the right).
+/// Horizontally: picks the topmost window first, breaking ties by the
+/// edge closest to the direction of travel (leftmost when entering from
+/// the left, rightmost when entering from the right).
+///
+/// Vertically: picks the window touching the edge entered through (top
+/// edge when moving down, bottom edge when moving up), breaking ties by
+/// the leftmost.
 pub fn find_entry(positions: &[(usize, Rect)], direction: Direction) -> Option<usize> {
+    let horizontal = matches!(direction, Direction::Left | Direction::Right);
     let positive = matches!(direction, Direction::Right | Direction::Down);
     positions
         .iter()
         .max_by_key(|(_, r)| {
-            let x = if positive {
-                -r.center_x()
+            if horizontal {
+                let x = if positive {
+                    -r.center_x()
+                } else {
+                    r.center_x()
+                };
+                (-r.center_y(), x)
             } else {
-                r.center_x()
-            };
-            (-r.center_y(), x)
+                let y = if positive { -r.y } else { r.y + r.height };
+                (y, -r.center_x())
+            }
         })
         .map(|(h, _)| *h)
 }
@@ -305,6 +315,22 @@ mod tests {
         assert_eq!(find_entry(&pos, Direction::Left), Some(2));
     }
 
+    #[test]
+    fn entry_moving_down_picks_top_left() {
+        let pos = four_windows();
+        assert_eq!(find_entry(&pos, Direction::Down), Some(1));
+    }
+
+    #[test]
+    fn entry_moving_up_picks_bottom_window() {
+        let pos = vec![
+            (1, Rect::new(0, 0, 1920, 540)),
+            (2, Rect::new(0, 540, 1920, 540)),
+        ];
+        assert_eq!(find_entry(&pos, Direction::Up), Some(2));
+        assert_eq!(find_entry(&pos, Direction::Down), Some(1));
+    }
+
     #[test]
     fn entry_single_window() {
         let pos = vec![(42, Rect::new(0, 0, 1920, 1080))];
@@ -0,0 +1,638 @@
+//! Binary split tree backing the BSP layout.
+//!
+//! Every workspace keeps a [`SplitTree`]: leaves are windows, inner
+//! nodes are splits with their own orientation and ratio. New windows
+//! split an existing leaf, removing a window promotes its sibling, and
+//! the whole tree can be rotated or mirrored. The tree is serializable
+//! so it can be inspected and persisted.
+//!
+//! ```text
+//!            H 0.5                 +-----------+-----------+
+//!           /     \                |           |     2     |
+//!          1      V 0.5            |     1     +-----------+
+//!                /     \           |           |     3     |
+//!               2       3          +-----------+-----------+
+//! ```
+
+use serde::{Deserialize, Serialize};
+
+use crate::Rect;
+
+/// Smallest ratio a resize can shrink a split to.
+const MIN_RATIO: f64 = 0.1;
+/// Largest ratio a resize can grow a split to.
+const MAX_RATIO: f64 = 0.9;
+
+/// Direction in which a split divides its area.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Orientation {
+    /// Children side by side: `first` on the left, `second` on the right.
+    Horizontal,
+    /// Children stacked: `first` on top, `second` below.
+    Vertical,
+}
+
+impl Orientation {
+    /// Returns the other orientation.
+    pub fn flipped(self) -> Self {
+        match self {
+            Self::Horizontal => Self::Vertical,
+            Self::Vertical => Self::Horizontal,
+        }
+    }
+}
+
+/// A node of a [`SplitTree`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SplitNode {
+    /// A window, identified by its handle.
+    Leaf(usize),
+    /// An area divided between two subtrees.
+    Split(Split),
+}
+
+/// An inner node of a [`SplitTree`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Split {
+    /// How the area is divided.
+    pub orientation: Orientation,
+    /// Fraction of the area given to `first` (0.0–1.0).
+    pub ratio: f64,
+    /// Left or top subtree.
+    pub first: Box<SplitNode>,
+    /// Right or bottom subtree.
+    pub second: Box<SplitNode>,
+}
+
+/// A binary tree of splits whose leaves are window handles.
+///
+/// Leaf order (left to right, depth first) is the workspace's window
+/// order. A leaf that is split gets the orientation opposite to its
+/// parent's (horizontal at the root), so appending windows reproduces
+/// the classic alternating BSP spiral.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SplitTree {
+    /// Root node, or `None` when the tree holds no windows.
+    root: Option<SplitNode>,
+    /// Ratio given to newly created splits.
+    ratio: f64,
+}
+
+impl SplitTree {
+    /// Creates an empty tree whose new splits use `ratio`.
+    pub fn new(ratio: f64) -> Self {
+        Self { root: None, ratio }
+    }
+
+    /// Builds a tree by appending each handle in order.
+    pub fn from_handles(handles: &[usize], ratio: f64) -> Self {
+        let mut tree = Self::new(ratio);
+        for &hwnd in handles {
+            tree.push(hwnd);
+        }
+        tree
+    }
+
+    /// Returns the root node, if any.
+    pub fn root(&self) -> Option<&SplitNode> {
+        self.root.as_ref()
+    }
+
+    /// Returns the ratio used for new splits.
+    pub fn ratio(&self) -> f64 {
+        self.ratio
+    }
+
+    /// Sets the ratio for new splits and resets every existing split to it.
+    pub fn set_ratio(&mut self, ratio: f64) {
+        self.ratio = ratio;
+        if let Some(root) = &mut self.root {
+            root.for_each_split(&mut |split| split.ratio = ratio);
+        }
+    }
+
+    /// Returns whether the tree holds no windows.
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    /// Returns the number of windows in the tree.
+    pub fn len(&self) -> usize {
+        self.leaves().len()
+    }
+
+    /// Returns whether the tree contains the given window.
+    pub fn contains(&self, hwnd: usize) -> bool {
+        self.leaves().contains(&hwnd)
+    }
+
+    /// Returns the window handles in leaf order.
+    pub fn leaves(&self) -> Vec<usize> {
+        let mut out = Vec::new();
+        if let Some(root) = &self.root {
+            root.collect_leaves(&mut out);
+        }
+        out
+    }
+
+    /// Appends a window by splitting the last leaf.
+    ///
+    /// Returns `false` if the window is already in the tree.
+    pub fn push(&mut self, hwnd: usize) -> bool {
+        match self.leaves().last() {
+            Some(&last) => self.insert_after(last, hwnd),
+            None => {
+                self.root = Some(SplitNode::Leaf(hwnd));
+                true
+            }
+        }
+    }
+
+    /// Splits the leaf `target`, placing `hwnd` after it (right or below).
+    ///
+    /// Returns `false` if `target` is missing or `hwnd` is already present.
+    pub fn insert_after(&mut self, target: usize, hwnd: usize) -> bool {
+        self.insert(target, hwnd, false)
+    }
+
+    /// Splits the leaf `target`, placing `hwnd` before it (left or above).
+    ///
+    /// Returns `false` if `target` is missing or `hwnd` is already present.
+    pub fn insert_before(&mut self, target: usize, hwnd: usize) -> bool {
+        self.insert(target, hwnd, true)
+    }
+
+    fn insert(&mut self, target: usize, hwnd: usize, before: bool) -> bool {
+        if self.contains(hwnd) {
+            return false;
+        }
+        let ratio = self.ratio;
+        self.root
+            .as_mut()
+            .is_some_and(|root| root.split_leaf(target, hwnd, before, ratio, None))
+    }
+
+    /// Removes a window; its sibling takes over the parent split's area.
+    ///
+    /// Returns `true` if the window was found and removed.
+    pub fn remove(&mut self, hwnd: usize) -> bool {
+        match &mut self.root {
+            Some(SplitNode::Leaf(h)) if *h == hwnd => {
+                self.root = None;
+                true
+            }
+            Some(root) => root.remove(hwnd),
+            None => false,
+        }
+    }
+
+    /// Exchanges the positions of two windows.
+    ///
+    /// Returns `false` unless both windows are in the tree.
+    pub fn swap(&mut self, a: usize, b: usize) -> bool {
+        if !self.contains(a) || !self.contains(b) {
+            return false;
+        }
+        if let Some(root) = &mut self.root {
+            root.for_each_leaf(&mut |h| {
+                if *h == a {
+                    *h = b;
+                } else if *h == b {
+                    *h = a;
+                }
+            });
+        }
+        true
+    }
+
+    /// Shifts the divider of the nearest split with `orientation` that
+    /// contains `hwnd` by `delta`, keeping the ratio within 0.1–0.9.
+    ///
+    /// A positive `delta` moves the divider right (or down). Returns
+    /// `false` if no such split exists.
+    pub fn resize(&mut self, hwnd: usize, orientation: Orientation, delta: f64) -> bool {
+        self.root
+            .as_mut()
+            .and_then(|root| root.resize(hwnd, orientation, delta))
+            .unwrap_or(false)
+    }
+
+    /// Rotates the whole layout 90 degrees clockwise.
+    ///
+    /// Side-by-side splits become stacked (left goes on top); stacked
+    /// splits become side by side (top goes right).
+    pub fn rotate(&mut self) {
+        if let Some(root) = &mut self.root {
+            root.for_each_split(&mut |split| {
+                if split.orientation == Orientation::Vertical {
+                    split.mirror();
+                }
+                split.orientation = split.orientation.flipped();
+            });
+        }
+    }
+
+    /// Mirrors every split with the given orientation.
+    ///
+    /// `Horizontal` swaps left and right, `Vertical` swaps top and bottom.
+    pub fn flip(&mut self, orientation: Orientation) {
+        if let Some(root) = &mut self.root {
+            root.for_each_split(&mut |split| {
+                if split.orientation == orientation {
+                    split.mirror();
+                }
+            });
+        }
+    }
+
+    /// Computes window rectangles, in leaf order.
+    ///
+    /// `gap` pixels are left around the work area edges and between
+    /// neighboring windows.
+    pub fn layout(&self, work_area: &Rect, gap: i32) -> Vec<(usize, Rect)> {
+        let mut results = Vec::new();
+        if let Some(root) = &self.root {
+            let padded = Rect::new(
+                work_area.x + gap,
+                work_area.y + gap,
+                (work_area.width - gap * 2).max(1),
+                (work_area.height - gap * 2).max(1),
+            );
+            root.layout(&padded, gap / 2, &mut results);
+        }
+        results
+    }
+}
+
+impl Default for SplitTree {
+    fn default() -> Self {
+        Self::new(0.5)
+    }
+}
+
+impl Split {
+    /// Swaps the children, keeping each one's size.
+    fn mirror(&mut self) {
+        std::mem::swap(&mut self.first, &mut self.second);
+        self.ratio = 1.0 - self.ratio;
+    }
+
+    /// Divides `area` between the two children.
+    fn areas(&self, area: &Rect, half_gap: i32) -> (Rect, Rect) {
+        match self.orientation {
+            Orientation::Horizontal => {
+                let split = (area.width as f64 * self.ratio) as i32;
+                let first = Rect::new(area.x, area.y, (split - half_gap).max(1), area.height);
+                let second = Rect::new(
+                    area.x + split + half_gap,
+                    area.y,
+                    (area.width - split - half_gap).max(1),
+                    area.height,
+                );
+                (first, second)
+            }
+            Orientation::Vertical => {
+                let split = (area.height as f64 * self.ratio) as i32;
+                let first = Rect::new(area.x, area.y, area.width, (split - half_gap).max(1));
+                let second = Rect::new(
+                    area.x,
+                    area.y + split + half_gap,
+                    area.width,
+                    (area.height - split - half_gap).max(1),
+                );
+                (first, second)
+            }
+        }
+    }
+}
+
+impl SplitNode {
+    fn collect_leaves(&self, out: &mut Vec<usize>) {
+        match self {
+            Self::Leaf(hwnd) => out.push(*hwnd),
+            Self::Split(split) => {
+                split.first.collect_leaves(out);
+                split.second.collect_leaves(out);
+            }
+        }
+    }
+
+    fn for_each_leaf(&mut self, f: &mut impl FnMut(&mut usize)) {
+        match self {
+            Self::Leaf(hwnd) => f(hwnd),
+            Self::Split(split) => {
+                split.first.for_each_leaf(f);
+                split.second.for_each_leaf(f);
+            }
+        }
+    }
+
+    fn for_each_split(&mut self, f: &mut impl FnMut(&mut Split)) {
+        if let Self::Split(split) = self {
+            f(split);
+            split.first.for_each_split(f);
+            split.second.for_each_split(f);
+        }
+    }
+
+    fn split_leaf(
+        &mut self,
+        target: usize,
+        hwnd: usize,
+        before: bool,
+        ratio: f64,
+        parent: Option<Orientation>,
+    ) -> bool {
+        match self {
+            Self::Leaf(h) if *h == target => {
+                let existing = Box::new(Self::Leaf(target));
+                let added = Box::new(Self::Leaf(hwnd));
+                let (first, second) = if before {
+                    (added, existing)
+                } else {
+                    (existing, added)
+                };
+                *self = Self::Split(Split {
+                    orientation: parent.map_or(Orientation::Horizontal, Orientation::flipped),
+                    ratio,
+                    first,
+                    second,
+                });
+                true
+            }
+            Self::Leaf(_) => false,
+            Self::Split(split) => {
+                let orientation = Some(split.orientation);
+                split
+                    .first
+                    .split_leaf(target, hwnd, before, ratio, orientation)
+                    || split
+                        .second
+                        .split_leaf(target, hwnd, before, ratio, orientation)
+            }
+        }
+    }
+
+    fn remove(&mut self, hwnd: usize) -> bool {
+        let Self::Split(split) = self else {
+            return false;
+        };
+        let leaf = Self::Leaf(hwnd);
+        let sibling = if *split.first == leaf {
+            &mut split.second
+        } else if *split.second == leaf {
+            &mut split.first
+        } else {
+            return split.first.remove(hwnd) || split.second.remove(hwnd);
+        };
+        let promoted = std::mem::replace(sibling.as_mut(), leaf);
+        *self = promoted;
+        true
+    }
+
+    /// Returns `None` if `hwnd` is not in this subtree, otherwise whether
+    /// a split was resized.
+    fn resize(&mut self, hwnd: usize, orientation: Orientation, delta: f64) -> Option<bool> {
+        match self {
+            Self::Leaf(h) => (*h == hwnd).then_some(false),
+            Self::Split(split) => {
+                let resized = split
+                    .first
+                    .resize(hwnd, orientation, delta)
+                    .or_else(|| split.second.resize(hwnd, orientation, delta))?;
+                if !resized && split.orientation == orientation {
+                    split.ratio = (split.ratio + delta).clamp(MIN_RATIO, MAX_RATIO);
+                    return Some(true);
+                }
+                Some(resized)
+            }
+        }
+    }
+
+    fn layout(&self, area: &Rect, half_gap: i32, out: &mut Vec<(usize, Rect)>) {
+        match self {
+            Self::Leaf(hwnd) => out.push((*hwnd, *area)),
+            Self::Split(split) => {
+                let (first, second) = split.areas(area, half_gap);
+                split.first.layout(&first, half_gap, out);
+                split.second.layout(&second, half_gap, out);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn area() -> Rect {
+        Rect::new(0, 0, 1920, 1080)
+    }
+
+    #[test]
+    fn appending_builds_the_alternating_spiral() {
+        // Arrange
+        let tree = SplitTree::from_handles(&[1, 2, 3], 0.5);
+
+        // Act
+        let positions = tree.layout(&area(), 0);
+
+        // Assert
+        assert_eq!(
+            positions,
+            vec![
+                (1, Rect::new(0, 0, 960, 1080)),
+                (2, Rect::new(960, 0, 960, 540)),
+                (3, Rect::new(960, 540, 960, 540)),
+            ]
+        );
+    }
+
+    #[test]
+    fn insert_after_splits_the_target_leaf() {
+        // Arrange
+        let mut tree = SplitTree::from_handles(&[1, 2], 0.5);
+
+        // Act
+        assert!(tree.insert_after(1, 3));
+
+        // Assert
+        assert_eq!(tree.leaves(), vec![1, 3, 2]);
+        let positions = tree.layout(&area(), 0);
+        assert_eq!(positions[0], (1, Rect::new(0, 0, 960, 540)));
+        assert_eq!(positions[1], (3, Rect::new(0, 540, 960, 540)));
+        assert_eq!(positions[2], (2, Rect::new(960, 0, 960, 1080)));
+    }
+
+    #[test]
+    fn insert_before_places_new_window_first() {
+        // Arrange
+        let mut tree = SplitTree::from_handles(&[1], 0.5);
+
+        // Act
+        assert!(tree.insert_before(1, 2));
+
+        // Assert
+        assert_eq!(tree.leaves(), vec![2, 1]);
+    }
+
+    #[test]
+    fn insert_rejects_duplicates_and_missing_targets() {
+        // Arrange
+        let mut tree = SplitTree::from_handles(&[1, 2], 0.5);
+
+        // Act / Assert
+        assert!(!tree.insert_after(1, 2));
+        assert!(!tree.insert_after(9, 3));
+        assert!(!tree.push(1));
+        assert_eq!(tree.len(), 2);
+    }
+
+    #[test]
+    fn remove_promotes_the_sibling() {
+        // Arrange
+        let mut tree = SplitTree::from_handles(&[1, 2, 3], 0.5);
+
+        // Act
+        assert!(tree.remove(2));
+
+        // Assert
+        assert_eq!(tree.leaves(), vec![1, 3]);
+        assert_eq!(
+            tree.layout(&area(), 0)[1],
+            (3, Rect::new(960, 0, 960, 1080))
+        );
+    }
+
+    #[test]
+    fn remove_last_window_empties_the_tree() {
+        // Arrange
+        let mut tree = SplitTree::from_handles(&[1], 0.5);
+
+        // Act
+        assert!(tree.remove(1));
+
+        // Assert
+        assert!(tree.is_empty());
+        assert!(!tree.remove(1));
+    }
+
+    #[test]
+    fn swap_exchanges_positions() {
+        // Arrange
+        let mut tree = SplitTree::from_handles(&[1, 2, 3], 0.5);
+
+        // Act
+        assert!(tree.swap(1, 3));
+
+        // Assert
+        assert_eq!(tree.leaves(), vec![3, 2, 1]);
+        assert!(!tree.swap(1, 9));
+    }
+
+    #[test]
+    fn rotate_turns_side_by_side_into_stacked() {
+        // Arrange
+        let mut tree = SplitTree::from_handles(&[1, 2], 0.5);
+
+        // Act
+        tree.rotate();
+
+        // Assert
+        let positions = tree.layout(&area(), 0);
+        assert_eq!(positions[0], (1, Rect::new(0, 0, 1920, 540)));
+        assert_eq!(positions[1], (2, Rect::new(0, 540, 1920, 540)));
+    }
+
+    #[test]
+    fn rotating_four_times_is_identity() {
+        // Arrange
+        let original = SplitTree::from_handles(&[1, 2, 3, 4], 0.6);
+        let mut tree = original.clone();
+
+        // Act
+        for _ in 0..4 {
+            tree.rotate();
+        }
+
+        // Assert
+        assert_eq!(tree, original);
+    }
+
+    #[test]
+    fn flip_mirrors_matching_splits_only() {
+        // Arrange
+        let mut tree = SplitTree::from_handles(&[1, 2, 3], 0.5);
+
+        // Act
+        tree.flip(Orientation::Horizontal);
+
+        // Assert
+        assert_eq!(tree.leaves(), vec![2, 3, 1]);
+        let positions = tree.layout(&area(), 0);
+        assert_eq!(positions[2], (1, Rect::new(960, 0, 960, 1080)));
+    }
+
+    #[test]
+    fn set_ratio_resets_existing_splits() {
+        // Arrange
+        let mut tree = SplitTree::from_handles(&[1, 2], 0.5);
+
+        // Act
+        tree.set_ratio(0.25);
+
+        // Assert
+        assert_eq!(tree.layout(&area(), 0)[0], (1, Rect::new(0, 0, 480, 1080)));
+    }
+
+    #[test]
+    fn resize_moves_the_nearest_matching_split() {
+        // Arrange
+        let mut tree = SplitTree::from_handles(&[1, 2, 3], 0.5);
+
+        // Act
+        assert!(tree.resize(3, Orientation::Vertical, 0.25));
+        assert!(tree.resize(3, Orientation::Horizontal, -0.25));
+
+        // Assert
+        let positions = tree.layout(&area(), 0);
+        assert_eq!(positions[0], (1, Rect::new(0, 0, 480, 1080)));
+        assert_eq!(positions[1], (2, Rect::new(480, 0, 1440, 810)));
+        assert_eq!(positions[2], (3, Rect::new(480, 810, 1440, 270)));
+    }
+
+    #[test]
+    fn resize_clamps_and_reports_missing_splits() {
+        // Arrange
+        let mut tree = SplitTree::from_handles(&[1, 2], 0.5);
+
+        // Act
+        assert!(tree.resize(1, Orientation::Horizontal, 5.0));
+        let vertical = tree.resize(1, Orientation::Vertical, 0.1);
+        let missing = tree.resize(9, Orientation::Horizontal, 0.1);
+
+        // Assert
+        assert!(!vertical);
+        assert!(!missing);
+        assert_eq!(tree.layout(&area(), 0)[0], (1, Rect::new(0, 0, 1728, 1080)));
+    }
+
+    #[test]
+    fn tree_round_trips_through_json() {
+        // Arrange
+        let tree = SplitTree::from_handles(&[1, 2], 0.5);
+
+        // Act
+        let json = serde_json::to_string(&tree).unwrap();
+        let parsed: SplitTree = serde_json::from_str(&json).unwrap();
+
+        // Assert
+        assert_eq!(
+            json,
+            r#"{"root":{"split":{"orientation":"horizontal","ratio":0.5,"first":{"leaf":1},"second":{"leaf":2}}},"ratio":0.5}"#
+        );
+        assert_eq!(parsed, tree);
+    }
+}
@@ -0,0 +1,313 @@
+use std::collections::HashSet;
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::LayoutKind;
+use crate::query::{StateSnapshot, WindowSnapshot};
+
+/// A category of [`DaemonEvent`]s a subscriber can ask for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum EventTopic {
+    /// The focused monitor or window changed.
+    FocusChanged,
+    /// A monitor switched to another workspace.
+    WorkspaceChanged,
+    /// A workspace changed its layout or monocle mode.
+    LayoutChanged,
+    /// A window started being managed.
+    WindowManaged,
+    /// A window stopped being managed.
+    WindowUnmanaged,
+    /// A configuration file was reloaded.
+    ConfigReloaded,
+    /// Hotkeys were paused or unpaused.
+    Paused,
+}
+
+impl EventTopic {
+    /// Every topic, in declaration order.
+    pub const ALL: [EventTopic; 7] = [
+        Self::FocusChanged,
+        Self::WorkspaceChanged,
+        Self::LayoutChanged,
+        Self::WindowManaged,
+        Self::WindowUnmanaged,
+        Self::ConfigReloaded,
+        Self::Paused,
+    ];
+}
+
+impl FromStr for EventTopic {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|topic| topic.to_string() == s)
+            .ok_or_else(|| format!("unknown event topic: {s}"))
+    }
+}
+
+impl fmt::Display for EventTopic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::FocusChanged => "focus-changed",
+            Self::WorkspaceChanged => "workspace-changed",
+            Self::LayoutChanged => "layout-changed",
+            Self::WindowManaged => "window-managed",
+            Self::WindowUnmanaged => "window-unmanaged",
+            Self::ConfigReloaded => "config-reloaded",
+            Self::Paused => "paused",
+        };
+        f.write_str(name)
+    }
+}
+
+/// A notification streamed to clients subscribed with
+/// [`Command::Subscribe`](crate::Command::Subscribe).
+///
+/// Serialized as one JSON object per line, tagged by `"event"`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "event", rename_all = "kebab-case")]
+pub enum DaemonEvent {
+    /// The focused monitor or window changed, or the focused window's
+    /// title did.
+    FocusChanged {
+        /// Index of the focused monitor.
+        monitor: usize,
+        /// The focused window, if any.
+        window: Option<WindowSnapshot>,
+    },
+    /// A monitor switched to another workspace.
+    WorkspaceChanged {
+        /// Index of the monitor.
+        monitor: usize,
+        /// Number of the now visible workspace (1-based).
+        workspace: usize,
+        /// Number of the previously visible workspace (1-based).
+        previous: usize,
+    },
+    /// A workspace changed its layout or monocle mode.
+    LayoutChanged {
+        /// Index of the monitor the workspace belongs to.
+        monitor: usize,
+        /// Workspace number (1-based).
+        workspace: usize,
+        /// The new layout algorithm.
+        layout: LayoutKind,
+        /// Whether monocle mode is on.
+        monocle: bool,
+    },
+    /// A window started being managed.
+    WindowManaged {
+        /// The newly managed window.
+        window: WindowSnapshot,
+    },
+    /// A window stopped being managed (closed, minimized or excluded).
+    WindowUnmanaged {
+        /// Handle of the window.
+        hwnd: usize,
+    },
+    /// A configuration file was reloaded.
+    ConfigReloaded {
+        /// What was reloaded: `config`, `rules`, `bar` or `layouts`.
+        config: String,
+    },
+    /// Hotkeys were paused or unpaused.
+    Paused {
+        /// Whether hotkeys are now paused.
+        paused: bool,
+    },
+}
+
+impl DaemonEvent {
+    /// Returns the topic the event belongs to.
+    pub fn topic(&self) -> EventTopic {
+        match self {
+            Self::FocusChanged { .. } => EventTopic::FocusChanged,
+            Self::WorkspaceChanged { .. } => EventTopic::WorkspaceChanged,
+            Self::LayoutChanged { .. } => EventTopic::LayoutChanged,
+            Self::WindowManaged { .. } => EventTopic::WindowManaged,
+            Self::WindowUnmanaged { .. } => EventTopic::WindowUnmanaged,
+            Self::ConfigReloaded { .. } => EventTopic::ConfigReloaded,
+            Self::Paused { .. } => EventTopic::Paused,
+        }
+    }
+
+    /// Returns the events that turn `old` into `new`.
+    ///
+    /// Window events come first so a subscriber learns about a window
+    /// before it is reported as focused. Monitors and workspaces are
+    /// compared by position; ones that appeared or vanished (e.g. after a
+    /// display change) produce no events of their own.
+    pub fn changes(old: &StateSnapshot, new: &StateSnapshot) -> Vec<DaemonEvent> {
+        let mut events = Vec::new();
+
+        let old_hwnds: HashSet<usize> = old.windows().iter().map(|w| w.hwnd).collect();
+        let new_hwnds: HashSet<usize> = new.windows().iter().map(|w| w.hwnd).collect();
+        for window in new.windows() {
+            if !old_hwnds.contains(&window.hwnd) {
+                events.push(Self::WindowManaged {
+                    window: window.clone(),
+                });
+            }
+        }
+        for window in old.windows() {
+            if !new_hwnds.contains(&window.hwnd) {
+                events.push(Self::WindowUnmanaged { hwnd: window.hwnd });
+            }
+        }
+
+        for (old_mon, new_mon) in old.monitors.iter().zip(&new.monitors) {
+            if old_mon.active_workspace != new_mon.active_workspace {
+                events.push(Self::WorkspaceChanged {
+                    monitor: new_mon.index,
+                    workspace: new_mon.active_workspace,
+                    previous: old_mon.active_workspace,
+                });
+            }
+            for (old_ws, new_ws) in old_mon.workspaces.iter().zip(&new_mon.workspaces) {
+                if old_ws.layout != new_ws.layout || old_ws.monocle != new_ws.monocle {
+                    events.push(Self::LayoutChanged {
+                        monitor: new_ws.monitor,
+                        workspace: new_ws.number,
+                        layout: new_ws.layout.clone(),
+                        monocle: new_ws.monocle,
+                    });
+                }
+            }
+        }
+
+        let (old_focus, new_focus) = (old.focused(), new.focused());
+        let key = |focus: &crate::query::FocusSnapshot| {
+            let window = focus.window.as_ref().map(|w| (w.hwnd, w.title.clone()));
+            (focus.monitor, window)
+        };
+        if key(&old_focus) != key(&new_focus) {
+            events.push(Self::FocusChanged {
+                monitor: new_focus.monitor,
+                window: new_focus.window,
+            });
+        }
+
+        events
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::query::{MonitorSnapshot, WorkspaceSnapshot};
+
+    fn window(hwnd: usize, title: &str) -> WindowSnapshot {
+        WindowSnapshot {
+            hwnd,
+            workspace: 1,
+            title: title.into(),
+            ..Default::default()
+        }
+    }
+
+    fn state(windows: Vec<WindowSnapshot>, focused_window: Option<usize>) -> StateSnapshot {
+        let workspace = |number| WorkspaceSnapshot {
+            number,
+            visible: number == 1,
+            ..Default::default()
+        };
+        StateSnapshot {
+            monitors: vec![MonitorSnapshot {
+                active_workspace: 1,
+                workspaces: vec![
+                    WorkspaceSnapshot {
+                        windows,
+                        ..workspace(1)
+                    },
+                    workspace(2),
+                ],
+                ..Default::default()
+            }],
+            focused_monitor: 0,
+            focused_window,
+        }
+    }
+
+    #[test]
+    fn topics_round_trip_through_their_names() {
+        for topic in EventTopic::ALL {
+            assert_eq!(topic.to_string().parse::<EventTopic>(), Ok(topic));
+        }
+        assert!("focus".parse::<EventTopic>().is_err());
+    }
+
+    #[test]
+    fn events_serialize_as_tagged_objects() {
+        let json = serde_json::to_string(&DaemonEvent::Paused { paused: true }).unwrap();
+
+        assert_eq!(json, r#"{"event":"paused","paused":true}"#);
+    }
+
+    #[test]
+    fn unchanged_state_produces_no_events() {
+        let old = state(vec![window(1, "a")], Some(1));
+
+        assert!(DaemonEvent::changes(&old, &old.clone()).is_empty());
+    }
+
+    #[test]
+    fn reports_managed_windows_before_focus() {
+        let old = state(vec![window(1, "a")], Some(1));
+        let new = state(vec![window(1, "a"), window(2, "b")], Some(2));
+
+        let events = DaemonEvent::changes(&old, &new);
+
+        assert_eq!(
+            events,
+            vec![
+                DaemonEvent::WindowManaged {
+                    window: window(2, "b")
+                },
+                DaemonEvent::FocusChanged {
+                    monitor: 0,
+                    window: Some(window(2, "b")),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_workspace_layout_and_unmanaged_changes() {
+        let old = state(vec![window(1, "a")], Some(1));
+        let mut new = state(Vec::new(), None);
+        new.monitors[0].active_workspace = 2;
+        new.monitors[0].workspaces[1].layout = LayoutKind::Grid;
+
+        let topics: Vec<_> = DaemonEvent::changes(&old, &new)
+            .iter()
+            .map(DaemonEvent::topic)
+            .collect();
+
+        assert_eq!(
+            topics,
+            vec![
+                EventTopic::WindowUnmanaged,
+                EventTopic::WorkspaceChanged,
+                EventTopic::LayoutChanged,
+                EventTopic::FocusChanged,
+            ]
+        );
+    }
+
+    #[test]
+    fn focused_title_change_is_a_focus_change() {
+        let old = state(vec![window(1, "a")], Some(1));
+        let new = state(vec![window(1, "b")], Some(1));
+
+        let events = DaemonEvent::changes(&old, &new);
+
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].topic(), EventTopic::FocusChanged);
+    }
+}
@@ -0,0 +1,59 @@
+//! Read-only view of the platform's window state.
+
+use std::time::Instant;
+
+use crate::Rect;
+
+/// Queries the tiling state machine needs to answer about live windows.
+///
+/// The state machine never touches the OS directly. Instead, each call
+/// into [`super::TilingState`] receives a `Desktop` that answers these
+/// questions, and the state machine responds with a list of
+/// [`super::Effect`]s for the platform to execute.
+///
+/// Platform crates implement this on top of their native APIs; tests
+/// and simulators implement it on top of an in-memory model.
+pub trait Desktop {
+    /// Returns whether the platform could tile this window at all,
+    /// before window rules are consulted.
+    ///
+    /// Implementations should reject invisible, cloaked, tool and
+    /// owned windows, as well as windows this process cannot move.
+    fn is_manageable(&self, hwnd: usize) -> bool;
+
+    /// Returns the window class name (empty if unavailable).
+    fn class(&self, hwnd: usize) -> String;
+
+    /// Returns the window title (empty if unavailable).
+    fn title(&self, hwnd: usize) -> String;
+
+    /// Returns the full path of the window's executable (empty if
+    /// unavailable).
+    fn exe_path(&self, hwnd: usize) -> String;
+
+    /// Returns whether the window is currently visible.
+    fn is_visible(&self, hwnd: usize) -> bool;
+
+    /// Returns whether the window is minimized.
+    fn is_minimized(&self, hwnd: usize) -> bool;
+
+    /// Returns whether the window is maximized.
+    fn is_maximized(&self, hwnd: usize) -> bool;
+
+    /// Returns the window's current rect, or `None` if unavailable.
+    fn rect(&self, hwnd: usize) -> Option<Rect>;
+
+    /// Returns the ID of the monitor that currently contains the window.
+    fn monitor_id(&self, hwnd: usize) -> usize;
+
+    /// Returns the owner window, if the window is owned (e.g. a dialog).
+    fn owner(&self, hwnd: usize) -> Option<usize>;
+
+    /// Returns the current time.
+    ///
+    /// Used for the workspace-switch cooldown. Simulators override this
+    /// to keep replays deterministic.
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
@@ -0,0 +1,280 @@
+//! Monitor display change handling for the tiling state machine.
+
+use super::monitor_memory::Homecoming;
+use super::workspace_transfer::shifted;
+use super::{Desktop, Effect, MonitorInfo, MonitorState, TilingState};
+use crate::Rect;
+
+impl TilingState {
+    /// Adjusts monitor work areas by subtracting bar height from the top.
+    ///
+    /// Only monitors whose index appears in `bar_monitors` are adjusted.
+    /// An empty slice means all monitors.
+    pub fn adjust_work_areas_for_bar(
+        &mut self,
+        desktop: &dyn Desktop,
+        bar_height: i32,
+        bar_monitors: &[usize],
+    ) -> Vec<Effect> {
+        self.apply_bar_offset(bar_height, bar_monitors);
+        self.retile_all(desktop);
+        self.take_effects()
+    }
+
+    /// Resets work areas to the OS values, then applies the bar offset.
+    ///
+    /// Used when bar config changes to avoid accumulating offsets.
+    pub fn reset_and_adjust_work_areas(
+        &mut self,
+        desktop: &dyn Desktop,
+        os_monitors: &[MonitorInfo],
+        bar_height: i32,
+        bar_monitors: &[usize],
+    ) -> Vec<Effect> {
+        for mon in &mut self.monitors {
+            if let Some(os) = os_monitors.iter().find(|m| m.id == mon.id) {
+                mon.work_area = os.work_area;
+            }
+        }
+        self.adjust_work_areas_for_bar(desktop, bar_height, bar_monitors)
+    }
+
+    /// Rebuilds internal monitor state after a display configuration change.
+    ///
+    /// Preserves workspaces for monitors that still exist (matched by ID,
+    /// with position/resolution fallback). Windows on removed monitors are
+    /// migrated to the same workspace number on the primary monitor, and
+    /// their home is remembered so they move back when a matching monitor
+    /// reconnects. Floating windows keep their position relative to the
+    /// work area they move between.
+    pub fn handle_display_change(
+        &mut self,
+        desktop: &dyn Desktop,
+        new_monitors: Vec<MonitorInfo>,
+        bar_height: i32,
+        bar_monitor_indices: &[usize],
+    ) -> Vec<Effect> {
+        if new_monitors.is_empty() {
+            return self.take_effects();
+        }
+
+        let old_count = self.monitors.len();
+        let new_count = new_monitors.len();
+
+        // Check if monitors actually changed (debounce rapid WM_DISPLAYCHANGE).
+        if old_count == new_count {
+            let ids_match = new_monitors
+                .iter()
+                .zip(self.monitors.iter())
+                .all(|(new, old)| new.id == old.id && new.work_area == old.work_area);
+            if ids_match {
+                return self.take_effects();
+            }
+        }
+
+        crate::log_info!("Display change: {} -> {} monitors", old_count, new_count);
+
+        let mut new_states: Vec<MonitorState> = Vec::with_capacity(new_count);
+        let mut fresh: Vec<usize> = Vec::new();
+        // Floating windows that changed monitor, with the work area their
+        // rect was relative to before the change.
+        let mut moved_floating: Vec<(usize, Rect)> = Vec::new();
+
+        for info in &new_monitors {
+            // Try to find a matching old monitor by ID first, then by position.
+            let old_idx = self
+                .monitors
+                .iter()
+                .position(|m| m.id == info.id)
+                .or_else(|| {
+                    self.monitors.iter().position(|m| {
+                        m.work_area.x == info.work_area.x && m.work_area.y == info.work_area.y
+                    })
+                });
+
+            if let Some(idx) = old_idx {
+                // Reuse workspaces from the matching old monitor.
+                let old = &mut self.monitors[idx];
+                new_states.push(MonitorState {
+                    id: info.id,
+                    work_area: info.work_area,
+                    name: info.name.clone(),
+                    bounds: info.bounds,
+                    workspaces: std::mem::take(&mut old.workspaces),
+                    active_workspace: old.active_workspace,
+                    workspace_history: std::mem::take(&mut old.workspace_history),
+                });
+            } else {
+                // Brand new monitor — create fresh workspaces.
+                fresh.push(new_states.len());
+                new_states.push(MonitorState {
+                    id: info.id,
+                    work_area: info.work_area,
+                    name: info.name.clone(),
+                    bounds: info.bounds,
+                    workspaces: self.new_workspaces(new_states.len(), info),
+                    active_workspace: 0,
+                    workspace_history: Vec::new(),
+                });
+            }
+        }
+
+        // Migrate windows from removed monitors (those whose workspaces
+        // were not claimed by any new monitor), keeping their workspace
+        // numbers.
+        let fallback_idx = 0; // primary monitor
+        let old_monitors = std::mem::take(&mut self.monitors);
+        for old_mon in &old_monitors {
+            // If workspaces are empty, they were moved via std::mem::take.
+            if old_mon.workspaces.is_empty() {
+                continue;
+            }
+            // This old monitor was removed — migrate its windows.
+            let mut homes = Vec::new();
+            for (ws_idx, ws) in old_mon.workspaces.iter().enumerate() {
+                for hwnd in ws.windows() {
+                    crate::log_info!(
+                        "Migrating window 0x{:X} from removed monitor {} ws {} to monitor {}",
+                        hwnd,
+                        old_mon.id,
+                        ws_idx + 1,
+                        new_states[fallback_idx].id
+                    );
+                    let target = &mut new_states[fallback_idx];
+                    // The primary monitor may have fewer workspaces.
+                    let to_ws = ws_idx.min(target.workspaces.len() - 1);
+                    let visible = target.active_workspace == to_ws;
+                    let target = &mut target.workspaces[to_ws];
+                    if let Some(rect) = ws.floating_rect(hwnd) {
+                        target.add_floating(hwnd, rect);
+                        moved_floating.push((hwnd, old_mon.work_area));
+                    } else {
+                        target.add(hwnd);
+                    }
+                    self.set_visible(hwnd, visible);
+                    homes.push((hwnd, ws_idx));
+                }
+            }
+            self.monitor_memory.remember(
+                old_mon.id,
+                old_mon.work_area,
+                old_mon.active_workspace,
+                homes,
+            );
+        }
+
+        self.monitors = new_states;
+
+        // A monitor that changed position may have a different count.
+        for mon_idx in 0..self.monitors.len() {
+            self.fit_workspaces(mon_idx);
+        }
+
+        // Bring windows home to monitors that were added back.
+        for mon_idx in fresh {
+            let info = &new_monitors[mon_idx];
+            if let Some(home) = self.monitor_memory.recall(info.id, &info.work_area) {
+                self.restore_home(mon_idx, home, &old_monitors, &mut moved_floating);
+            }
+        }
+
+        // Clamp focused monitor and follow the focused window.
+        if self.focused_monitor >= self.monitors.len() {
+            self.focused_monitor = 0;
+        }
+        if let Some((mon_idx, _)) = self.focused_window.and_then(|h| self.find_window(h)) {
+            self.focused_monitor = mon_idx;
+        }
+
+        // Re-apply bar offsets and retile.
+        self.apply_bar_offset(bar_height, bar_monitor_indices);
+        self.place_moved_floating(moved_floating);
+        self.retile_all(desktop);
+        self.update_border();
+        self.take_effects()
+    }
+
+    /// Moves remembered windows back to the reconnected monitor at
+    /// `mon_idx` and restores its visible workspace.
+    ///
+    /// Floating windows are added to `moved_floating` with the work area
+    /// they were on before this display change (from `old_monitors`).
+    fn restore_home(
+        &mut self,
+        mon_idx: usize,
+        home: Homecoming,
+        old_monitors: &[MonitorState],
+        moved_floating: &mut Vec<(usize, Rect)>,
+    ) {
+        let last = self.monitors[mon_idx].workspaces.len() - 1;
+        self.monitors[mon_idx].active_workspace = home.active_workspace.min(last);
+        for (hwnd, ws_idx) in home.homes {
+            let ws_idx = ws_idx.min(last);
+            // Windows closed while away are no longer managed.
+            let Some((from_mon, from_ws)) = self.find_window(hwnd) else {
+                continue;
+            };
+            let source = &mut self.monitors[from_mon].workspaces[from_ws];
+            let floating = source.floating_rect(hwnd);
+            source.remove(hwnd);
+            if source.monocle_window() == Some(hwnd) {
+                source.set_monocle(false);
+                source.set_monocle_window(None);
+            }
+            if floating.is_some() && moved_floating.iter().all(|&(h, _)| h != hwnd) {
+                let from_id = self.monitors[from_mon].id;
+                let from = old_monitors
+                    .iter()
+                    .find(|m| m.id == from_id)
+                    .map_or(self.monitors[from_mon].work_area, |m| m.work_area);
+                moved_floating.push((hwnd, from));
+            }
+            let target = &mut self.monitors[mon_idx].workspaces[ws_idx];
+            match floating {
+                Some(rect) => target.add_floating(hwnd, rect),
+                None => target.add(hwnd),
+            };
+            crate::log_info!(
+                "Restoring window 0x{:X} to monitor {} ws {}",
+                hwnd,
+                self.monitors[mon_idx].id,
+                ws_idx + 1
+            );
+            self.set_visible(hwnd, self.monitors[mon_idx].active_workspace == ws_idx);
+        }
+    }
+
+    /// Translates the rects of floating windows that changed monitor from
+    /// the work area they left into the one they are on now, like a
+    /// workspace transfer does.
+    fn place_moved_floating(&mut self, moved: Vec<(usize, Rect)>) {
+        for (hwnd, from) in moved {
+            let Some((mon_idx, ws_idx)) = self.find_window(hwnd) else {
+                continue;
+            };
+            let to = self.monitors[mon_idx].work_area;
+            let ws = &mut self.monitors[mon_idx].workspaces[ws_idx];
+            let Some(rect) = ws.floating_rect(hwnd) else {
+                continue;
+            };
+            let rect = shifted(rect, &from, &to);
+            ws.set_floating_rect(hwnd, rect);
+            self.effects.push(Effect::SetRect { hwnd, rect });
+        }
+    }
+
+    /// Returns the current monitor IDs for debounce comparison.
+    pub fn monitor_ids(&self) -> Vec<usize> {
+        self.monitors.iter().map(|m| m.id).collect()
+    }
+
+    /// Subtracts the bar height from the top of each barred monitor.
+    fn apply_bar_offset(&mut self, bar_height: i32, bar_monitors: &[usize]) {
+        for (i, mon) in self.monitors.iter_mut().enumerate() {
+            if bar_monitors.is_empty() || bar_monitors.contains(&i) {
+                mon.work_area.y += bar_height;
+                mon.work_area.height -= bar_height;
+            }
+        }
+    }
+}
@@ -0,0 +1,88 @@
+//! Side effects requested by the tiling state machine.
+
+use crate::Rect;
+use crate::config::{CornerStyle, HidingBehaviour};
+
+/// A platform operation requested by [`super::TilingState`].
+///
+/// Effects are returned in the order they must be executed. Ordering
+/// matters: for example, a window is focused before the previous
+/// workspace is hidden so the OS never auto-uncloaks the old window.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Effect {
+    /// Move and resize a window, then force a repaint.
+    ///
+    /// Executors may skip the call when the window is already at the
+    /// requested position and is not maximized.
+    SetRect {
+        /// Window handle.
+        hwnd: usize,
+        /// Target position and size.
+        rect: Rect,
+    },
+    /// Hide a window when switching away from its workspace.
+    Hide {
+        /// Window handle.
+        hwnd: usize,
+        /// Strategy to hide the window with.
+        hiding: HidingBehaviour,
+    },
+    /// Show a window that was hidden by a workspace switch.
+    Show {
+        /// Window handle.
+        hwnd: usize,
+        /// Strategy the window was hidden with.
+        hiding: HidingBehaviour,
+    },
+    /// Make a window visible regardless of how it was hidden.
+    ///
+    /// Used on shutdown so no window is left invisible.
+    Restore {
+        /// Window handle.
+        hwnd: usize,
+    },
+    /// Bring a window to the foreground.
+    Focus {
+        /// Window handle.
+        hwnd: usize,
+    },
+    /// Move the cursor to the center of a window.
+    MoveCursor {
+        /// Window handle.
+        hwnd: usize,
+    },
+    /// Draw the focus border around a window.
+    ///
+    /// Executors hide the border instead when the window is maximized
+    /// or minimized at execution time.
+    ShowBorder {
+        /// Window handle.
+        hwnd: usize,
+        /// Whether to use the monocle border color.
+        monocle: bool,
+    },
+    /// Hide the focus border.
+    HideBorder,
+    /// Ask a window to close gracefully.
+    Close {
+        /// Window handle.
+        hwnd: usize,
+    },
+    /// Minimize a window.
+    Minimize {
+        /// Window handle.
+        hwnd: usize,
+    },
+    /// Apply the configured corner style to a managed window.
+    SetCorners {
+        /// Window handle.
+        hwnd: usize,
+        /// Corner style to apply.
+        style: CornerStyle,
+    },
+    /// Restore the default corner style on a window leaving tiling.
+    ResetCorners {
+        /// Window handle.
+        hwnd: usize,
+    },
+}
@@ -0,0 +1,272 @@
+//! Event handling for the tiling state machine.
+
+use crate::WindowEvent;
+
+use super::{Desktop, Effect, TilingState};
+
+impl TilingState {
+    /// Handles a window event and re-tiles the affected monitor.
+    pub fn handle_event(&mut self, desktop: &dyn Desktop, event: &WindowEvent) -> Vec<Effect> {
+        match event {
+            WindowEvent::Created { hwnd } => {
+                // Don't use adopt_rejected here — Created events come
+                // through a relaxed id_object filter (for WPF compat)
+                // so child element SHOW events share the parent hwnd
+                // and would poison the cache before the real window is
+                // ready.
+                if !self.is_tileable(desktop, *hwnd) {
+                    return self.take_effects();
+                }
+                // Skip windows already managed in any workspace. WPF apps
+                // (e.g. Visual Studio) fire spurious EVENT_OBJECT_SHOW from
+                // child elements; without this guard the window gets added
+                // to every workspace that happens to be active at the time.
+                if self.find_window(*hwnd).is_some() {
+                    return self.take_effects();
+                }
+                self.add_and_focus(desktop, *hwnd, true);
+            }
+            WindowEvent::Restored { hwnd } => {
+                if self.adopt_rejected.contains(hwnd) {
+                    return self.take_effects();
+                }
+                if !self.is_tileable(desktop, *hwnd) {
+                    self.adopt_rejected.insert(*hwnd);
+                    return self.take_effects();
+                }
+                // If the window was already re-adopted (e.g. by a Focused
+                // event that fired before this Restored event during
+                // Alt+Tab), just focus it instead of bailing out.
+                if self.find_window(*hwnd).is_some() {
+                    self.focus_from_mouse = false;
+                    self.focus_and_update_border(desktop, *hwnd);
+                    return self.take_effects();
+                }
+                self.add_and_focus(desktop, *hwnd, false);
+            }
+            WindowEvent::Destroyed { hwnd } => {
+                self.adopt_rejected.remove(hwnd);
+                self.monitor_memory.forget(*hwnd);
+                self.effects.push(Effect::ResetCorners { hwnd: *hwnd });
+                self.remove_from_tiling(desktop, *hwnd, "del", false);
+            }
+            WindowEvent::Hidden { hwnd } => {
+                if self.hidden_by_switch.contains(hwnd) {
+                    return self.take_effects();
+                }
+                // Skip spurious hides (e.g. Chromium repainting a child surface):
+                // a real hide-to-tray leaves the window invisible.
+                if desktop.is_visible(*hwnd) {
+                    crate::log_debug!("hide-ignored 0x{:X} (still visible)", hwnd);
+                    return self.take_effects();
+                }
+                self.effects.push(Effect::ResetCorners { hwnd: *hwnd });
+                self.remove_from_tiling(desktop, *hwnd, "hide", false);
+            }
+            WindowEvent::Minimized { hwnd } => {
+                if self.hidden_by_switch.contains(hwnd) {
+                    return self.take_effects();
+                }
+                self.remove_from_tiling(desktop, *hwnd, "min", true);
+            }
+            WindowEvent::Moved { hwnd } => {
+                // Don't retile a window that the user just maximized —
+                // reassign_monitor would snap it back to BSP layout.
+                let maximized = desktop.is_maximized(*hwnd);
+                if self.focused_window == Some(*hwnd) {
+                    self.focused_maximized = maximized;
+                }
+                if maximized {
+                    self.update_border();
+                } else {
+                    self.reassign_monitor(desktop, *hwnd);
+                }
+            }
+            WindowEvent::Focused { hwnd } => self.handle_focused(desktop, *hwnd),
+            WindowEvent::TitleChanged { hwnd } => {
+                // Some applications (notably WPF-based apps) do not fire
+                // EVENT_OBJECT_CREATE or EVENT_OBJECT_SHOW for their main
+                // window. They do, however, fire EVENT_OBJECT_NAMECHANGE
+                // once the window title is set. Use this as a fallback to
+                // discover windows that slipped past the Created handler.
+                if self.find_window(*hwnd).is_none() {
+                    self.try_adopt(desktop, *hwnd);
+                } else {
+                    self.reevaluate_rules(desktop, *hwnd);
+                }
+            }
+            WindowEvent::MouseHover { hwnd } => {
+                if !self.is_managed_on_active_workspace(*hwnd) {
+                    return self.take_effects();
+                }
+                if self.focused_window == Some(*hwnd) {
+                    return self.take_effects();
+                }
+                self.focus_from_mouse = true;
+                self.focus_and_update_border(desktop, *hwnd);
+            }
+            WindowEvent::LocationChanged { hwnd } => {
+                // EVENT_OBJECT_LOCATIONCHANGE fires frequently (every
+                // animation frame), so only react when the focused
+                // window's maximized state changes. This catches
+                // maximize/restore via the title-bar buttons which do
+                // not fire EVENT_SYSTEM_MOVESIZEEND.
+                if self.focused_window == Some(*hwnd) {
+                    let maximized = desktop.is_maximized(*hwnd);
+                    if maximized != self.focused_maximized {
+                        self.focused_maximized = maximized;
+                        if maximized {
+                            // Entering maximize — hide border immediately.
+                            self.update_border();
+                        } else {
+                            // Restoring from maximize — retile to snap
+                            // the window back to its BSP position so the
+                            // border doesn't flash before the animation
+                            // finishes.
+                            self.reassign_monitor(desktop, *hwnd);
+                            self.update_border();
+                        }
+                    }
+                }
+            }
+            WindowEvent::DisplayChanged | WindowEvent::WorkAreaChanged => {
+                // Handled by the daemon loop, not here.
+            }
+        }
+        self.take_effects()
+    }
+
+    /// Handles a `Focused` event.
+    fn handle_focused(&mut self, desktop: &dyn Desktop, hwnd: usize) {
+        if let Some(idx) = self.owning_monitor(hwnd) {
+            // Check if the window is on a non-active workspace
+            // (e.g. user clicked a cloaked window's taskbar icon).
+            // Switch to that workspace so the window becomes visible.
+            // Suppress during the cooldown period after a workspace
+            // switch to prevent deferred focus events from
+            // triggering an infinite switching loop.
+            let off_active = self
+                .find_window(hwnd)
+                .is_some_and(|(mi, wi)| wi != self.monitors[mi].active_workspace);
+
+            if off_active {
+                if self.ws_switch_cooldown.is_none_or(|t| desktop.now() >= t) {
+                    // Outside cooldown: the user clicked a hidden
+                    // window's taskbar icon — switch to its workspace.
+                    if let Some((mon_idx, ws_idx)) = self.find_window(hwnd) {
+                        self.focused_monitor = mon_idx;
+                        self.goto_workspace(desktop, ws_idx);
+                        self.focus_from_mouse = true;
+                        self.focus_and_update_border(desktop, hwnd);
+                    }
+                } else {
+                    // Inside cooldown: stale deferred focus event
+                    // from a previous workspace switch — ignore it
+                    // to prevent corrupting the focused state.
+                    crate::log_debug!(
+                        "focus-suppressed 0x{:X} (off-workspace, cooldown active)",
+                        hwnd
+                    );
+                }
+                return;
+            }
+            self.focus_from_mouse = true;
+            self.focused_window = Some(hwnd);
+            self.focused_monitor = idx;
+            self.focused_maximized = desktop.is_maximized(hwnd);
+            self.update_border();
+            self.focus_from_mouse = false;
+        } else if let Some(owner) = desktop.owner(hwnd)
+            && let Some(idx) = self.owning_monitor(owner)
+        {
+            // An owned window (dialog, property sheet) got focus.
+            //
+            // Due to a Win32 race condition, dialogs created on
+            // a different thread may not have their owner set
+            // when EVENT_OBJECT_CREATE fires, causing them to
+            // pass is_app_window() and get tiled. Now that the
+            // owner is queryable, clean up if the dialog was
+            // incorrectly added.
+            if let Some((mon_idx, ws_idx)) = self.find_window(hwnd) {
+                self.monitors[mon_idx].workspaces[ws_idx].remove(hwnd);
+                crate::log_info!("-fix 0x{:X} (owned dialog removed from tiling)", hwnd);
+                self.apply_layout_on(desktop, mon_idx);
+            }
+            // Move the border to the owner so the user can see
+            // which application the dialog belongs to.
+            self.focused_window = Some(owner);
+            self.focused_monitor = idx;
+            self.focused_maximized = desktop.is_maximized(owner);
+            self.update_border();
+            self.focus_from_mouse = false;
+        }
+        // Window is not tracked and has no managed owner. It may
+        // be an app whose creation event was missed (e.g. WPF).
+        // Try to adopt it; if it's not tileable (Alt+Tab UI,
+        // shell, system dialogs) try_adopt will bail out and
+        // the border stays on the last managed window.
+        else {
+            self.try_adopt(desktop, hwnd);
+        }
+    }
+
+    /// Removes a window from the tiling layout, clears focus/monocle state
+    /// if needed, and retiles the affected monitor.
+    ///
+    /// When `active_only` is true, only removes the window if it is on the
+    /// active workspace (used by Minimized to avoid pruning windows hidden
+    /// by workspace switching).
+    pub(super) fn remove_from_tiling(
+        &mut self,
+        desktop: &dyn Desktop,
+        hwnd: usize,
+        reason: &str,
+        active_only: bool,
+    ) {
+        let Some((mon_idx, ws_idx)) = self.find_window(hwnd) else {
+            return;
+        };
+        if active_only && ws_idx != self.monitors[mon_idx].active_workspace {
+            return;
+        }
+
+        self.monitors[mon_idx].workspaces[ws_idx].remove(hwnd);
+        self.rule_floated.remove(&hwnd);
+        crate::log_info!(
+            "-{} 0x{:X} from mon {} ws {} (now {})",
+            reason,
+            hwnd,
+            mon_idx,
+            ws_idx + 1,
+            self.monitors[mon_idx].workspaces[ws_idx].len()
+        );
+
+        if self.monitors[mon_idx].workspaces[ws_idx].monocle()
+            && self.monitors[mon_idx].workspaces[ws_idx].monocle_window() == Some(hwnd)
+        {
+            self.monitors[mon_idx].workspaces[ws_idx].set_monocle(false);
+            self.monitors[mon_idx].workspaces[ws_idx].set_monocle_window(None);
+        }
+
+        let on_active = ws_idx == self.monitors[mon_idx].active_workspace;
+
+        if self.focused_window == Some(hwnd) {
+            self.focused_window = None;
+            // Promote a sibling to focus so the border stays visible.
+            if on_active {
+                let ws = &self.monitors[mon_idx].workspaces[ws_idx];
+                let replacement = ws
+                    .last_focused()
+                    .filter(|&h| ws.contains(h))
+                    .or_else(|| ws.handles().first().copied());
+                if let Some(new_hwnd) = replacement {
+                    self.focus_and_update_border(desktop, new_hwnd);
+                }
+            }
+        }
+
+        if on_active {
+            self.apply_layout_on(desktop, mon_idx);
+        }
+    }
+}
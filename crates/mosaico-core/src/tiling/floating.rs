@@ -0,0 +1,83 @@
+//! Floating windows: toggling and following user moves.
+//!
+//! Floating windows stay on their workspace (so they are hidden and
+//! shown with it) but are left out of the layout. Their last known
+//! rect is remembered so toggling them back restores it.
+
+use crate::Rect;
+
+use super::{Desktop, Effect, TilingState};
+
+impl TilingState {
+    /// Returns whether the window floats on any workspace.
+    pub(super) fn is_floating(&self, hwnd: usize) -> bool {
+        self.find_window(hwnd)
+            .is_some_and(|(mi, wi)| self.monitors[mi].workspaces[wi].is_floating(hwnd))
+    }
+
+    /// Toggles the focused window between tiled and floating, then
+    /// retiles its monitor.
+    ///
+    /// A window floated for the first time on a workspace is centered
+    /// at half the work area's size.
+    pub(super) fn toggle_float(&mut self, desktop: &dyn Desktop) {
+        let Some(hwnd) = self.focused_window else {
+            return;
+        };
+        let Some((mon_idx, ws_idx)) = self.find_window(hwnd) else {
+            return;
+        };
+        let work_area = self.monitors[mon_idx].work_area;
+        // The user's choice wins over title rules from now on.
+        self.rule_floated.remove(&hwnd);
+        let ws = &mut self.monitors[mon_idx].workspaces[ws_idx];
+
+        if ws.is_floating(hwnd) {
+            if let Some(rect) = desktop.rect(hwnd) {
+                ws.set_floating_rect(hwnd, rect);
+            }
+            ws.tile(hwnd);
+            crate::log_info!("tile 0x{:X} on mon {} ws {}", hwnd, mon_idx, ws_idx + 1);
+        } else {
+            let default = Rect::new(
+                work_area.x + work_area.width / 4,
+                work_area.y + work_area.height / 4,
+                (work_area.width / 2).max(1),
+                (work_area.height / 2).max(1),
+            );
+            let Some(rect) = ws.float(hwnd, default) else {
+                return;
+            };
+            // A floating window cannot fill the monitor in monocle mode.
+            if ws.monocle_window() == Some(hwnd) {
+                ws.set_monocle(false);
+                ws.set_monocle_window(None);
+            }
+            self.effects.push(Effect::SetRect { hwnd, rect });
+            crate::log_info!("float 0x{:X} on mon {} ws {}", hwnd, mon_idx, ws_idx + 1);
+        }
+        self.apply_layout_on(desktop, mon_idx);
+    }
+
+    /// Remembers where the user moved a floating window, moving it to
+    /// the active workspace of the monitor it now sits on.
+    pub(super) fn track_floating(&mut self, desktop: &dyn Desktop, hwnd: usize) {
+        let Some((mon_idx, ws_idx)) = self.find_window(hwnd) else {
+            return;
+        };
+        let Some(rect) = desktop.rect(hwnd) else {
+            return;
+        };
+        match self.monitor_index_for(desktop, hwnd) {
+            Some(to) if to != mon_idx => {
+                self.monitors[mon_idx].workspaces[ws_idx].remove(hwnd);
+                self.monitors[to].active_ws_mut().add_floating(hwnd, rect);
+                if self.focused_window == Some(hwnd) {
+                    self.focused_monitor = to;
+                }
+            }
+            _ => self.monitors[mon_idx].workspaces[ws_idx].set_floating_rect(hwnd, rect),
+        }
+        self.update_border();
+    }
+}
@@ -0,0 +1,52 @@
+//! Focus management and border requests for the tiling state machine.
+
+use super::{Desktop, Effect, TilingState};
+
+impl TilingState {
+    /// Sets the focused window, brings it to the foreground, and
+    /// refreshes the focus border.
+    pub(super) fn focus_and_update_border(&mut self, desktop: &dyn Desktop, hwnd: usize) {
+        self.focused_window = Some(hwnd);
+        self.focused_maximized = desktop.is_maximized(hwnd);
+        self.effects.push(Effect::Focus { hwnd });
+        if self.mouse_follows_focus && !self.focus_from_mouse {
+            self.effects.push(Effect::MoveCursor { hwnd });
+        }
+        self.focus_from_mouse = false;
+        self.update_border();
+    }
+
+    /// Moves the cursor to the focused window if `mouse_follows_focus` is
+    /// enabled.  Called after move/swap operations where the focused window
+    /// changes position but the focus itself does not change.
+    pub(super) fn move_cursor_to_focused(&mut self) {
+        if self.mouse_follows_focus
+            && let Some(hwnd) = self.focused_window
+        {
+            self.effects.push(Effect::MoveCursor { hwnd });
+        }
+    }
+
+    /// Requests the focus border around the focused window, or hides it
+    /// when nothing is focused.
+    pub(super) fn update_border(&mut self) {
+        let Some(hwnd) = self.focused_window else {
+            self.effects.push(Effect::HideBorder);
+            return;
+        };
+        let Some(mon) = self.monitors.get(self.focused_monitor) else {
+            return;
+        };
+        let monocle = mon.active_ws().monocle();
+        self.effects.push(Effect::ShowBorder { hwnd, monocle });
+    }
+
+    /// Focuses the first window on the primary monitor at startup.
+    pub(super) fn focus_initial(&mut self, desktop: &dyn Desktop) {
+        if let Some(mon) = self.monitors.first()
+            && let Some(&hwnd) = mon.active_ws().handles().first()
+        {
+            self.focus_and_update_border(desktop, hwnd);
+        }
+    }
+}
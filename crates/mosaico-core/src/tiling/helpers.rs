@@ -0,0 +1,183 @@
+//! Window lookup and management helpers for the tiling state machine.
+
+use super::rule_placement::window_props;
+use super::{Desktop, Effect, TilingState};
+
+impl TilingState {
+    /// Returns whether a window passes both the platform checks and the
+    /// window rules.
+    pub(super) fn is_tileable(&self, desktop: &dyn Desktop, hwnd: usize) -> bool {
+        if !desktop.is_manageable(hwnd) {
+            return false;
+        }
+        crate::config::should_manage(&window_props(desktop, hwnd), &self.rules)
+    }
+
+    /// Re-assigns a window to the correct monitor after it was moved.
+    ///
+    /// If the window moved to a different monitor, it is removed from
+    /// the old workspace and added to the new one, then both monitors
+    /// are re-tiled.
+    pub(super) fn reassign_monitor(&mut self, desktop: &dyn Desktop, hwnd: usize) {
+        if self.is_floating(hwnd) {
+            self.track_floating(desktop, hwnd);
+            return;
+        }
+        let old = self.owning_monitor(hwnd);
+        let new = self.monitor_index_for(desktop, hwnd);
+
+        match (old, new) {
+            (Some(from), Some(to)) if from != to => {
+                self.monitors[from].active_ws_mut().remove(hwnd);
+                self.monitors[to].active_ws_mut().add(hwnd);
+                self.apply_layout_on(desktop, from);
+                self.apply_layout_on(desktop, to);
+            }
+            (Some(idx), Some(_)) => {
+                // Same monitor — just re-tile to snap it back.
+                self.apply_layout_on(desktop, idx);
+            }
+            (None, Some(to)) if self.is_tileable(desktop, hwnd) => {
+                // Window wasn't tracked but appeared on a monitor.
+                self.add_to_monitor(desktop, to, hwnd, None, false);
+                self.apply_layout_on(desktop, to);
+            }
+            _ => {}
+        }
+    }
+
+    pub(super) fn monitor_index_for(&self, desktop: &dyn Desktop, hwnd: usize) -> Option<usize> {
+        let mid = desktop.monitor_id(hwnd);
+        self.monitors.iter().position(|m| m.id == mid)
+    }
+
+    pub(super) fn is_managed_on_active_workspace(&self, hwnd: usize) -> bool {
+        self.monitors
+            .get(self.focused_monitor)
+            .is_some_and(|mon| mon.active_ws().contains(hwnd))
+    }
+
+    pub(super) fn owning_monitor(&self, hwnd: usize) -> Option<usize> {
+        self.monitors
+            .iter()
+            .position(|m| m.workspaces.iter().any(|ws| ws.contains(hwnd)))
+    }
+
+    /// Finds which monitor and workspace contain the given window.
+    ///
+    /// Returns `(monitor_index, workspace_index)` or `None` if the
+    /// window is not managed anywhere.
+    pub fn find_window(&self, hwnd: usize) -> Option<(usize, usize)> {
+        for (mi, mon) in self.monitors.iter().enumerate() {
+            for (wi, ws) in mon.workspaces.iter().enumerate() {
+                if ws.contains(hwnd) {
+                    return Some((mi, wi));
+                }
+            }
+        }
+        None
+    }
+
+    /// Adopts an untracked window into the tiling layout if it is tileable.
+    ///
+    /// Some applications (e.g. WPF-based apps like Visual Studio) never
+    /// fire EVENT_OBJECT_CREATE for their main window. This method is
+    /// called from fallback event handlers (TitleChanged, Focused) to
+    /// pick up windows that the Created handler missed.
+    pub(super) fn try_adopt(&mut self, desktop: &dyn Desktop, hwnd: usize) {
+        // Don't use adopt_rejected here — try_adopt is called from
+        // TitleChanged (relaxed id_object filter) where child element
+        // events share the parent hwnd and would poison the cache.
+        if !self.is_tileable(desktop, hwnd) {
+            return;
+        }
+        let Some(idx) = self.monitor_index_for(desktop, hwnd) else {
+            return;
+        };
+        let Some(placed @ (mon, ws)) = self.add_to_monitor(desktop, idx, hwnd, None, true) else {
+            return;
+        };
+        crate::log_info!(
+            "+adopt 0x{:X} [{}] \"{}\" on mon {} ws {} (now {})",
+            hwnd,
+            desktop.class(hwnd),
+            desktop.title(hwnd),
+            mon,
+            ws + 1,
+            self.monitors[mon].workspaces[ws].len()
+        );
+        self.effects.push(Effect::SetCorners {
+            hwnd,
+            style: self.border_config.corner_style,
+        });
+        self.hide_if_placed_away(hwnd, placed);
+        self.apply_layout_on(desktop, mon);
+    }
+
+    /// Adds a window to the focused monitor's active workspace and focuses it.
+    ///
+    /// Shared by the `Created` and `Restored` event handlers. With
+    /// `place`, a matching rule's `monitor` and `workspace` apply; a
+    /// window placed on a hidden workspace is hidden and not focused.
+    pub(super) fn add_and_focus(&mut self, desktop: &dyn Desktop, hwnd: usize, place: bool) {
+        // Place new windows on the focused monitor so they appear
+        // where the user is working, not wherever the OS spawns them.
+        if self.monitors.get(self.focused_monitor).is_none() {
+            return;
+        }
+        let after = self.focused_window;
+        if let Some(placed @ (idx, ws)) =
+            self.add_to_monitor(desktop, self.focused_monitor, hwnd, after, place)
+        {
+            crate::log_info!(
+                "+add 0x{:X} [{}] \"{}\" to mon {} ws {} (now {})",
+                hwnd,
+                desktop.class(hwnd),
+                desktop.title(hwnd),
+                idx,
+                ws + 1,
+                self.monitors[idx].workspaces[ws].len()
+            );
+            self.effects.push(Effect::SetCorners {
+                hwnd,
+                style: self.border_config.corner_style,
+            });
+            if !self.hide_if_placed_away(hwnd, placed) {
+                return;
+            }
+            self.focused_monitor = idx;
+            // Focus the new window before layout so monocle
+            // mode sizes the correct window.
+            self.focused_window = Some(hwnd);
+            // In monocle mode the newest tiled window becomes the
+            // monocle target so it fills the work area.
+            if self.monitors[idx].active_ws().monocle()
+                && self.monitors[idx].active_ws().is_tiled(hwnd)
+            {
+                self.monitors[idx]
+                    .active_ws_mut()
+                    .set_monocle_window(Some(hwnd));
+            }
+            self.apply_layout_on(desktop, idx);
+            self.focus_from_mouse = false;
+            self.focus_and_update_border(desktop, hwnd);
+        }
+    }
+
+    pub(super) fn close_focused(&mut self) {
+        let Some(hwnd) = self.focused_window else {
+            return;
+        };
+        // Ask the window to close so it can handle its own shutdown gracefully.
+        self.effects.push(Effect::Close { hwnd });
+    }
+
+    pub(super) fn minimize_focused(&mut self) {
+        let Some(hwnd) = self.focused_window else {
+            return;
+        };
+        // Minimizing fires a Minimized event, which removes the window
+        // from the active workspace and re-tiles.
+        self.effects.push(Effect::Minimize { hwnd });
+    }
+}
@@ -0,0 +1,243 @@
+//! Layout application: tiling and monocle mode.
+//!
+//! Computes window positions from the workspace split tree (BSP) or
+//! handle list and requests them as `SetRect` effects.
+
+use crate::{
+    ColumnsLayout, CustomLayout, Direction, GridLayout, HorizontalStackLayout, Layout,
+    LayoutDefinition, LayoutKind, Rect, RowsLayout, ThreeColumnLayout, VerticalStackLayout,
+};
+
+use super::{Desktop, Effect, TilingState, workspace_number};
+
+impl TilingState {
+    /// Returns the gap for the monitor at `mon_idx`.
+    pub(super) fn gap(&self, mon_idx: usize) -> i32 {
+        self.overrides(mon_idx).gap.unwrap_or(self.layout_gap)
+    }
+
+    /// Computes the layout positions for the active workspace on the given monitor.
+    pub fn compute_positions(&self, monitor_idx: usize) -> Vec<(usize, Rect)> {
+        let Some(state) = self.monitors.get(monitor_idx) else {
+            return Vec::new();
+        };
+        let gap = self.gap(monitor_idx);
+        let ratio = state.active_ws().master_ratio();
+        let master_count = state.active_ws().master_count();
+        let layout: &dyn Layout = match state.active_ws().layout_kind() {
+            LayoutKind::Bsp => return state.active_ws().tree().layout(&state.work_area, gap),
+            LayoutKind::VerticalStack => &VerticalStackLayout {
+                gap,
+                ratio,
+                master_count,
+            },
+            LayoutKind::HorizontalStack => &HorizontalStackLayout {
+                gap,
+                ratio,
+                master_count,
+            },
+            LayoutKind::ThreeColumn => &ThreeColumnLayout {
+                gap,
+                ratio,
+                master_count,
+            },
+            LayoutKind::Grid => &GridLayout { gap },
+            LayoutKind::Columns => &ColumnsLayout { gap },
+            LayoutKind::Rows => &RowsLayout { gap },
+            LayoutKind::Custom(name) => {
+                match self.custom_layouts.iter().find(|l| &l.name == name) {
+                    Some(def) => &CustomLayout {
+                        gap,
+                        root: &def.root,
+                    },
+                    // An unknown custom layout falls back to BSP.
+                    None => return state.active_ws().tree().layout(&state.work_area, gap),
+                }
+            }
+        };
+        state.active_ws().compute_layout(layout, &state.work_area)
+    }
+
+    /// Steps the focused workspace forward or backward through its
+    /// layout cycle.
+    pub(super) fn cycle_layout(&mut self, desktop: &dyn Desktop, forward: bool) {
+        if self.monitors.is_empty() {
+            return;
+        }
+        let idx = self.focused_monitor;
+        let mon = &self.monitors[idx];
+        let cycle = workspace_number(mon.active_workspace)
+            .and_then(|num| self.workspace_cycles.get(&num))
+            .unwrap_or(&self.layout_cycle);
+        let current = mon.active_ws().layout_kind();
+        let kind = if forward {
+            current.next(cycle)
+        } else {
+            current.prev(cycle)
+        };
+        self.set_layout(desktop, kind);
+    }
+
+    /// Replaces the custom layouts without retiling.
+    ///
+    /// Call before adopting windows so the first tiling already uses
+    /// them.
+    pub fn set_custom_layouts(&mut self, layouts: Vec<LayoutDefinition>) {
+        self.custom_layouts = layouts;
+    }
+
+    /// Replaces the custom layouts and retiles every monitor.
+    pub fn reload_layouts(
+        &mut self,
+        desktop: &dyn Desktop,
+        layouts: Vec<LayoutDefinition>,
+    ) -> Vec<Effect> {
+        self.set_custom_layouts(layouts);
+        self.retile_all(desktop);
+        self.update_border();
+        self.take_effects()
+    }
+
+    /// Switches the focused workspace to `kind` and retiles.
+    pub(super) fn set_layout(&mut self, desktop: &dyn Desktop, kind: LayoutKind) {
+        let idx = self.focused_monitor;
+        let Some(mon) = self.monitors.get_mut(idx) else {
+            return;
+        };
+        mon.active_ws_mut().set_layout_kind(kind);
+        self.apply_layout_on(desktop, idx);
+    }
+
+    /// Moves the divider next to the focused window and retiles.
+    pub(super) fn resize_focused(&mut self, desktop: &dyn Desktop, dir: Direction) {
+        let Some(hwnd) = self.focused_window else {
+            return;
+        };
+        let idx = self.focused_monitor;
+        let step = self.resize_step;
+        let Some(mon) = self.monitors.get_mut(idx) else {
+            return;
+        };
+        // Monocle fills the work area; there is no divider to move.
+        if mon.active_ws().monocle() {
+            return;
+        }
+        if mon.active_ws_mut().resize(hwnd, dir, step) {
+            self.apply_layout_on(desktop, idx);
+            self.update_border();
+        }
+    }
+
+    /// Adds or removes one window from the focused workspace's master
+    /// pane and retiles.
+    pub(super) fn adjust_master_count(&mut self, desktop: &dyn Desktop, increase: bool) {
+        let idx = self.focused_monitor;
+        let Some(mon) = self.monitors.get_mut(idx) else {
+            return;
+        };
+        if mon.active_ws_mut().adjust_master_count(increase) {
+            self.apply_layout_on(desktop, idx);
+            self.update_border();
+        }
+    }
+
+    /// Swaps the focused window into the master slot and retiles.
+    pub(super) fn promote_to_master(&mut self, desktop: &dyn Desktop) {
+        let Some(hwnd) = self.focused_window else {
+            return;
+        };
+        let Some((mon_idx, ws_idx)) = self.find_window(hwnd) else {
+            return;
+        };
+        if ws_idx != self.monitors[mon_idx].active_workspace {
+            return;
+        }
+        if self.monitors[mon_idx].active_ws_mut().promote(hwnd) {
+            self.apply_layout_on(desktop, mon_idx);
+            self.update_border();
+        }
+    }
+
+    pub(super) fn toggle_monocle(&mut self, desktop: &dyn Desktop) {
+        if self.monitors.is_empty() {
+            return;
+        }
+        let idx = self.focused_monitor;
+        let focused = self.focused_window;
+        let ws = self.monitors[idx].active_ws_mut();
+        let enabling = !ws.monocle();
+        ws.set_monocle(enabling);
+        if enabling {
+            ws.set_monocle_window(focused);
+        } else {
+            ws.set_monocle_window(None);
+        }
+        self.apply_layout_on(desktop, idx);
+    }
+
+    pub(super) fn retile_all(&mut self, desktop: &dyn Desktop) {
+        for i in 0..self.monitors.len() {
+            self.apply_layout_on(desktop, i);
+        }
+    }
+
+    pub(super) fn apply_layout_on(&mut self, desktop: &dyn Desktop, monitor_idx: usize) {
+        self.prune_stale_handles(desktop, monitor_idx);
+
+        let Some(state) = self.monitors.get(monitor_idx) else {
+            return;
+        };
+        if state.active_ws().monocle() {
+            // In monocle mode, the remembered monocle window fills the
+            // work area. Fall back to focused_window when monocle_window
+            // is unset (e.g. toggled without a specific target).
+            let monocle_hwnd = state.active_ws().monocle_window().or(self.focused_window);
+            if let Some(hwnd) = monocle_hwnd
+                && state.active_ws().is_tiled(hwnd)
+            {
+                let gap = self.gap(monitor_idx);
+                let rect = Rect::new(
+                    state.work_area.x + gap,
+                    state.work_area.y + gap,
+                    (state.work_area.width - gap * 2).max(1),
+                    (state.work_area.height - gap * 2).max(1),
+                );
+                self.effects.push(Effect::SetRect { hwnd, rect });
+                self.update_border();
+                return;
+            }
+        }
+        let positions = self.compute_positions(monitor_idx);
+        self.effects.extend(
+            positions
+                .into_iter()
+                .map(|(hwnd, rect)| Effect::SetRect { hwnd, rect }),
+        );
+        self.update_border();
+    }
+
+    /// Removes handles from the workspace that are no longer valid windows.
+    ///
+    /// Some windows (especially UWP and WinUI apps) may close without
+    /// firing a reliable `Destroyed` event. Similarly, a window may be
+    /// minimized without firing `Minimized` (e.g. it was minimized
+    /// before being adopted, or the event was lost). This ensures stale
+    /// or minimized handles don't leave phantom slots in the layout.
+    fn prune_stale_handles(&mut self, desktop: &dyn Desktop, monitor_idx: usize) {
+        let Some(state) = self.monitors.get_mut(monitor_idx) else {
+            return;
+        };
+        // Floating windows hold no slot, so they are left alone.
+        let stale: Vec<usize> = state
+            .active_ws()
+            .handles()
+            .iter()
+            .copied()
+            .filter(|&hwnd| !desktop.is_visible(hwnd) || desktop.is_minimized(hwnd))
+            .collect();
+        for hwnd in &stale {
+            state.active_ws_mut().remove(*hwnd);
+            crate::log_info!("-prune 0x{:X} (stale or minimized)", hwnd);
+        }
+    }
+}
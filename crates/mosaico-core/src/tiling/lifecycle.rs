@@ -0,0 +1,127 @@
+//! Lifecycle and configuration reload for the tiling state machine.
+
+use crate::config::{Config, WindowRule};
+
+use super::{Desktop, Effect, TilingState};
+
+impl TilingState {
+    /// Shows all windows across every workspace and monitor.
+    ///
+    /// Called on daemon shutdown so that windows hidden by workspace
+    /// switching are restored and not left invisible.
+    pub fn restore_all_windows(&mut self) -> Vec<Effect> {
+        self.effects.push(Effect::HideBorder);
+        for mon in &self.monitors {
+            for ws in &mon.workspaces {
+                for hwnd in ws.windows() {
+                    self.effects.push(Effect::ResetCorners { hwnd });
+                    self.effects.push(Effect::Restore { hwnd });
+                }
+            }
+        }
+        self.hidden_by_switch.clear();
+        self.take_effects()
+    }
+
+    /// Applies a new layout and border config, then retiles all windows.
+    pub fn reload_config(&mut self, desktop: &dyn Desktop, config: &Config) -> Vec<Effect> {
+        // Only a changed ratio resets the workspace ratios, so unrelated
+        // config edits keep interactive resizes.
+        let old_ratios: Vec<f64> = (0..self.monitors.len())
+            .map(|i| self.ratio(&self.overrides(i)))
+            .collect();
+        let master_count_changed = self.master_count != config.layout.master_count;
+        self.layout_gap = config.layout.gap;
+        self.layout_ratio = config.layout.ratio;
+        self.master_count = config.layout.master_count;
+        self.resize_step = config.layout.resize_step;
+        self.layout_cycle.clone_from(&config.layout.cycle);
+        self.workspace_cycles
+            .clone_from(&config.layout.workspace_cycles);
+        self.default_layout.clone_from(&config.layout.default);
+        self.workspace_layouts.clone_from(&config.layout.workspaces);
+        self.workspaces_config.clone_from(&config.workspaces);
+        self.monitor_configs.clone_from(&config.monitor);
+        for mon_idx in 0..self.monitors.len() {
+            self.fit_workspaces(mon_idx);
+        }
+        // Reset workspace layouts to config values.
+        for (mon_idx, old_ratio) in old_ratios.into_iter().enumerate() {
+            let overrides = self.overrides(mon_idx);
+            let ratio = self.ratio(&overrides);
+            let kinds: Vec<_> = (0..self.monitors[mon_idx].workspaces.len())
+                .map(|i| self.initial_layout(&overrides, i))
+                .collect();
+            let master_count = self.master_count;
+            let workspaces = &mut self.monitors[mon_idx].workspaces;
+            for (ws, kind) in workspaces.iter_mut().zip(kinds) {
+                if ratio != old_ratio {
+                    ws.reset_ratios(ratio);
+                }
+                if master_count_changed {
+                    ws.set_master_count(master_count);
+                }
+                ws.set_layout_kind(kind);
+            }
+        }
+        self.hiding = config.layout.hiding;
+        self.border_config = config.borders.clone();
+        self.mouse_follows_focus = config.mouse.follows_focus;
+        self.apply_corner_preference_all();
+        self.retile_all(desktop);
+        self.update_border();
+        self.take_effects()
+    }
+
+    /// Replaces the window rules and removes windows that should no
+    /// longer be managed under the new rule set.
+    pub fn reload_rules(&mut self, desktop: &dyn Desktop, rules: Vec<WindowRule>) -> Vec<Effect> {
+        self.rules = rules;
+        self.adopt_rejected.clear();
+        self.remove_newly_unmanaged(desktop);
+        self.take_effects()
+    }
+
+    /// Removes tiled windows that no longer pass `is_tileable` and
+    /// retiles affected monitors.
+    fn remove_newly_unmanaged(&mut self, desktop: &dyn Desktop) {
+        // Collect first (immutable) to avoid borrow conflicts.
+        let mut removals: Vec<(usize, usize, usize)> = Vec::new();
+        for (mi, mon) in self.monitors.iter().enumerate() {
+            for (wi, ws) in mon.workspaces.iter().enumerate() {
+                for hwnd in ws.windows() {
+                    if !self.is_tileable(desktop, hwnd) {
+                        removals.push((mi, wi, hwnd));
+                    }
+                }
+            }
+        }
+        if removals.is_empty() {
+            return;
+        }
+        let mut affected: Vec<usize> = Vec::new();
+        for &(mi, wi, hwnd) in &removals {
+            self.effects.push(Effect::ResetCorners { hwnd });
+            self.monitors[mi].workspaces[wi].remove(hwnd);
+            crate::log_info!("-rule 0x{hwnd:X} (unmanaged by new rules)");
+            if !affected.contains(&mi) {
+                affected.push(mi);
+            }
+        }
+        for idx in affected {
+            self.apply_layout_on(desktop, idx);
+        }
+    }
+
+    /// Applies the current corner preference to every managed window.
+    fn apply_corner_preference_all(&mut self) {
+        let style = self.border_config.corner_style;
+        for mon in &self.monitors {
+            for ws in &mon.workspaces {
+                for hwnd in ws.windows() {
+                    self.effects.push(Effect::SetCorners { hwnd, style });
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,437 @@
+//! Platform-agnostic tiling state machine.
+//!
+//! [`TilingState`] owns every monitor, workspace and focus decision.
+//! It consumes [`WindowEvent`]s and [`Action`]s, consults a [`Desktop`]
+//! for live window state, and returns the [`Effect`]s the platform must
+//! execute. Nothing in here calls the OS, so the whole tiling behavior
+//! can be exercised headlessly.
+
+mod desktop;
+mod display;
+mod effect;
+mod event_handler;
+mod floating;
+mod focus;
+mod helpers;
+mod layout;
+mod lifecycle;
+mod monitor_memory;
+mod navigation;
+mod navigation_helpers;
+mod rule_placement;
+mod snapshot;
+mod workspace;
+mod workspace_transfer;
+
+use std::collections::{HashMap, HashSet};
+use std::time::Instant;
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::{
+    BorderConfig, HidingBehaviour, LayoutConfig, MonitorConfig, MonitorOverrides, WindowRule,
+    WorkspacesConfig, monitor_overrides,
+};
+use crate::{Action, LayoutDefinition, LayoutKind, Rect, Workspace};
+
+use monitor_memory::MonitorMemory;
+
+pub use desktop::Desktop;
+pub use effect::Effect;
+
+/// A connected monitor as reported by the platform.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MonitorInfo {
+    /// Unique identifier assigned by the platform.
+    pub id: usize,
+    /// Work area excluding the taskbar and docked toolbars.
+    pub work_area: Rect,
+    /// Device name (e.g. `\\.\DISPLAY1`), matched by `[[monitor]]`.
+    #[serde(default)]
+    pub name: String,
+    /// Full monitor area, whose size is the monitor's resolution.
+    #[serde(default)]
+    pub bounds: Rect,
+}
+
+/// Per-monitor state: multiple workspaces and work area.
+pub struct MonitorState {
+    /// Platform monitor identifier.
+    pub id: usize,
+    /// Area available for tiling (bar offsets already applied).
+    pub work_area: Rect,
+    /// Device name reported by the platform.
+    pub name: String,
+    /// Full monitor area, including the taskbar.
+    pub bounds: Rect,
+    /// All workspaces on this monitor.
+    pub workspaces: Vec<Workspace>,
+    /// Index of the visible workspace.
+    pub active_workspace: usize,
+    /// Previously visible workspaces, most recent last. Holds each
+    /// index at most once and never the visible one.
+    pub workspace_history: Vec<usize>,
+}
+
+impl MonitorState {
+    /// Makes workspace `idx` visible, recording the one it replaces in
+    /// the history.
+    pub fn switch_workspace(&mut self, idx: usize) {
+        let prev = self.active_workspace;
+        self.workspace_history.retain(|&i| i != idx && i != prev);
+        self.workspace_history.push(prev);
+        self.active_workspace = idx;
+    }
+
+    /// Returns the visible workspace.
+    pub fn active_ws(&self) -> &Workspace {
+        &self.workspaces[self.active_workspace]
+    }
+
+    /// Returns the visible workspace mutably.
+    pub fn active_ws_mut(&mut self) -> &mut Workspace {
+        &mut self.workspaces[self.active_workspace]
+    }
+}
+
+/// Result of resolving a directional spatial action.
+///
+/// Used by both focus and move operations to share the spatial
+/// neighbor lookup and monitor-overflow logic.
+enum SpatialTarget {
+    /// A neighbor window on the same monitor.
+    Neighbor(usize),
+    /// The adjacent monitor when no same-monitor neighbor exists.
+    AdjacentMonitor(usize),
+}
+
+/// Tiling state for all connected monitors.
+pub struct TilingState {
+    monitors: Vec<MonitorState>,
+    layout_gap: i32,
+    layout_ratio: f64,
+    /// Master pane window count workspaces start with.
+    master_count: usize,
+    /// Ratio change applied by one `resize-*` action.
+    resize_step: f64,
+    /// Layouts `cycle-layout` steps through.
+    layout_cycle: Vec<LayoutKind>,
+    /// Per-workspace overrides of `layout_cycle`, keyed by number.
+    workspace_cycles: HashMap<u8, Vec<LayoutKind>>,
+    /// Layout new workspaces start with.
+    default_layout: LayoutKind,
+    /// Per-workspace overrides of `default_layout`, keyed by number.
+    workspace_layouts: HashMap<u8, LayoutKind>,
+    /// Workspace count and names.
+    workspaces_config: WorkspacesConfig,
+    /// `[[monitor]]` blocks overriding the settings above per monitor.
+    monitor_configs: Vec<MonitorConfig>,
+    /// User-defined layouts from `layouts.toml`.
+    custom_layouts: Vec<LayoutDefinition>,
+    /// Homes of windows migrated off disconnected monitors.
+    monitor_memory: MonitorMemory,
+    rules: Vec<WindowRule>,
+    border_config: BorderConfig,
+    focused_monitor: usize,
+    focused_window: Option<usize>,
+    /// Tracks whether the focused window is currently maximized.
+    ///
+    /// Used to detect maximize/restore transitions from
+    /// `LocationChanged` events without refreshing the border on
+    /// every animation frame.
+    focused_maximized: bool,
+    /// Whether keyboard-driven focus should move the cursor.
+    mouse_follows_focus: bool,
+    /// Suppresses cursor moves after mouse-driven focus.
+    focus_from_mouse: bool,
+    /// How windows are hidden during workspace switches.
+    hiding: HidingBehaviour,
+    /// Windows hidden programmatically by workspace switching.
+    ///
+    /// Events for these hwnds are ignored until they are shown again.
+    /// This prevents `Hidden` events from removing windows that were
+    /// just hidden by a workspace switch.
+    hidden_by_switch: HashSet<usize>,
+    /// Cooldown timestamp suppressing focus-triggered workspace switches.
+    ///
+    /// Platform events fired during a workspace switch (e.g. by
+    /// focusing a window or repositioning it) are queued asynchronously
+    /// and may arrive after the switch completes. Checking a boolean
+    /// guard is insufficient because the flag is cleared before these
+    /// deferred events are processed.
+    ///
+    /// Instead we record the `Instant` when the switch finishes and
+    /// suppress focus-triggered switches until a short cooldown
+    /// elapses, preventing infinite workspace-switching loops.
+    ws_switch_cooldown: Option<Instant>,
+    /// Windows that `try_adopt` already rejected via `is_tileable`.
+    ///
+    /// Prevents re-evaluating expensive platform queries (visibility,
+    /// elevation, class, title) on every deferred event for windows
+    /// that will never be managed (e.g. elevated Visual Studio).
+    /// Cleared on rule reload; entries removed on `Destroyed`.
+    adopt_rejected: HashSet<usize>,
+    /// Windows floated by a rule with `reevaluate_on_title_change`.
+    ///
+    /// They are tiled again once no such rule matches their title.
+    /// Entries are dropped when the window is tiled or leaves tiling.
+    rule_floated: HashSet<usize>,
+    /// Effects queued by the current call, drained when it returns.
+    effects: Vec<Effect>,
+}
+
+impl TilingState {
+    /// Creates tiling state for the given monitors with empty workspaces.
+    pub fn new(
+        layout_config: &LayoutConfig,
+        workspaces_config: &WorkspacesConfig,
+        monitor_configs: &[MonitorConfig],
+        rules: Vec<WindowRule>,
+        border_config: BorderConfig,
+        mouse_follows_focus: bool,
+        monitors: Vec<MonitorInfo>,
+    ) -> Self {
+        let mut state = Self {
+            monitors: Vec::new(),
+            layout_gap: layout_config.gap,
+            layout_ratio: layout_config.ratio,
+            master_count: layout_config.master_count,
+            resize_step: layout_config.resize_step,
+            layout_cycle: layout_config.cycle.clone(),
+            workspace_cycles: layout_config.workspace_cycles.clone(),
+            default_layout: layout_config.default.clone(),
+            workspace_layouts: layout_config.workspaces.clone(),
+            workspaces_config: workspaces_config.clone(),
+            monitor_configs: monitor_configs.to_vec(),
+            custom_layouts: Vec::new(),
+            monitor_memory: MonitorMemory::default(),
+            rules,
+            border_config,
+            focused_monitor: 0,
+            focused_window: None,
+            focused_maximized: false,
+            mouse_follows_focus,
+            focus_from_mouse: false,
+            hiding: layout_config.hiding,
+            hidden_by_switch: HashSet::new(),
+            ws_switch_cooldown: None,
+            adopt_rejected: HashSet::new(),
+            rule_floated: HashSet::new(),
+            effects: Vec::new(),
+        };
+        state.monitors = monitors
+            .into_iter()
+            .enumerate()
+            .map(|(i, info)| MonitorState {
+                workspaces: state.new_workspaces(i, &info),
+                id: info.id,
+                work_area: info.work_area,
+                name: info.name,
+                bounds: info.bounds,
+                active_workspace: 0,
+                workspace_history: Vec::new(),
+            })
+            .collect();
+        state
+    }
+
+    /// Adopts windows that already exist at startup, tiles them, and
+    /// focuses the first window on the primary monitor.
+    pub fn adopt_existing(&mut self, desktop: &dyn Desktop, handles: &[usize]) -> Vec<Effect> {
+        for &hwnd in handles {
+            if self.is_tileable(desktop, hwnd)
+                && let Some(idx) = self.monitor_index_for(desktop, hwnd)
+                && let Some(placed) = self.add_to_monitor(desktop, idx, hwnd, None, true)
+            {
+                self.effects.push(Effect::SetCorners {
+                    hwnd,
+                    style: self.border_config.corner_style,
+                });
+                self.hide_if_placed_away(hwnd, placed);
+            }
+        }
+
+        self.retile_all(desktop);
+        self.focus_initial(desktop);
+        self.take_effects()
+    }
+
+    /// Executes a user-triggered action.
+    pub fn handle_action(&mut self, desktop: &dyn Desktop, action: &Action) -> Vec<Effect> {
+        match action {
+            Action::Focus(dir) => {
+                self.focus_from_mouse = false;
+                self.focus_direction(desktop, *dir);
+            }
+            Action::Move(dir) => self.move_direction(desktop, *dir),
+            Action::Resize(dir) => self.resize_focused(desktop, *dir),
+            Action::Retile => self.retile_all(desktop),
+            Action::ToggleMonocle => self.toggle_monocle(desktop),
+            Action::ToggleFloat => self.toggle_float(desktop),
+            Action::CloseFocused => self.close_focused(),
+            Action::MinimizeFocused => self.minimize_focused(),
+            Action::CycleLayout => self.cycle_layout(desktop, true),
+            Action::CycleLayoutPrev => self.cycle_layout(desktop, false),
+            Action::SetLayout(kind) => self.set_layout(desktop, kind.clone()),
+            Action::IncreaseMaster => self.adjust_master_count(desktop, true),
+            Action::DecreaseMaster => self.adjust_master_count(desktop, false),
+            Action::PromoteToMaster => self.promote_to_master(desktop),
+            Action::GoToWorkspace(ws) => {
+                if let Some(idx) = self.resolve_workspace(ws) {
+                    self.goto_or_back(desktop, idx);
+                }
+            }
+            Action::GoToWorkspaceNext => self.goto_adjacent_workspace(desktop, true),
+            Action::GoToWorkspacePrev => self.goto_adjacent_workspace(desktop, false),
+            Action::GoToWorkspaceLast => self.goto_last_workspace(desktop),
+            Action::MoveWorkspaceToMonitor(target) => {
+                self.move_workspace_to_monitor(desktop, *target);
+            }
+            Action::SwapWorkspacesWithMonitor(dir) => {
+                self.swap_workspaces_with_monitor(desktop, *dir);
+            }
+            Action::SendToMonitor {
+                monitor,
+                workspace,
+                follow,
+            } => self.send_to_monitor(desktop, *monitor, workspace.as_ref(), *follow),
+            Action::SendToWorkspace(ws) => {
+                if let Some(idx) = self.resolve_workspace(ws) {
+                    self.send_to_workspace(desktop, idx);
+                }
+            }
+            // TogglePause is handled by the daemon before reaching here.
+            Action::TogglePause => {}
+        }
+        self.take_effects()
+    }
+
+    /// Returns the total number of managed windows, tiled and floating.
+    pub fn window_count(&self) -> usize {
+        self.monitors
+            .iter()
+            .flat_map(|m| &m.workspaces)
+            .map(|ws| ws.len())
+            .sum()
+    }
+
+    /// Returns the state of every monitor, in platform order.
+    pub fn monitors(&self) -> &[MonitorState] {
+        &self.monitors
+    }
+
+    /// Returns the index of the focused monitor.
+    pub fn focused_monitor(&self) -> usize {
+        self.focused_monitor
+    }
+
+    /// Returns the focused window, if any.
+    pub fn focused_window(&self) -> Option<usize> {
+        self.focused_window
+    }
+
+    /// Returns each monitor's `bar` override from `[[monitor]]` blocks,
+    /// in platform order.
+    pub fn monitor_bars(&self) -> Vec<Option<bool>> {
+        (0..self.monitors.len())
+            .map(|i| self.overrides(i).bar)
+            .collect()
+    }
+
+    /// Returns the bar label of each workspace on the monitor at
+    /// `mon_idx`: its configured name, or its number.
+    pub fn workspace_labels(&self, mon_idx: usize) -> Vec<String> {
+        let count = self.monitors.get(mon_idx).map_or(0, |m| m.workspaces.len());
+        (0..count)
+            .map(|i| self.workspaces_config.label(i))
+            .collect()
+    }
+
+    /// Returns the active border configuration.
+    pub fn border_config(&self) -> &BorderConfig {
+        &self.border_config
+    }
+
+    /// Re-emits the focus border for the focused window.
+    ///
+    /// Call after work areas change (e.g. bar adjustment at startup) so
+    /// the border reflects the final window position, not the pre-adjustment one.
+    pub fn refresh_border(&mut self) -> Vec<Effect> {
+        self.update_border();
+        self.take_effects()
+    }
+
+    /// Drains the effects queued so far.
+    fn take_effects(&mut self) -> Vec<Effect> {
+        std::mem::take(&mut self.effects)
+    }
+
+    /// Returns the `[[monitor]]` overrides for the monitor at `mon_idx`.
+    fn overrides(&self, mon_idx: usize) -> MonitorOverrides {
+        self.monitors
+            .get(mon_idx)
+            .map_or_else(Default::default, |m| {
+                monitor_overrides(&self.monitor_configs, mon_idx, &m.name, &m.bounds)
+            })
+    }
+
+    /// Returns the `[[monitor]]` overrides for a monitor not yet in
+    /// `self.monitors`.
+    fn overrides_for(&self, mon_idx: usize, info: &MonitorInfo) -> MonitorOverrides {
+        monitor_overrides(&self.monitor_configs, mon_idx, &info.name, &info.bounds)
+    }
+
+    /// Returns the layout workspace `idx` (0-based) starts with: its
+    /// per-workspace override, the monitor's layout, or the default.
+    fn initial_layout(&self, overrides: &MonitorOverrides, idx: usize) -> LayoutKind {
+        workspace_number(idx)
+            .and_then(|num| self.workspace_layouts.get(&num))
+            .or(overrides.layout.as_ref())
+            .unwrap_or(&self.default_layout)
+            .clone()
+    }
+
+    /// Returns the split ratio for the monitor's workspaces.
+    fn ratio(&self, overrides: &MonitorOverrides) -> f64 {
+        overrides.ratio.unwrap_or(self.layout_ratio)
+    }
+
+    /// Returns how many workspaces a monitor has.
+    fn workspace_count(&self, overrides: &MonitorOverrides, mon_idx: usize) -> usize {
+        overrides
+            .workspaces
+            .map_or_else(|| self.workspaces_config.count_for(mon_idx), usize::from)
+    }
+
+    /// Creates an empty workspace at `idx` (0-based) on the monitor at
+    /// `mon_idx`, honoring the layout overrides from the config.
+    fn new_workspace(&self, mon_idx: usize, idx: usize) -> Workspace {
+        self.new_workspace_with(&self.overrides(mon_idx), idx)
+    }
+
+    fn new_workspace_with(&self, overrides: &MonitorOverrides, idx: usize) -> Workspace {
+        let mut ws = Workspace::with_layout(self.initial_layout(overrides, idx));
+        ws.reset_ratios(self.ratio(overrides));
+        ws.set_master_count(self.master_count);
+        ws
+    }
+
+    /// Creates the full set of workspaces for the monitor at `mon_idx`.
+    fn new_workspaces(&self, mon_idx: usize, info: &MonitorInfo) -> Vec<Workspace> {
+        let overrides = self.overrides_for(mon_idx, info);
+        (0..self.workspace_count(&overrides, mon_idx))
+            .map(|i| self.new_workspace_with(&overrides, i))
+            .collect()
+    }
+}
+
+/// Returns the 1-based number of the workspace at `idx` (0-based), as
+/// used by config tables keyed by workspace number, or `None` if it has
+/// no such number.
+fn workspace_number(idx: usize) -> Option<u8> {
+    u8::try_from(idx + 1).ok()
+}
+
+#[cfg(test)]
+#[path = "tests.rs"]
+mod tests;
@@ -0,0 +1,89 @@
+//! Remembers where windows lived on monitors that were disconnected.
+//!
+//! When a monitor goes away its windows are migrated to the primary
+//! monitor, but each window's home (the monitor and workspace it came
+//! from) is kept here. When a matching monitor reconnects, the display
+//! change handler asks for the homes and moves the windows back.
+
+use crate::Rect;
+
+/// A disconnected monitor and the windows that lived on it.
+#[derive(Debug, Clone)]
+struct RememberedMonitor {
+    /// Platform monitor identifier at the time of disconnection.
+    id: usize,
+    /// Last known work area, used to recognise the monitor if it comes
+    /// back with a new identifier.
+    work_area: Rect,
+    /// Workspace that was visible when the monitor went away.
+    active_workspace: usize,
+    /// `(hwnd, workspace index)` for every window that lived there.
+    homes: Vec<(usize, usize)>,
+}
+
+/// Homes of windows migrated off disconnected monitors.
+#[derive(Debug, Clone, Default)]
+pub(super) struct MonitorMemory {
+    monitors: Vec<RememberedMonitor>,
+}
+
+/// What a reconnected monitor should get back.
+pub(super) struct Homecoming {
+    /// Workspace to make visible.
+    pub(super) active_workspace: usize,
+    /// `(hwnd, workspace index)` pairs to move back.
+    pub(super) homes: Vec<(usize, usize)>,
+}
+
+impl MonitorMemory {
+    /// Records a disconnected monitor and its windows.
+    ///
+    /// Replaces any earlier record of the same monitor.
+    pub(super) fn remember(
+        &mut self,
+        id: usize,
+        work_area: Rect,
+        active_workspace: usize,
+        homes: Vec<(usize, usize)>,
+    ) {
+        self.monitors
+            .retain(|m| m.id != id && !same_monitor_area(&m.work_area, &work_area));
+        self.monitors.push(RememberedMonitor {
+            id,
+            work_area,
+            active_workspace,
+            homes,
+        });
+    }
+
+    /// Takes the record of the monitor matching `id` (or, failing that,
+    /// `work_area`), if one was remembered.
+    pub(super) fn recall(&mut self, id: usize, work_area: &Rect) -> Option<Homecoming> {
+        let idx = self.monitors.iter().position(|m| m.id == id).or_else(|| {
+            self.monitors
+                .iter()
+                .position(|m| same_monitor_area(&m.work_area, work_area))
+        })?;
+        let mon = self.monitors.remove(idx);
+        Some(Homecoming {
+            active_workspace: mon.active_workspace,
+            homes: mon.homes,
+        })
+    }
+
+    /// Forgets a window, e.g. because it was destroyed.
+    pub(super) fn forget(&mut self, hwnd: usize) {
+        for mon in &mut self.monitors {
+            mon.homes.retain(|&(h, _)| h != hwnd);
+        }
+    }
+}
+
+/// Returns whether two work areas belong to the same physical monitor.
+///
+/// Compares the horizontal extent and the bottom edge, which a top bar
+/// offset does not change, so a bar-adjusted area still matches the
+/// area the platform reports.
+fn same_monitor_area(a: &Rect, b: &Rect) -> bool {
+    a.x == b.x && a.width == b.width && a.y + a.height == b.y + b.height
+}
@@ -0,0 +1,140 @@
+//! Spatial navigation: focus and move in cardinal directions.
+//!
+//! Handles same-monitor neighbor lookup, cross-monitor overflow,
+//! and spatial entry-point selection when jumping between monitors.
+
+use crate::action::Direction;
+
+use super::{Desktop, SpatialTarget, TilingState};
+
+impl TilingState {
+    /// Focuses a window in the given spatial direction.
+    ///
+    /// Picks the spatial neighbor on the same monitor, overflowing to
+    /// the adjacent monitor in that direction at the edge.
+    pub(super) fn focus_direction(&mut self, desktop: &dyn Desktop, dir: Direction) {
+        if self.monitors.is_empty() {
+            return;
+        }
+        if self.monitors[self.focused_monitor].active_ws().monocle() {
+            // Monocle: skip same-monitor neighbors, jump to the adjacent
+            // monitor as if this were the only window.
+            self.focus_adjacent_monitor(desktop, dir);
+            return;
+        }
+        match self.resolve_spatial_target(dir) {
+            Some(SpatialTarget::Neighbor(hwnd)) => {
+                self.focus_and_update_border(desktop, hwnd);
+            }
+            Some(SpatialTarget::AdjacentMonitor(idx)) => {
+                self.focus_adjacent_monitor_idx(desktop, idx, dir);
+            }
+            None if self.focused_window.is_none() => {
+                // No focused window (empty workspace) — jump to the
+                // adjacent monitor directly.
+                self.focus_adjacent_monitor(desktop, dir);
+            }
+            None => {}
+        }
+    }
+
+    /// Focuses the entry window on the nearest monitor in the given direction.
+    fn focus_adjacent_monitor(&mut self, desktop: &dyn Desktop, dir: Direction) {
+        if let Some(idx) = self.find_adjacent_monitor(dir) {
+            self.focus_adjacent_monitor_idx(desktop, idx, dir);
+        }
+    }
+
+    /// Focuses the entry window on a specific monitor.
+    ///
+    /// When the target monitor is in monocle mode, the first window in
+    /// the workspace is focused and expanded to fill the screen.
+    fn focus_adjacent_monitor_idx(&mut self, desktop: &dyn Desktop, idx: usize, dir: Direction) {
+        self.focused_monitor = idx;
+        let mon = &self.monitors[idx];
+        let entry = if mon.active_ws().monocle() {
+            // Return to the remembered monocle window, or fall back
+            // to the first window in the workspace.
+            mon.active_ws()
+                .monocle_window()
+                .or_else(|| mon.active_ws().handles().first().copied())
+        } else {
+            self.find_entry_window(idx, dir)
+        };
+        if let Some(hwnd) = entry {
+            self.focus_and_update_border(desktop, hwnd);
+            if self.monitors[idx].active_ws().monocle() {
+                self.apply_layout_on(desktop, idx);
+            }
+        } else {
+            self.focused_window = None;
+            self.update_border();
+        }
+    }
+
+    /// Moves (swaps) the focused window in the given spatial direction.
+    ///
+    /// Swaps with the spatial neighbor on the same monitor, overflowing
+    /// to the adjacent monitor in that direction at the edge.
+    pub(super) fn move_direction(&mut self, desktop: &dyn Desktop, dir: Direction) {
+        if self.monitors.is_empty() {
+            return;
+        }
+        let Some(hwnd) = self.focused_window else {
+            return;
+        };
+        // Monocle: window is stuck until monocle is removed.
+        if self.monitors[self.focused_monitor].active_ws().monocle() {
+            return;
+        }
+        match self.resolve_spatial_target(dir) {
+            Some(SpatialTarget::Neighbor(neighbor)) => {
+                let ws = self.monitors[self.focused_monitor].active_ws();
+                let Some(a) = ws.index_of(hwnd) else { return };
+                let Some(b) = ws.index_of(neighbor) else {
+                    return;
+                };
+                self.swap_and_retile(desktop, a, b);
+            }
+            Some(SpatialTarget::AdjacentMonitor(target)) => {
+                self.move_to_monitor(desktop, hwnd, target, dir);
+            }
+            None => {}
+        }
+    }
+
+    /// Moves the focused window to a specific target monitor.
+    fn move_to_monitor(
+        &mut self,
+        desktop: &dyn Desktop,
+        hwnd: usize,
+        target: usize,
+        dir: Direction,
+    ) {
+        let source = self.focused_monitor;
+        self.monitors[source].active_ws_mut().remove(hwnd);
+        // Enter on the side facing the source monitor.
+        if matches!(dir, Direction::Right | Direction::Down) {
+            self.monitors[target].active_ws_mut().insert(0, hwnd);
+        } else {
+            self.monitors[target].active_ws_mut().add(hwnd);
+        }
+        self.apply_layout_on(desktop, source);
+        self.apply_layout_on(desktop, target);
+        self.focused_monitor = target;
+        self.update_border();
+        self.move_cursor_to_focused();
+    }
+
+    /// Swaps two windows by workspace index, re-tiles, and updates the border.
+    fn swap_and_retile(&mut self, desktop: &dyn Desktop, a: usize, b: usize) {
+        self.monitors[self.focused_monitor]
+            .active_ws_mut()
+            .swap(a, b);
+        self.apply_layout_on(desktop, self.focused_monitor);
+        self.update_border();
+        self.move_cursor_to_focused();
+    }
+
+    // helper methods live in navigation_helpers.rs
+}
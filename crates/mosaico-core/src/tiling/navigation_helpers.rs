@@ -0,0 +1,86 @@
+//! Helper methods for spatial navigation.
+
+use crate::MonitorTopology;
+use crate::action::Direction;
+
+use super::{SpatialTarget, TilingState};
+
+impl TilingState {
+    /// Finds the nearest monitor in the given direction.
+    pub(super) fn find_adjacent_monitor(&self, dir: Direction) -> Option<usize> {
+        self.topology().neighbor(self.focused_monitor, dir)
+    }
+
+    /// Returns the current monitor arrangement.
+    pub(super) fn topology(&self) -> MonitorTopology {
+        let rects: Vec<_> = self.monitors.iter().map(|m| m.work_area).collect();
+        MonitorTopology::new(&rects)
+    }
+
+    /// Resolves the spatial target for a directional action.
+    ///
+    /// Looks for a neighbor on the same monitor first. If none exists,
+    /// looks for a monitor physically in the requested direction (no
+    /// wrapping).
+    pub(super) fn resolve_spatial_target(&self, dir: Direction) -> Option<SpatialTarget> {
+        let focused_hwnd = self.focused_window?;
+
+        let positions = self.compute_positions(self.focused_monitor);
+
+        crate::log_debug!(
+            "resolve_spatial dir={} mon={} focused=0x{:X} windows={}",
+            dir,
+            self.focused_monitor,
+            focused_hwnd,
+            positions.len()
+        );
+        for (h, r) in &positions {
+            crate::log_debug!(
+                "  pos 0x{:X}: ({},{} {}x{}) center_x={}",
+                h,
+                r.x,
+                r.y,
+                r.width,
+                r.height,
+                r.center_x()
+            );
+        }
+
+        let focused_rect = positions
+            .iter()
+            .find(|(h, _)| *h == focused_hwnd)
+            .map(|(_, r)| *r)?;
+
+        crate::log_debug!(
+            "  focused_rect: ({},{} {}x{}) center_x={}",
+            focused_rect.x,
+            focused_rect.y,
+            focused_rect.width,
+            focused_rect.height,
+            focused_rect.center_x()
+        );
+
+        if let Some(neighbor) = crate::spatial::find_neighbor(&positions, &focused_rect, dir) {
+            crate::log_debug!("  -> Neighbor 0x{:X}", neighbor);
+            return Some(SpatialTarget::Neighbor(neighbor));
+        }
+
+        // No neighbor — check if a monitor exists in the requested
+        // direction (no wrapping).
+        match self.find_adjacent_monitor(dir) {
+            Some(idx) => {
+                crate::log_debug!("  -> AdjacentMonitor {}", idx);
+                Some(SpatialTarget::AdjacentMonitor(idx))
+            }
+            None => {
+                crate::log_debug!("  -> None (no monitor in dir {})", dir);
+                None
+            }
+        }
+    }
+
+    pub(super) fn find_entry_window(&self, monitor_idx: usize, dir: Direction) -> Option<usize> {
+        let positions = self.compute_positions(monitor_idx);
+        crate::spatial::find_entry(&positions, dir)
+    }
+}
@@ -0,0 +1,164 @@
+//! Applying window rules when a window joins the tiling layout.
+
+use crate::config::{WindowProps, WindowRule};
+
+use super::{Desktop, Effect, TilingState};
+
+impl TilingState {
+    /// Adds a new window to a monitor, following the first matching rule.
+    ///
+    /// Windows matched by a `float = true` rule float at the rule's
+    /// initial rect. Others are tiled at the rule's `slot`, after `after`
+    /// when it is on the workspace, or at the end. With `place`, the
+    /// rule's `monitor` and `workspace` replace `idx` and its active
+    /// workspace.
+    ///
+    /// Returns the monitor and workspace indices the window landed on.
+    pub(super) fn add_to_monitor(
+        &mut self,
+        desktop: &dyn Desktop,
+        idx: usize,
+        hwnd: usize,
+        after: Option<usize>,
+        place: bool,
+    ) -> Option<(usize, usize)> {
+        let props = window_props(desktop, hwnd);
+        let rule = crate::config::find_rule(&props, &self.rules).filter(|rule| rule.manage);
+        let (mon, ws_idx) = match rule {
+            Some(rule) if place => self.rule_target(rule, idx),
+            _ => (idx, self.monitors[idx].active_workspace),
+        };
+        let float = rule
+            .filter(|rule| rule.float)
+            .map(|rule| rule.float_rect(&self.monitors[mon].work_area, desktop.rect(hwnd)));
+        let slot = rule.and_then(|rule| rule.slot);
+        let monocle = rule.is_some_and(|rule| rule.monocle);
+        let reevaluate = rule.is_some_and(|rule| rule.reevaluate_on_title_change);
+
+        let ws = &mut self.monitors[mon].workspaces[ws_idx];
+        if let Some(rect) = float {
+            if !ws.add_floating(hwnd, rect) {
+                return None;
+            }
+            if reevaluate {
+                self.rule_floated.insert(hwnd);
+            }
+            self.effects.push(Effect::SetRect { hwnd, rect });
+            return Some((mon, ws_idx));
+        }
+        let added = match (slot, after) {
+            (Some(slot), _) => ws.insert(slot, hwnd),
+            (None, Some(target)) => ws.add_after(target, hwnd),
+            (None, None) => ws.add(hwnd),
+        };
+        if !added {
+            return None;
+        }
+        if monocle {
+            ws.set_monocle(true);
+            ws.set_monocle_window(Some(hwnd));
+        }
+        Some((mon, ws_idx))
+    }
+
+    /// Re-applies the rules to a managed window after its title changed.
+    ///
+    /// Only a rule with `reevaluate_on_title_change` that matches the new
+    /// title acts: the window is released when the rule does not manage
+    /// it, and floated or tiled to follow the rule's `float`. A window
+    /// such a rule floated is tiled again once none matches. Windows on
+    /// hidden workspaces are left alone.
+    pub(super) fn reevaluate_rules(&mut self, desktop: &dyn Desktop, hwnd: usize) {
+        if !self
+            .rules
+            .iter()
+            .any(|rule| rule.reevaluate_on_title_change)
+        {
+            return;
+        }
+        let Some((mon, ws_idx)) = self.find_window(hwnd) else {
+            return;
+        };
+        if ws_idx != self.monitors[mon].active_workspace {
+            return;
+        }
+        let props = window_props(desktop, hwnd);
+        let rule = crate::config::find_rule(&props, &self.rules)
+            .filter(|rule| rule.reevaluate_on_title_change);
+        let float = match rule {
+            Some(rule) if !rule.manage => {
+                self.effects.push(Effect::ResetCorners { hwnd });
+                self.remove_from_tiling(desktop, hwnd, "rule", true);
+                return;
+            }
+            Some(rule) => rule
+                .float
+                .then(|| rule.float_rect(&self.monitors[mon].work_area, desktop.rect(hwnd))),
+            None if self.rule_floated.contains(&hwnd) => None,
+            None => return,
+        };
+
+        let ws = &mut self.monitors[mon].workspaces[ws_idx];
+        if float.is_some() == ws.is_floating(hwnd) {
+            return;
+        }
+        match float {
+            Some(default) => {
+                let Some(rect) = ws.float(hwnd, default) else {
+                    return;
+                };
+                if ws.monocle_window() == Some(hwnd) {
+                    ws.set_monocle(false);
+                    ws.set_monocle_window(None);
+                }
+                self.rule_floated.insert(hwnd);
+                self.effects.push(Effect::SetRect { hwnd, rect });
+                crate::log_info!("rule-float 0x{:X} on mon {} ws {}", hwnd, mon, ws_idx + 1);
+            }
+            None => {
+                ws.tile(hwnd);
+                self.rule_floated.remove(&hwnd);
+                crate::log_info!("rule-tile 0x{:X} on mon {} ws {}", hwnd, mon, ws_idx + 1);
+            }
+        }
+        self.apply_layout_on(desktop, mon);
+    }
+
+    /// Returns the monitor and workspace a rule sends new windows to.
+    ///
+    /// Targets that do not exist fall back to monitor `idx` and the
+    /// target monitor's active workspace.
+    fn rule_target(&self, rule: &WindowRule, idx: usize) -> (usize, usize) {
+        let mon = rule
+            .monitor
+            .map(|n| usize::from(n).saturating_sub(1))
+            .filter(|&m| m < self.monitors.len())
+            .unwrap_or(idx);
+        let ws = rule
+            .workspace
+            .as_ref()
+            .and_then(|ws| self.resolve_workspace(ws))
+            .filter(|&w| w < self.monitors[mon].workspaces.len())
+            .unwrap_or(self.monitors[mon].active_workspace);
+        (mon, ws)
+    }
+
+    /// Hides a window that a rule placed on a workspace that is not
+    /// visible. Returns whether the window is visible.
+    pub(super) fn hide_if_placed_away(&mut self, hwnd: usize, (mon, ws): (usize, usize)) -> bool {
+        if self.monitors[mon].active_workspace == ws {
+            return true;
+        }
+        self.hide_window(hwnd);
+        false
+    }
+}
+
+/// Collects the properties window rules match against.
+pub(super) fn window_props(desktop: &dyn Desktop, hwnd: usize) -> WindowProps {
+    WindowProps::new(
+        &desktop.class(hwnd),
+        &desktop.title(hwnd),
+        &desktop.exe_path(hwnd),
+    )
+}
@@ -0,0 +1,59 @@
+//! Typed snapshots of the tiling state for `Command::Query`.
+
+use crate::query::{MonitorSnapshot, StateSnapshot, WindowSnapshot, WorkspaceSnapshot};
+
+use super::{Desktop, TilingState};
+
+impl TilingState {
+    /// Captures every monitor, workspace and managed window, querying
+    /// `desktop` for each window's title, class, process and rect.
+    pub fn snapshot(&self, desktop: &dyn Desktop) -> StateSnapshot {
+        let monitors = self
+            .monitors
+            .iter()
+            .enumerate()
+            .map(|(mi, mon)| MonitorSnapshot {
+                index: mi,
+                id: mon.id,
+                name: mon.name.clone(),
+                bounds: mon.bounds,
+                work_area: mon.work_area,
+                active_workspace: mon.active_workspace + 1,
+                workspaces: mon
+                    .workspaces
+                    .iter()
+                    .enumerate()
+                    .map(|(wi, ws)| WorkspaceSnapshot {
+                        monitor: mi,
+                        number: wi + 1,
+                        label: self.workspaces_config.label(wi),
+                        visible: wi == mon.active_workspace,
+                        layout: ws.layout_kind().clone(),
+                        monocle: ws.monocle(),
+                        monocle_window: ws.monocle_window(),
+                        windows: ws
+                            .windows()
+                            .into_iter()
+                            .map(|hwnd| WindowSnapshot {
+                                hwnd,
+                                monitor: mi,
+                                workspace: wi + 1,
+                                title: desktop.title(hwnd),
+                                class: desktop.class(hwnd),
+                                process: crate::window::process_name(&desktop.exe_path(hwnd))
+                                    .to_string(),
+                                rect: desktop.rect(hwnd),
+                                floating: ws.is_floating(hwnd),
+                            })
+                            .collect(),
+                    })
+                    .collect(),
+            })
+            .collect();
+        StateSnapshot {
+            monitors,
+            focused_monitor: self.focused_monitor,
+            focused_window: self.focused_window,
+        }
+    }
+}
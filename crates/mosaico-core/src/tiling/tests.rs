@@ -0,0 +1,309 @@
+use std::collections::HashMap;
+
+use super::*;
+use crate::action::DEFAULT_WORKSPACES;
+
+#[cfg(test)]
+#[path = "tests/display_tests.rs"]
+mod display_tests;
+#[cfg(test)]
+#[path = "tests/floating_tests.rs"]
+mod floating_tests;
+#[cfg(test)]
+#[path = "tests/hiding_tests.rs"]
+mod hiding_tests;
+#[cfg(test)]
+#[path = "tests/minimize_tests.rs"]
+mod minimize_tests;
+#[cfg(test)]
+#[path = "tests/monitor_config_tests.rs"]
+mod monitor_config_tests;
+#[cfg(test)]
+#[path = "tests/monitor_tests.rs"]
+mod monitor_tests;
+#[cfg(test)]
+#[path = "tests/monocle_tests.rs"]
+mod monocle_tests;
+#[cfg(test)]
+#[path = "tests/rule_placement_tests.rs"]
+mod rule_placement_tests;
+#[cfg(test)]
+#[path = "tests/state_tests.rs"]
+mod state_tests;
+#[cfg(test)]
+#[path = "tests/workspace_tests.rs"]
+mod workspace_tests;
+#[cfg(test)]
+#[path = "tests/workspace_transfer_tests.rs"]
+mod workspace_transfer_tests;
+
+pub(super) fn make_monitor(id: usize) -> MonitorState {
+    MonitorState {
+        id,
+        work_area: Rect::new(0, 0, 1920, 1080),
+        name: String::new(),
+        bounds: Rect::new(0, 0, 1920, 1080),
+        workspaces: (0..DEFAULT_WORKSPACES).map(|_| Workspace::new()).collect(),
+        active_workspace: 0,
+        workspace_history: Vec::new(),
+    }
+}
+
+// -- MonitorState helpers --
+
+#[test]
+fn active_ws_returns_correct_workspace() {
+    let mut mon = make_monitor(1);
+    mon.workspaces[0].add(100);
+    mon.workspaces[1].add(200);
+
+    assert!(mon.active_ws().contains(100));
+    assert!(!mon.active_ws().contains(200));
+
+    mon.active_workspace = 1;
+    assert!(!mon.active_ws().contains(100));
+    assert!(mon.active_ws().contains(200));
+}
+
+#[test]
+fn active_ws_mut_modifies_correct_workspace() {
+    let mut mon = make_monitor(1);
+    mon.active_ws_mut().add(100);
+    assert!(mon.workspaces[0].contains(100));
+
+    mon.active_workspace = 1;
+    mon.active_ws_mut().add(200);
+    assert!(mon.workspaces[1].contains(200));
+    assert!(!mon.workspaces[1].contains(100));
+}
+
+#[test]
+fn monitor_state_has_default_workspaces() {
+    let mon = make_monitor(1);
+    assert_eq!(mon.workspaces.len(), DEFAULT_WORKSPACES as usize);
+}
+
+// -- find_window --
+
+pub(super) fn make_monitors(n: usize) -> Vec<MonitorState> {
+    (0..n).map(make_monitor).collect()
+}
+
+// -- hidden_by_switch --
+
+#[test]
+fn hidden_by_switch_tracks_hwnds() {
+    let mut set = HashSet::new();
+
+    // Simulate hiding windows for workspace switch
+    set.insert(100);
+    set.insert(200);
+    assert!(set.contains(&100));
+    assert!(set.contains(&200));
+    assert!(!set.contains(&300));
+
+    // Simulate showing them again
+    set.remove(&100);
+    assert!(!set.contains(&100));
+    assert!(set.contains(&200));
+}
+
+#[test]
+fn hidden_by_switch_ignores_duplicates() {
+    let mut set = HashSet::new();
+    set.insert(100);
+    set.insert(100); // no-op
+    assert_eq!(set.len(), 1);
+}
+
+// -- workspace switch simulation --
+
+pub(super) fn simulate_display_change(
+    old_monitors: &mut [MonitorState],
+    new_infos: Vec<(usize, Rect)>,
+) -> Vec<MonitorState> {
+    let mut new_states: Vec<MonitorState> = Vec::new();
+
+    for (id, work_area) in &new_infos {
+        let old_idx = old_monitors.iter().position(|m| m.id == *id).or_else(|| {
+            old_monitors
+                .iter()
+                .position(|m| m.work_area.x == work_area.x && m.work_area.y == work_area.y)
+        });
+
+        if let Some(idx) = old_idx {
+            let old = &mut old_monitors[idx];
+            new_states.push(MonitorState {
+                id: *id,
+                work_area: *work_area,
+                name: String::new(),
+                bounds: *work_area,
+                workspaces: std::mem::take(&mut old.workspaces),
+                active_workspace: old.active_workspace,
+                workspace_history: std::mem::take(&mut old.workspace_history),
+            });
+        } else {
+            new_states.push(MonitorState {
+                id: *id,
+                work_area: *work_area,
+                name: String::new(),
+                bounds: *work_area,
+                workspaces: (0..DEFAULT_WORKSPACES).map(|_| Workspace::new()).collect(),
+                active_workspace: 0,
+                workspace_history: Vec::new(),
+            });
+        }
+    }
+
+    // Migrate windows from removed monitors.
+    for old_mon in old_monitors.iter() {
+        if old_mon.workspaces.is_empty() {
+            continue;
+        }
+        for (ws_idx, ws) in old_mon.workspaces.iter().enumerate() {
+            for &hwnd in ws.handles() {
+                new_states[0].workspaces[ws_idx].add(hwnd);
+            }
+        }
+    }
+
+    new_states
+}
+
+// Standalone helper matching TilingManager::find_window logic
+pub(super) fn find_window_in(monitors: &[MonitorState], hwnd: usize) -> Option<(usize, usize)> {
+    for (mi, mon) in monitors.iter().enumerate() {
+        for (wi, ws) in mon.workspaces.iter().enumerate() {
+            if ws.contains(hwnd) {
+                return Some((mi, wi));
+            }
+        }
+    }
+    None
+}
+
+/// A window in the [`FakeDesktop`].
+#[derive(Clone)]
+pub(super) struct FakeWindow {
+    pub(super) monitor: usize,
+    pub(super) class: String,
+    pub(super) title: String,
+    pub(super) exe_path: String,
+    pub(super) visible: bool,
+    pub(super) minimized: bool,
+    pub(super) maximized: bool,
+    pub(super) owner: Option<usize>,
+    pub(super) rect: Rect,
+}
+
+/// In-memory [`Desktop`] for driving `TilingState` in tests.
+#[derive(Default)]
+pub(super) struct FakeDesktop {
+    pub(super) windows: HashMap<usize, FakeWindow>,
+}
+
+impl FakeDesktop {
+    /// Adds a visible, manageable window on the given monitor.
+    pub(super) fn add(&mut self, hwnd: usize, monitor: usize) {
+        self.windows.insert(
+            hwnd,
+            FakeWindow {
+                monitor,
+                class: "App".into(),
+                title: format!("Window {hwnd}"),
+                exe_path: r"C:\Program Files\App\app.exe".into(),
+                visible: true,
+                minimized: false,
+                maximized: false,
+                owner: None,
+                rect: Rect::new(0, 0, 800, 600),
+            },
+        );
+    }
+
+    fn get(&self, hwnd: usize) -> Option<&FakeWindow> {
+        self.windows.get(&hwnd)
+    }
+}
+
+impl Desktop for FakeDesktop {
+    fn is_manageable(&self, hwnd: usize) -> bool {
+        self.get(hwnd)
+            .is_some_and(|w| w.visible && !w.minimized && w.owner.is_none())
+    }
+
+    fn class(&self, hwnd: usize) -> String {
+        self.get(hwnd).map(|w| w.class.clone()).unwrap_or_default()
+    }
+
+    fn title(&self, hwnd: usize) -> String {
+        self.get(hwnd).map(|w| w.title.clone()).unwrap_or_default()
+    }
+
+    fn exe_path(&self, hwnd: usize) -> String {
+        self.get(hwnd)
+            .map(|w| w.exe_path.clone())
+            .unwrap_or_default()
+    }
+
+    fn is_visible(&self, hwnd: usize) -> bool {
+        self.get(hwnd).is_some_and(|w| w.visible)
+    }
+
+    fn is_minimized(&self, hwnd: usize) -> bool {
+        self.get(hwnd).is_some_and(|w| w.minimized)
+    }
+
+    fn is_maximized(&self, hwnd: usize) -> bool {
+        self.get(hwnd).is_some_and(|w| w.maximized)
+    }
+
+    fn rect(&self, hwnd: usize) -> Option<Rect> {
+        self.get(hwnd).map(|w| w.rect)
+    }
+
+    fn monitor_id(&self, hwnd: usize) -> usize {
+        self.get(hwnd).map_or(0, |w| w.monitor)
+    }
+
+    fn owner(&self, hwnd: usize) -> Option<usize> {
+        self.get(hwnd).and_then(|w| w.owner)
+    }
+}
+
+/// Creates a tiling state with gap 0 and side-by-side 1920x1080 monitors
+/// whose IDs match their indices.
+pub(super) fn make_state(monitor_count: usize) -> TilingState {
+    let layout = LayoutConfig {
+        gap: 0,
+        ..Default::default()
+    };
+    let monitors = (0..monitor_count)
+        .map(|i| {
+            let rect = Rect::new(i as i32 * 1920, 0, 1920, 1080);
+            MonitorInfo {
+                id: i,
+                work_area: rect,
+                name: format!(r"\\.\DISPLAY{}", i + 1),
+                bounds: rect,
+            }
+        })
+        .collect();
+    TilingState::new(
+        &layout,
+        &WorkspacesConfig::default(),
+        &[],
+        Vec::new(),
+        BorderConfig::default(),
+        false,
+        monitors,
+    )
+}
+
+/// Returns the `SetRect` target for `hwnd` among the given effects.
+pub(super) fn rect_for(effects: &[Effect], hwnd: usize) -> Option<Rect> {
+    effects.iter().rev().find_map(|e| match e {
+        Effect::SetRect { hwnd: h, rect } if *h == hwnd => Some(*rect),
+        _ => None,
+    })
+}
@@ -0,0 +1,247 @@
+use super::super::*;
+use super::{FakeDesktop, make_monitors, make_state, rect_for, simulate_display_change};
+use crate::WindowEvent;
+
+#[test]
+fn display_change_preserves_windows_on_same_monitor() {
+    let mut monitors = make_monitors(2);
+    monitors[0].workspaces[0].add(100);
+    monitors[0].workspaces[0].add(200);
+    monitors[1].workspaces[0].add(300);
+
+    let new_infos = vec![
+        (0, Rect::new(0, 0, 1920, 1080)),
+        (1, Rect::new(1920, 0, 1920, 1080)),
+    ];
+    let result = simulate_display_change(&mut monitors, new_infos);
+
+    assert_eq!(result.len(), 2);
+    assert!(result[0].active_ws().contains(100));
+    assert!(result[0].active_ws().contains(200));
+    assert!(result[1].active_ws().contains(300));
+}
+
+#[test]
+fn display_change_migrates_windows_from_removed_monitor() {
+    let mut monitors = make_monitors(2);
+    monitors[0].workspaces[0].add(100);
+    monitors[1].workspaces[0].add(200);
+    monitors[1].workspaces[1].add(300);
+
+    // Only monitor 0 remains.
+    let new_infos = vec![(0, Rect::new(0, 0, 1920, 1080))];
+    let result = simulate_display_change(&mut monitors, new_infos);
+
+    assert_eq!(result.len(), 1);
+    assert!(result[0].active_ws().contains(100));
+    // Migrated from removed monitor, keeping workspace numbers.
+    assert!(result[0].workspaces[0].contains(200));
+    assert!(result[0].workspaces[1].contains(300));
+}
+
+#[test]
+fn display_change_adds_new_monitor_with_empty_workspaces() {
+    let mut monitors = make_monitors(1);
+    monitors[0].workspaces[0].add(100);
+
+    // A new monitor (id=99) appears.
+    let new_infos = vec![
+        (0, Rect::new(0, 0, 1920, 1080)),
+        (99, Rect::new(1920, 0, 2560, 1440)),
+    ];
+    let result = simulate_display_change(&mut monitors, new_infos);
+
+    assert_eq!(result.len(), 2);
+    assert!(result[0].active_ws().contains(100));
+    assert_eq!(result[1].active_ws().len(), 0);
+    assert_eq!(result[1].id, 99);
+}
+
+#[test]
+fn display_change_matches_by_position_when_id_changes() {
+    let mut monitors = make_monitors(1);
+    monitors[0].workspaces[0].add(100);
+    monitors[0].workspaces[0].add(200);
+
+    // Same position but new ID (reconnected monitor).
+    let new_infos = vec![(42, Rect::new(0, 0, 1920, 1080))];
+    let result = simulate_display_change(&mut monitors, new_infos);
+
+    assert_eq!(result.len(), 1);
+    assert_eq!(result[0].id, 42);
+    assert!(result[0].active_ws().contains(100));
+    assert!(result[0].active_ws().contains(200));
+}
+
+#[test]
+fn display_change_no_monitors_is_noop() {
+    // The real method returns early for empty new_monitors.
+    let result: Vec<MonitorState> = Vec::new();
+    assert!(result.is_empty());
+}
+
+#[test]
+fn display_change_preserves_active_workspace() {
+    let mut monitors = make_monitors(1);
+    monitors[0].active_workspace = 3;
+    monitors[0].workspaces[3].add(100);
+
+    let new_infos = vec![(0, Rect::new(0, 0, 1920, 1080))];
+    let result = simulate_display_change(&mut monitors, new_infos);
+
+    assert_eq!(result[0].active_workspace, 3);
+    assert!(result[0].workspaces[3].contains(100));
+}
+
+#[test]
+fn display_change_preserves_monocle_state() {
+    let mut monitors = make_monitors(1);
+    monitors[0].active_ws_mut().set_monocle(true);
+    monitors[0].active_ws_mut().set_monocle_window(Some(100));
+    monitors[0].workspaces[0].add(100);
+
+    let new_infos = vec![(0, Rect::new(0, 0, 1920, 1080))];
+    let result = simulate_display_change(&mut monitors, new_infos);
+
+    assert!(result[0].active_ws().monocle());
+    assert_eq!(result[0].active_ws().monocle_window(), Some(100));
+}
+
+fn monitor(id: usize, x: i32) -> MonitorInfo {
+    MonitorInfo {
+        id,
+        work_area: Rect::new(x, 0, 1920, 1080),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn unplugged_monitor_windows_keep_workspace_numbers() {
+    // Arrange
+    let mut desktop = FakeDesktop::default();
+    desktop.add(10, 0);
+    desktop.add(20, 1);
+    desktop.add(30, 1);
+    let mut state = make_state(2);
+    state.adopt_existing(&desktop, &[10, 20, 30]);
+    state.handle_event(&desktop, &WindowEvent::Focused { hwnd: 30 });
+    state.handle_action(&desktop, &Action::SendToWorkspace(3.into()));
+
+    // Act
+    let effects = state.handle_display_change(&desktop, vec![monitor(0, 0)], 0, &[]);
+
+    // Assert
+    let mon = &state.monitors()[0];
+    assert_eq!(mon.workspaces[0].handles(), &[10, 20]);
+    assert_eq!(mon.workspaces[2].handles(), &[30]);
+    assert!(
+        effects
+            .iter()
+            .any(|e| matches!(e, Effect::Hide { hwnd: 30, .. }))
+    );
+}
+
+#[test]
+fn replugged_monitor_gets_its_windows_back() {
+    // Arrange
+    let mut desktop = FakeDesktop::default();
+    desktop.add(10, 0);
+    desktop.add(20, 1);
+    desktop.add(30, 1);
+    let mut state = make_state(2);
+    state.adopt_existing(&desktop, &[10, 20, 30]);
+    state.handle_event(&desktop, &WindowEvent::Focused { hwnd: 30 });
+    state.handle_action(&desktop, &Action::SendToWorkspace(3.into()));
+    state.handle_display_change(&desktop, vec![monitor(0, 0)], 0, &[]);
+
+    // Act
+    let effects =
+        state.handle_display_change(&desktop, vec![monitor(0, 0), monitor(1, 1920)], 0, &[]);
+
+    // Assert
+    let mons = state.monitors();
+    assert_eq!(mons[0].workspaces[0].handles(), &[10]);
+    assert!(mons[0].workspaces[2].is_empty());
+    assert_eq!(mons[1].workspaces[0].handles(), &[20]);
+    assert_eq!(mons[1].workspaces[2].handles(), &[30]);
+    assert_eq!(mons[1].active_workspace, 2);
+    assert!(
+        effects
+            .iter()
+            .any(|e| matches!(e, Effect::Show { hwnd: 30, .. }))
+    );
+    assert!(
+        effects
+            .iter()
+            .any(|e| matches!(e, Effect::Hide { hwnd: 20, .. }))
+    );
+}
+
+#[test]
+fn replugged_monitor_is_recognised_by_area_when_id_changes() {
+    // Arrange
+    let mut desktop = FakeDesktop::default();
+    desktop.add(10, 0);
+    desktop.add(20, 1);
+    let mut state = make_state(2);
+    state.adopt_existing(&desktop, &[10, 20]);
+    state.handle_display_change(&desktop, vec![monitor(0, 0)], 0, &[]);
+
+    // Act
+    state.handle_display_change(&desktop, vec![monitor(0, 0), monitor(7, 1920)], 0, &[]);
+
+    // Assert
+    let mons = state.monitors();
+    assert_eq!(mons[0].workspaces[0].handles(), &[10]);
+    assert_eq!(mons[1].id, 7);
+    assert_eq!(mons[1].workspaces[0].handles(), &[20]);
+}
+
+#[test]
+fn floating_window_moves_with_unplugged_monitor_and_back() {
+    // Arrange
+    let mut desktop = FakeDesktop::default();
+    desktop.add(10, 0);
+    desktop.add(20, 1);
+    let mut state = make_state(2);
+    state.adopt_existing(&desktop, &[10, 20]);
+    state.handle_event(&desktop, &WindowEvent::Focused { hwnd: 20 });
+    state.handle_action(&desktop, &Action::ToggleFloat);
+    let home = state.monitors()[1].active_ws().floating_rect(20).unwrap();
+
+    // Act
+    let unplugged = state.handle_display_change(&desktop, vec![monitor(0, 0)], 0, &[]);
+    let away = state.monitors()[0].active_ws().floating_rect(20);
+    let replugged =
+        state.handle_display_change(&desktop, vec![monitor(0, 0), monitor(1, 1920)], 0, &[]);
+
+    // Assert
+    let away_rect = Rect::new(home.x - 1920, home.y, home.width, home.height);
+    assert_eq!(away, Some(away_rect));
+    assert_eq!(rect_for(&unplugged, 20), Some(away_rect));
+    assert!(state.monitors()[1].active_ws().is_floating(20));
+    assert_eq!(
+        state.monitors()[1].active_ws().floating_rect(20),
+        Some(home)
+    );
+    assert_eq!(rect_for(&replugged, 20), Some(home));
+}
+
+#[test]
+fn window_closed_while_away_is_not_restored() {
+    // Arrange
+    let mut desktop = FakeDesktop::default();
+    desktop.add(10, 0);
+    desktop.add(20, 1);
+    let mut state = make_state(2);
+    state.adopt_existing(&desktop, &[10, 20]);
+    state.handle_display_change(&desktop, vec![monitor(0, 0)], 0, &[]);
+    state.handle_event(&desktop, &WindowEvent::Destroyed { hwnd: 20 });
+
+    // Act
+    state.handle_display_change(&desktop, vec![monitor(0, 0), monitor(1, 1920)], 0, &[]);
+
+    // Assert
+    assert_eq!(state.window_count(), 1);
+    assert!(state.monitors()[1].workspaces[0].is_empty());
+}
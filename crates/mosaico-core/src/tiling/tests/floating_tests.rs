@@ -0,0 +1,153 @@
+use super::super::*;
+use super::{FakeDesktop, make_state, rect_for};
+use crate::WindowEvent;
+use crate::config::RuleMatch;
+
+#[test]
+fn toggle_float_takes_window_out_of_layout() {
+    // Arrange
+    let mut desktop = FakeDesktop::default();
+    desktop.add(10, 0);
+    desktop.add(20, 0);
+    let mut state = make_state(1);
+    state.adopt_existing(&desktop, &[10, 20]);
+    state.handle_event(&desktop, &WindowEvent::Focused { hwnd: 20 });
+
+    // Act
+    let effects = state.handle_action(&desktop, &Action::ToggleFloat);
+
+    // Assert
+    assert_eq!(rect_for(&effects, 20), Some(Rect::new(480, 270, 960, 540)));
+    assert_eq!(rect_for(&effects, 10), Some(Rect::new(0, 0, 1920, 1080)));
+    let ws = state.monitors()[0].active_ws();
+    assert_eq!(ws.handles(), &[10]);
+    assert_eq!(ws.floating(), &[20]);
+    assert_eq!(state.window_count(), 2);
+}
+
+#[test]
+fn toggle_float_twice_restores_tile_and_remembers_rect() {
+    // Arrange
+    let mut desktop = FakeDesktop::default();
+    desktop.add(10, 0);
+    desktop.add(20, 0);
+    let mut state = make_state(1);
+    state.adopt_existing(&desktop, &[10, 20]);
+    state.handle_event(&desktop, &WindowEvent::Focused { hwnd: 20 });
+    state.handle_action(&desktop, &Action::ToggleFloat);
+    let moved = Rect::new(100, 100, 500, 400);
+    desktop.windows.get_mut(&20).unwrap().rect = moved;
+    state.handle_event(&desktop, &WindowEvent::Moved { hwnd: 20 });
+
+    // Act
+    let tiled = state.handle_action(&desktop, &Action::ToggleFloat);
+    let floated = state.handle_action(&desktop, &Action::ToggleFloat);
+
+    // Assert
+    assert_eq!(rect_for(&tiled, 20), Some(Rect::new(960, 0, 960, 1080)));
+    assert_eq!(rect_for(&floated, 20), Some(moved));
+}
+
+#[test]
+fn moving_floating_window_does_not_retile() {
+    // Arrange
+    let mut desktop = FakeDesktop::default();
+    desktop.add(10, 0);
+    desktop.add(20, 0);
+    let mut state = make_state(1);
+    state.adopt_existing(&desktop, &[10, 20]);
+    state.handle_event(&desktop, &WindowEvent::Focused { hwnd: 20 });
+    state.handle_action(&desktop, &Action::ToggleFloat);
+
+    // Act
+    let effects = state.handle_event(&desktop, &WindowEvent::Moved { hwnd: 20 });
+
+    // Assert
+    assert_eq!(rect_for(&effects, 10), None);
+    assert_eq!(rect_for(&effects, 20), None);
+}
+
+#[test]
+fn float_rule_places_new_window_at_rule_rect() {
+    // Arrange
+    let mut desktop = FakeDesktop::default();
+    desktop.add(10, 0);
+    desktop.add(20, 0);
+    desktop.windows.get_mut(&20).unwrap().class = "Calculator".into();
+    let mut state = make_state(1);
+    let rules = vec![WindowRule {
+        matcher: RuleMatch {
+            match_class: Some("Calculator".into()),
+            ..Default::default()
+        },
+        manage: true,
+        float: true,
+        size: Some([400, 600]),
+        ..Default::default()
+    }];
+    state.reload_rules(&desktop, rules);
+    state.adopt_existing(&desktop, &[10]);
+
+    // Act
+    let effects = state.handle_event(&desktop, &WindowEvent::Created { hwnd: 20 });
+
+    // Assert
+    assert_eq!(rect_for(&effects, 20), Some(Rect::new(760, 240, 400, 600)));
+    assert_eq!(rect_for(&effects, 10), Some(Rect::new(0, 0, 1920, 1080)));
+    assert!(state.monitors()[0].active_ws().is_floating(20));
+    assert_eq!(state.focused_window(), Some(20));
+}
+
+#[test]
+fn floating_window_follows_workspace_switching() {
+    // Arrange
+    let mut desktop = FakeDesktop::default();
+    desktop.add(10, 0);
+    desktop.add(20, 0);
+    let mut state = make_state(1);
+    state.adopt_existing(&desktop, &[10, 20]);
+    state.handle_event(&desktop, &WindowEvent::Focused { hwnd: 20 });
+    state.handle_action(&desktop, &Action::ToggleFloat);
+
+    // Act
+    let away = state.handle_action(&desktop, &Action::GoToWorkspace(2.into()));
+    let back = state.handle_action(&desktop, &Action::GoToWorkspace(1.into()));
+
+    // Assert
+    assert!(
+        away.iter()
+            .any(|e| matches!(e, Effect::Hide { hwnd: 20, .. }))
+    );
+    assert!(
+        back.iter()
+            .any(|e| matches!(e, Effect::Show { hwnd: 20, .. }))
+    );
+    assert!(state.monitors()[0].active_ws().is_floating(20));
+}
+
+#[test]
+fn send_to_workspace_keeps_window_floating() {
+    // Arrange
+    let mut desktop = FakeDesktop::default();
+    desktop.add(10, 0);
+    desktop.add(20, 0);
+    let mut state = make_state(1);
+    state.adopt_existing(&desktop, &[10, 20]);
+    state.handle_event(&desktop, &WindowEvent::Focused { hwnd: 20 });
+    state.handle_action(&desktop, &Action::ToggleFloat);
+
+    // Act
+    let effects = state.handle_action(&desktop, &Action::SendToWorkspace(3.into()));
+
+    // Assert
+    let mon = &state.monitors()[0];
+    assert_eq!(mon.active_workspace, 2);
+    assert_eq!(mon.workspaces[2].floating(), &[20]);
+    assert!(!mon.workspaces[0].contains(20));
+    assert!(
+        effects
+            .iter()
+            .any(|e| matches!(e, Effect::Hide { hwnd: 10, .. }))
+    );
+    assert_eq!(rect_for(&effects, 20), None);
+}
@@ -0,0 +1,122 @@
+use super::super::*;
+use super::{FakeDesktop, make_state, rect_for};
+use crate::Config;
+
+fn config_with(toml_str: &str) -> Config {
+    let mut config: Config = toml::from_str(toml_str).unwrap();
+    config.validate();
+    config
+}
+
+#[test]
+fn monitor_block_overrides_gap_layout_and_count() {
+    // Arrange
+    let mut desktop = FakeDesktop::default();
+    desktop.add(10, 1);
+    desktop.add(20, 1);
+    let mut state = make_state(2);
+    state.adopt_existing(&desktop, &[10, 20]);
+    let config = config_with(
+        r#"
+        [layout]
+        gap = 0
+
+        [[monitor]]
+        index = 2
+        gap = 10
+        layout = "vertical-stack"
+        workspaces = 3
+        "#,
+    );
+
+    // Act
+    let effects = state.reload_config(&desktop, &config);
+
+    // Assert
+    let mon = &state.monitors()[1];
+    assert_eq!(mon.workspaces.len(), 3);
+    assert_eq!(mon.active_ws().layout_kind(), &LayoutKind::VerticalStack);
+    assert_eq!(state.monitors()[0].workspaces.len(), 8);
+    assert_eq!(
+        state.monitors()[0].active_ws().layout_kind(),
+        &LayoutKind::Bsp
+    );
+    assert_eq!(rect_for(&effects, 10), Some(Rect::new(1930, 10, 945, 1060)));
+}
+
+#[test]
+fn monitor_block_matches_by_resolution_at_startup() {
+    // Arrange
+    let config = config_with(
+        r#"
+        [[monitor]]
+        resolution = "1080x1920"
+        layout = "horizontal-stack"
+        bar = false
+        "#,
+    );
+    let monitors = vec![
+        MonitorInfo {
+            id: 0,
+            work_area: Rect::new(0, 0, 1920, 1040),
+            name: r"\\.\DISPLAY1".into(),
+            bounds: Rect::new(0, 0, 1920, 1080),
+        },
+        MonitorInfo {
+            id: 1,
+            work_area: Rect::new(1920, 0, 1080, 1880),
+            name: r"\\.\DISPLAY2".into(),
+            bounds: Rect::new(1920, 0, 1080, 1920),
+        },
+    ];
+
+    // Act
+    let state = TilingState::new(
+        &config.layout,
+        &config.workspaces,
+        &config.monitor,
+        Vec::new(),
+        BorderConfig::default(),
+        false,
+        monitors,
+    );
+
+    // Assert
+    assert_eq!(
+        state.monitors()[1].active_ws().layout_kind(),
+        &LayoutKind::HorizontalStack
+    );
+    assert_eq!(
+        state.monitors()[0].active_ws().layout_kind(),
+        &LayoutKind::Bsp
+    );
+    assert_eq!(state.monitor_bars(), vec![None, Some(false)]);
+}
+
+#[test]
+fn monitor_block_count_wins_over_workspaces_monitors() {
+    // Arrange
+    let desktop = FakeDesktop::default();
+    let mut state = make_state(2);
+    let config = config_with(
+        r#"
+        [workspaces]
+        count = 6
+
+        [workspaces.monitors]
+        1 = 4
+        2 = 4
+
+        [[monitor]]
+        index = 2
+        workspaces = 2
+        "#,
+    );
+
+    // Act
+    state.reload_config(&desktop, &config);
+
+    // Assert
+    assert_eq!(state.monitors()[0].workspaces.len(), 4);
+    assert_eq!(state.monitors()[1].workspaces.len(), 2);
+}
@@ -1,5 +1,5 @@
-use super::super::*;
 use super::{find_window_in, make_monitor, make_monitors};
+use crate::action::DEFAULT_WORKSPACES;
 
 #[test]
 fn active_ws_returns_correct_workspace() {
@@ -28,9 +28,9 @@ fn active_ws_mut_modifies_correct_workspace() {
 }
 
 #[test]
-fn monitor_state_has_max_workspaces() {
+fn monitor_state_has_default_workspaces() {
     let mon = make_monitor(1);
-    assert_eq!(mon.workspaces.len(), MAX_WORKSPACES as usize);
+    assert_eq!(mon.workspaces.len(), DEFAULT_WORKSPACES as usize);
 }
 
 #[test]
@@ -0,0 +1,248 @@
+use super::super::*;
+use super::{FakeDesktop, make_state, rect_for};
+use crate::config::RuleMatch;
+use crate::{WindowEvent, WorkspaceRef};
+
+fn process_rule(process: &str) -> WindowRule {
+    WindowRule {
+        matcher: RuleMatch {
+            match_process: Some(process.into()),
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+#[test]
+fn created_window_opens_on_rule_workspace_hidden() {
+    // Arrange
+    let mut desktop = FakeDesktop::default();
+    desktop.add(10, 0);
+    desktop.add(20, 0);
+    desktop.windows.get_mut(&20).unwrap().exe_path = r"C:\Apps\Slack\slack.exe".into();
+    let mut state = make_state(1);
+    let rules = vec![WindowRule {
+        workspace: Some(WorkspaceRef::Number(3)),
+        ..process_rule("Slack.exe")
+    }];
+    state.reload_rules(&desktop, rules);
+    state.adopt_existing(&desktop, &[10]);
+
+    // Act
+    let effects = state.handle_event(&desktop, &WindowEvent::Created { hwnd: 20 });
+
+    // Assert
+    let mon = &state.monitors()[0];
+    assert_eq!(mon.active_ws().handles(), &[10]);
+    assert_eq!(mon.workspaces[2].handles(), &[20]);
+    assert_eq!(state.focused_window(), Some(10));
+    assert!(
+        effects
+            .iter()
+            .any(|e| matches!(e, Effect::Hide { hwnd: 20, .. }))
+    );
+}
+
+#[test]
+fn created_window_opens_on_rule_monitor_and_takes_focus() {
+    // Arrange
+    let mut desktop = FakeDesktop::default();
+    desktop.add(10, 0);
+    desktop.add(20, 0);
+    desktop.windows.get_mut(&20).unwrap().exe_path = r"C:\Apps\obs64.exe".into();
+    let mut state = make_state(2);
+    let rules = vec![WindowRule {
+        monitor: Some(2),
+        ..process_rule("obs64.exe")
+    }];
+    state.reload_rules(&desktop, rules);
+    state.adopt_existing(&desktop, &[10]);
+
+    // Act
+    state.handle_event(&desktop, &WindowEvent::Created { hwnd: 20 });
+
+    // Assert
+    assert_eq!(state.monitors()[0].active_ws().handles(), &[10]);
+    assert_eq!(state.monitors()[1].active_ws().handles(), &[20]);
+    assert_eq!(state.focused_window(), Some(20));
+}
+
+#[test]
+fn slot_and_monocle_rules_apply_to_new_windows() {
+    // Arrange
+    let mut desktop = FakeDesktop::default();
+    for hwnd in [10, 20, 30] {
+        desktop.add(hwnd, 0);
+    }
+    desktop.windows.get_mut(&30).unwrap().exe_path = r"C:\Apps\code.exe".into();
+    let mut state = make_state(1);
+    let rules = vec![WindowRule {
+        slot: Some(0),
+        monocle: true,
+        ..process_rule("code.exe")
+    }];
+    state.reload_rules(&desktop, rules);
+    state.adopt_existing(&desktop, &[10, 20]);
+
+    // Act
+    state.handle_event(&desktop, &WindowEvent::Created { hwnd: 30 });
+
+    // Assert
+    let ws = state.monitors()[0].active_ws();
+    assert_eq!(ws.handles(), &[30, 10, 20]);
+    assert!(ws.monocle());
+    assert_eq!(ws.monocle_window(), Some(30));
+}
+
+#[test]
+fn restored_window_ignores_rule_workspace() {
+    // Arrange
+    let mut desktop = FakeDesktop::default();
+    desktop.add(10, 0);
+    desktop.windows.get_mut(&10).unwrap().exe_path = r"C:\Apps\slack.exe".into();
+    let mut state = make_state(1);
+    let rules = vec![WindowRule {
+        workspace: Some(WorkspaceRef::Number(3)),
+        ..process_rule("slack.exe")
+    }];
+    state.reload_rules(&desktop, rules);
+
+    // Act
+    state.handle_event(&desktop, &WindowEvent::Restored { hwnd: 10 });
+
+    // Assert
+    assert_eq!(state.monitors()[0].active_ws().handles(), &[10]);
+    assert_eq!(state.focused_window(), Some(10));
+}
+
+fn title_rule(title: &str, reevaluate: bool) -> WindowRule {
+    WindowRule {
+        matcher: RuleMatch {
+            match_title: Some(title.into()),
+            ..Default::default()
+        },
+        reevaluate_on_title_change: reevaluate,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn title_change_releases_window_when_rule_opts_in() {
+    // Arrange
+    let mut desktop = FakeDesktop::default();
+    desktop.add(10, 0);
+    desktop.add(20, 0);
+    let mut state = make_state(1);
+    let rules = vec![WindowRule {
+        manage: false,
+        ..title_rule("Settings", true)
+    }];
+    state.reload_rules(&desktop, rules);
+    state.adopt_existing(&desktop, &[10, 20]);
+    desktop.windows.get_mut(&20).unwrap().title = "Settings".into();
+
+    // Act
+    let effects = state.handle_event(&desktop, &WindowEvent::TitleChanged { hwnd: 20 });
+
+    // Assert
+    assert_eq!(state.monitors()[0].active_ws().handles(), &[10]);
+    assert!(
+        effects
+            .iter()
+            .any(|e| matches!(e, Effect::ResetCorners { hwnd: 20 }))
+    );
+}
+
+#[test]
+fn title_change_floats_window_when_rule_opts_in() {
+    // Arrange
+    let mut desktop = FakeDesktop::default();
+    desktop.add(10, 0);
+    desktop.add(20, 0);
+    let mut state = make_state(1);
+    let rules = vec![WindowRule {
+        float: true,
+        size: Some([400, 300]),
+        ..title_rule("Picture-in-Picture", true)
+    }];
+    state.reload_rules(&desktop, rules);
+    state.adopt_existing(&desktop, &[10, 20]);
+    desktop.windows.get_mut(&20).unwrap().title = "Picture-in-Picture".into();
+
+    // Act
+    let effects = state.handle_event(&desktop, &WindowEvent::TitleChanged { hwnd: 20 });
+
+    // Assert
+    let ws = state.monitors()[0].active_ws();
+    assert_eq!(ws.handles(), &[10]);
+    assert_eq!(ws.floating(), &[20]);
+    assert_eq!(rect_for(&effects, 20), Some(Rect::new(760, 390, 400, 300)));
+    assert_eq!(rect_for(&effects, 10), Some(Rect::new(0, 0, 1920, 1080)));
+}
+
+#[test]
+fn title_change_ignores_rules_without_opt_in() {
+    // Arrange
+    let mut desktop = FakeDesktop::default();
+    desktop.add(10, 0);
+    let mut state = make_state(1);
+    let rules = vec![WindowRule {
+        manage: false,
+        ..title_rule("Settings", false)
+    }];
+    state.reload_rules(&desktop, rules);
+    state.adopt_existing(&desktop, &[10]);
+    desktop.windows.get_mut(&10).unwrap().title = "Settings".into();
+
+    // Act
+    state.handle_event(&desktop, &WindowEvent::TitleChanged { hwnd: 10 });
+
+    // Assert
+    assert_eq!(state.monitors()[0].active_ws().handles(), &[10]);
+}
+
+#[test]
+fn window_floated_by_title_rule_tiles_again_when_title_stops_matching() {
+    // Arrange
+    let mut desktop = FakeDesktop::default();
+    desktop.add(10, 0);
+    desktop.add(20, 0);
+    let mut state = make_state(1);
+    let rules = vec![WindowRule {
+        float: true,
+        size: Some([400, 300]),
+        ..title_rule("Picture-in-Picture", true)
+    }];
+    state.reload_rules(&desktop, rules);
+    state.adopt_existing(&desktop, &[10, 20]);
+    desktop.windows.get_mut(&20).unwrap().title = "Picture-in-Picture".into();
+    state.handle_event(&desktop, &WindowEvent::TitleChanged { hwnd: 20 });
+    desktop.windows.get_mut(&20).unwrap().title = "Video".into();
+
+    // Act
+    let effects = state.handle_event(&desktop, &WindowEvent::TitleChanged { hwnd: 20 });
+
+    // Assert
+    let ws = state.monitors()[0].active_ws();
+    assert_eq!(ws.handles(), &[10, 20]);
+    assert!(ws.floating().is_empty());
+    assert_eq!(rect_for(&effects, 10), Some(Rect::new(0, 0, 960, 1080)));
+}
+
+#[test]
+fn title_change_leaves_windows_the_user_floated() {
+    // Arrange
+    let mut desktop = FakeDesktop::default();
+    desktop.add(10, 0);
+    let mut state = make_state(1);
+    state.reload_rules(&desktop, vec![title_rule("Settings", true)]);
+    state.adopt_existing(&desktop, &[10]);
+    state.handle_action(&desktop, &Action::ToggleFloat);
+    desktop.windows.get_mut(&10).unwrap().title = "Other".into();
+
+    // Act
+    state.handle_event(&desktop, &WindowEvent::TitleChanged { hwnd: 10 });
+
+    // Assert
+    assert_eq!(state.monitors()[0].active_ws().floating(), &[10]);
+}
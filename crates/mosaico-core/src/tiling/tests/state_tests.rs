@@ -0,0 +1,663 @@
+use super::super::*;
+use super::{FakeDesktop, make_state, rect_for};
+use crate::action::Direction;
+use crate::{Config, WindowEvent};
+
+#[test]
+fn adopt_existing_tiles_windows_per_monitor() {
+    // Arrange
+    let mut desktop = FakeDesktop::default();
+    desktop.add(10, 0);
+    desktop.add(20, 0);
+    desktop.add(30, 1);
+    let mut state = make_state(2);
+
+    // Act
+    let effects = state.adopt_existing(&desktop, &[10, 20, 30]);
+
+    // Assert
+    assert_eq!(state.window_count(), 3);
+    assert_eq!(rect_for(&effects, 10), Some(Rect::new(0, 0, 960, 1080)));
+    assert_eq!(rect_for(&effects, 20), Some(Rect::new(960, 0, 960, 1080)));
+    assert_eq!(rect_for(&effects, 30), Some(Rect::new(1920, 0, 1920, 1080)));
+    assert_eq!(state.focused_window(), Some(10));
+    assert!(effects.contains(&Effect::Focus { hwnd: 10 }));
+}
+
+#[test]
+fn adopt_existing_skips_unmanageable_windows() {
+    let mut desktop = FakeDesktop::default();
+    desktop.add(10, 0);
+    desktop.add(20, 0);
+    desktop.windows.get_mut(&20).unwrap().visible = false;
+    let mut state = make_state(1);
+
+    state.adopt_existing(&desktop, &[10, 20]);
+
+    assert_eq!(state.window_count(), 1);
+    assert_eq!(state.find_window(20), None);
+}
+
+#[test]
+fn created_window_is_added_to_focused_monitor_and_focused() {
+    // Arrange
+    let mut desktop = FakeDesktop::default();
+    desktop.add(10, 0);
+    let mut state = make_state(2);
+    state.adopt_existing(&desktop, &[10]);
+    // The OS spawned the window on monitor 1, but the user works on 0.
+    desktop.add(20, 1);
+
+    // Act
+    let effects = state.handle_event(&desktop, &WindowEvent::Created { hwnd: 20 });
+
+    // Assert
+    assert_eq!(state.find_window(20), Some((0, 0)));
+    assert_eq!(state.focused_window(), Some(20));
+    assert_eq!(rect_for(&effects, 20), Some(Rect::new(960, 0, 960, 1080)));
+    assert!(effects.contains(&Effect::Focus { hwnd: 20 }));
+}
+
+#[test]
+fn destroyed_focused_window_promotes_sibling() {
+    let mut desktop = FakeDesktop::default();
+    desktop.add(10, 0);
+    desktop.add(20, 0);
+    let mut state = make_state(1);
+    state.adopt_existing(&desktop, &[10, 20]);
+    assert_eq!(state.focused_window(), Some(10));
+
+    desktop.windows.remove(&10);
+    let effects = state.handle_event(&desktop, &WindowEvent::Destroyed { hwnd: 10 });
+
+    assert_eq!(state.find_window(10), None);
+    assert_eq!(state.focused_window(), Some(20));
+    assert_eq!(rect_for(&effects, 20), Some(Rect::new(0, 0, 1920, 1080)));
+    assert!(effects.contains(&Effect::ResetCorners { hwnd: 10 }));
+}
+
+#[test]
+fn hidden_event_ignored_while_window_still_visible() {
+    let mut desktop = FakeDesktop::default();
+    desktop.add(10, 0);
+    let mut state = make_state(1);
+    state.adopt_existing(&desktop, &[10]);
+
+    let effects = state.handle_event(&desktop, &WindowEvent::Hidden { hwnd: 10 });
+
+    assert!(effects.is_empty());
+    assert_eq!(state.find_window(10), Some((0, 0)));
+}
+
+#[test]
+fn goto_workspace_focuses_target_before_hiding_previous() {
+    // Arrange
+    let mut desktop = FakeDesktop::default();
+    desktop.add(10, 0);
+    let mut state = make_state(1);
+    state.adopt_existing(&desktop, &[10]);
+    state.handle_action(&desktop, &Action::SendToWorkspace(2.into()));
+    desktop.add(20, 0);
+    state.handle_action(&desktop, &Action::GoToWorkspace(1.into()));
+    state.handle_event(&desktop, &WindowEvent::Created { hwnd: 20 });
+
+    // Act
+    let effects = state.handle_action(&desktop, &Action::GoToWorkspace(2.into()));
+
+    // Assert
+    let focus = effects
+        .iter()
+        .position(|e| *e == Effect::Focus { hwnd: 10 })
+        .expect("window on target workspace is focused");
+    let hide = effects
+        .iter()
+        .position(|e| matches!(e, Effect::Hide { hwnd: 20, .. }))
+        .expect("window on previous workspace is hidden");
+    assert!(focus < hide);
+    assert!(effects.contains(&Effect::Show {
+        hwnd: 10,
+        hiding: HidingBehaviour::Cloak,
+    }));
+}
+
+#[test]
+fn goto_workspace_restores_last_focused_window() {
+    let mut desktop = FakeDesktop::default();
+    desktop.add(10, 0);
+    desktop.add(20, 0);
+    let mut state = make_state(1);
+    state.adopt_existing(&desktop, &[10, 20]);
+    state.handle_event(&desktop, &WindowEvent::Focused { hwnd: 20 });
+
+    state.handle_action(&desktop, &Action::GoToWorkspace(3.into()));
+    assert_eq!(state.focused_window(), None);
+    state.handle_action(&desktop, &Action::GoToWorkspace(1.into()));
+
+    assert_eq!(state.focused_window(), Some(20));
+}
+
+#[test]
+fn send_to_workspace_moves_window_and_follows_it() {
+    let mut desktop = FakeDesktop::default();
+    desktop.add(10, 0);
+    desktop.add(20, 0);
+    let mut state = make_state(1);
+    state.adopt_existing(&desktop, &[10, 20]);
+
+    let effects = state.handle_action(&desktop, &Action::SendToWorkspace(4.into()));
+
+    assert_eq!(state.find_window(10), Some((0, 3)));
+    assert_eq!(state.monitors()[0].active_workspace, 3);
+    assert_eq!(state.focused_window(), Some(10));
+    assert!(effects.contains(&Effect::Hide {
+        hwnd: 20,
+        hiding: HidingBehaviour::Cloak,
+    }));
+}
+
+#[test]
+fn hide_mode_ignores_events_for_switched_windows() {
+    // Arrange
+    let mut desktop = FakeDesktop::default();
+    desktop.add(10, 0);
+    let layout = LayoutConfig {
+        hiding: HidingBehaviour::Hide,
+        ..Default::default()
+    };
+    let monitors = vec![MonitorInfo {
+        id: 0,
+        work_area: Rect::new(0, 0, 1920, 1080),
+        ..Default::default()
+    }];
+    let mut state = TilingState::new(
+        &layout,
+        &WorkspacesConfig::default(),
+        &[],
+        Vec::new(),
+        BorderConfig::default(),
+        false,
+        monitors,
+    );
+    state.adopt_existing(&desktop, &[10]);
+    state.handle_action(&desktop, &Action::GoToWorkspace(2.into()));
+    desktop.windows.get_mut(&10).unwrap().visible = false;
+
+    // Act
+    let effects = state.handle_event(&desktop, &WindowEvent::Hidden { hwnd: 10 });
+
+    // Assert
+    assert!(effects.is_empty());
+    assert_eq!(state.find_window(10), Some((0, 0)));
+}
+
+#[test]
+fn focus_right_overflows_to_adjacent_monitor() {
+    let mut desktop = FakeDesktop::default();
+    desktop.add(10, 0);
+    desktop.add(20, 0);
+    desktop.add(30, 1);
+    let mut state = make_state(2);
+    state.adopt_existing(&desktop, &[10, 20, 30]);
+
+    state.handle_action(&desktop, &Action::Focus(Direction::Right));
+    assert_eq!(state.focused_window(), Some(20));
+    state.handle_action(&desktop, &Action::Focus(Direction::Right));
+
+    assert_eq!(state.focused_window(), Some(30));
+    assert_eq!(state.focused_monitor(), 1);
+}
+
+#[test]
+fn focus_and_move_overflow_to_stacked_monitor() {
+    // Arrange: monitor 1 sits below monitor 0.
+    let mut desktop = FakeDesktop::default();
+    desktop.add(10, 0);
+    desktop.add(20, 0);
+    desktop.add(30, 1);
+    let layout = LayoutConfig {
+        gap: 0,
+        ..Default::default()
+    };
+    let monitors = vec![
+        MonitorInfo {
+            id: 0,
+            work_area: Rect::new(0, 0, 1920, 1080),
+            ..Default::default()
+        },
+        MonitorInfo {
+            id: 1,
+            work_area: Rect::new(0, 1080, 1920, 1080),
+            ..Default::default()
+        },
+    ];
+    let mut state = TilingState::new(
+        &layout,
+        &WorkspacesConfig::default(),
+        &[],
+        Vec::new(),
+        BorderConfig::default(),
+        false,
+        monitors,
+    );
+    state.adopt_existing(&desktop, &[10, 20, 30]);
+    state.handle_event(&desktop, &WindowEvent::Focused { hwnd: 20 });
+
+    // Act
+    state.handle_action(&desktop, &Action::Focus(Direction::Down));
+    let focused = state.focused_window();
+    let effects = state.handle_action(&desktop, &Action::Move(Direction::Up));
+
+    // Assert
+    assert_eq!(focused, Some(30));
+    assert_eq!(state.find_window(30), Some((0, 0)));
+    assert_eq!(state.focused_monitor(), 0);
+    // Entering from below lands on the bottom edge.
+    assert_eq!(rect_for(&effects, 30), Some(Rect::new(960, 540, 960, 540)));
+}
+
+#[test]
+fn move_right_at_edge_moves_window_to_next_monitor() {
+    let mut desktop = FakeDesktop::default();
+    desktop.add(10, 0);
+    desktop.add(20, 1);
+    let mut state = make_state(2);
+    state.adopt_existing(&desktop, &[10, 20]);
+
+    let effects = state.handle_action(&desktop, &Action::Move(Direction::Right));
+
+    assert_eq!(state.find_window(10), Some((1, 0)));
+    assert_eq!(state.monitors()[1].active_ws().handles(), &[10, 20]);
+    assert_eq!(rect_for(&effects, 10), Some(Rect::new(1920, 0, 960, 1080)));
+    assert_eq!(state.focused_monitor(), 1);
+}
+
+#[test]
+fn monocle_fills_work_area_with_focused_window() {
+    let mut desktop = FakeDesktop::default();
+    desktop.add(10, 0);
+    desktop.add(20, 0);
+    let mut state = make_state(1);
+    state.adopt_existing(&desktop, &[10, 20]);
+
+    let effects = state.handle_action(&desktop, &Action::ToggleMonocle);
+
+    assert_eq!(rect_for(&effects, 10), Some(Rect::new(0, 0, 1920, 1080)));
+    assert_eq!(rect_for(&effects, 20), None);
+    assert!(effects.contains(&Effect::ShowBorder {
+        hwnd: 10,
+        monocle: true,
+    }));
+}
+
+#[test]
+fn minimized_window_leaves_layout() {
+    let mut desktop = FakeDesktop::default();
+    desktop.add(10, 0);
+    desktop.add(20, 0);
+    let mut state = make_state(1);
+    state.adopt_existing(&desktop, &[10, 20]);
+
+    desktop.windows.get_mut(&20).unwrap().minimized = true;
+    let effects = state.handle_event(&desktop, &WindowEvent::Minimized { hwnd: 20 });
+
+    assert_eq!(state.find_window(20), None);
+    assert_eq!(rect_for(&effects, 10), Some(Rect::new(0, 0, 1920, 1080)));
+}
+
+#[test]
+fn focused_owned_dialog_moves_border_to_owner() {
+    let mut desktop = FakeDesktop::default();
+    desktop.add(10, 0);
+    desktop.add(20, 0);
+    let mut state = make_state(1);
+    state.adopt_existing(&desktop, &[10, 20]);
+    desktop.add(99, 0);
+    desktop.windows.get_mut(&99).unwrap().owner = Some(20);
+
+    let effects = state.handle_event(&desktop, &WindowEvent::Focused { hwnd: 99 });
+
+    assert_eq!(state.focused_window(), Some(20));
+    assert!(effects.contains(&Effect::ShowBorder {
+        hwnd: 20,
+        monocle: false,
+    }));
+}
+
+#[test]
+fn display_change_migrates_windows_to_primary_monitor() {
+    let mut desktop = FakeDesktop::default();
+    desktop.add(10, 0);
+    desktop.add(20, 1);
+    let mut state = make_state(2);
+    state.adopt_existing(&desktop, &[10, 20]);
+
+    let monitors = vec![MonitorInfo {
+        id: 0,
+        work_area: Rect::new(0, 0, 1920, 1080),
+        ..Default::default()
+    }];
+    let effects = state.handle_display_change(&desktop, monitors, 0, &[]);
+
+    assert_eq!(state.monitors().len(), 1);
+    assert_eq!(state.monitors()[0].active_ws().handles(), &[10, 20]);
+    assert_eq!(rect_for(&effects, 20), Some(Rect::new(960, 0, 960, 1080)));
+}
+
+#[test]
+fn bar_offset_shrinks_work_area() {
+    let mut desktop = FakeDesktop::default();
+    desktop.add(10, 0);
+    let mut state = make_state(1);
+    state.adopt_existing(&desktop, &[10]);
+
+    let effects = state.adjust_work_areas_for_bar(&desktop, 30, &[]);
+
+    assert_eq!(state.monitors()[0].work_area, Rect::new(0, 30, 1920, 1050));
+    assert_eq!(rect_for(&effects, 10), Some(Rect::new(0, 30, 1920, 1050)));
+}
+
+#[test]
+fn restore_all_windows_restores_every_workspace() {
+    let mut desktop = FakeDesktop::default();
+    desktop.add(10, 0);
+    desktop.add(20, 0);
+    let mut state = make_state(1);
+    state.adopt_existing(&desktop, &[10, 20]);
+    state.handle_action(&desktop, &Action::SendToWorkspace(2.into()));
+
+    let effects = state.restore_all_windows();
+
+    assert_eq!(effects[0], Effect::HideBorder);
+    assert!(effects.contains(&Effect::Restore { hwnd: 10 }));
+    assert!(effects.contains(&Effect::Restore { hwnd: 20 }));
+}
+
+#[test]
+fn resize_action_moves_split_next_to_focused_window() {
+    // Arrange
+    let mut desktop = FakeDesktop::default();
+    desktop.add(10, 0);
+    desktop.add(20, 0);
+    let mut state = make_state(1);
+    state.adopt_existing(&desktop, &[10, 20]);
+
+    // Act
+    let effects = state.handle_action(&desktop, &Action::Resize(Direction::Right));
+
+    // Assert
+    assert_eq!(rect_for(&effects, 10), Some(Rect::new(0, 0, 1056, 1080)));
+    assert_eq!(rect_for(&effects, 20), Some(Rect::new(1056, 0, 864, 1080)));
+}
+
+#[test]
+fn resized_ratio_survives_layout_cycling() {
+    // Arrange
+    let mut desktop = FakeDesktop::default();
+    desktop.add(10, 0);
+    desktop.add(20, 0);
+    let mut state = make_state(1);
+    state.adopt_existing(&desktop, &[10, 20]);
+    state.handle_action(&desktop, &Action::Resize(Direction::Left));
+
+    // Act
+    for _ in 0..2 {
+        state.handle_action(&desktop, &Action::CycleLayout);
+    }
+    let effects = state.handle_action(&desktop, &Action::CycleLayout);
+
+    // Assert
+    assert_eq!(rect_for(&effects, 10), Some(Rect::new(0, 0, 864, 1080)));
+}
+
+#[test]
+fn resize_is_ignored_in_monocle() {
+    // Arrange
+    let mut desktop = FakeDesktop::default();
+    desktop.add(10, 0);
+    desktop.add(20, 0);
+    let mut state = make_state(1);
+    state.adopt_existing(&desktop, &[10, 20]);
+    state.handle_action(&desktop, &Action::ToggleMonocle);
+
+    // Act
+    let effects = state.handle_action(&desktop, &Action::Resize(Direction::Right));
+
+    // Assert
+    assert!(effects.is_empty());
+}
+
+#[test]
+fn cycle_layout_follows_configured_cycle() {
+    // Arrange
+    let mut desktop = FakeDesktop::default();
+    for hwnd in [10, 20, 30, 40] {
+        desktop.add(hwnd, 0);
+    }
+    let mut state = make_state(1);
+    state.adopt_existing(&desktop, &[10, 20, 30, 40]);
+    let mut config = Config::default();
+    config.layout.gap = 0;
+    config.layout.cycle = vec![LayoutKind::Bsp, LayoutKind::Grid];
+    state.reload_config(&desktop, &config);
+
+    // Act
+    let effects = state.handle_action(&desktop, &Action::CycleLayout);
+
+    // Assert
+    assert_eq!(
+        state.monitors()[0].active_ws().layout_kind(),
+        &LayoutKind::Grid
+    );
+    assert_eq!(rect_for(&effects, 40), Some(Rect::new(960, 540, 960, 540)));
+}
+
+#[test]
+fn resize_down_grows_horizontal_stack_master() {
+    // Arrange
+    let mut desktop = FakeDesktop::default();
+    desktop.add(10, 0);
+    desktop.add(20, 0);
+    let mut state = make_state(1);
+    state.adopt_existing(&desktop, &[10, 20]);
+    state
+        .monitors
+        .first_mut()
+        .unwrap()
+        .active_ws_mut()
+        .set_layout_kind(LayoutKind::HorizontalStack);
+
+    // Act
+    let effects = state.handle_action(&desktop, &Action::Resize(Direction::Down));
+
+    // Assert
+    assert_eq!(rect_for(&effects, 10), Some(Rect::new(0, 0, 1920, 594)));
+    assert_eq!(rect_for(&effects, 20), Some(Rect::new(0, 594, 1920, 486)));
+}
+
+#[test]
+fn cycle_layout_prev_steps_backwards() {
+    // Arrange
+    let mut desktop = FakeDesktop::default();
+    desktop.add(10, 0);
+    let mut state = make_state(1);
+    state.adopt_existing(&desktop, &[10]);
+
+    // Act
+    state.handle_action(&desktop, &Action::CycleLayoutPrev);
+
+    // Assert
+    assert_eq!(
+        state.monitors()[0].active_ws().layout_kind(),
+        &LayoutKind::ThreeColumn
+    );
+}
+
+#[test]
+fn workspace_cycle_overrides_global_cycle() {
+    // Arrange
+    let desktop = FakeDesktop::default();
+    let mut state = make_state(1);
+    let mut config = Config::default();
+    config
+        .layout
+        .workspace_cycles
+        .insert(2, vec![LayoutKind::Bsp, LayoutKind::Rows]);
+    state.reload_config(&desktop, &config);
+
+    // Act
+    state.handle_action(&desktop, &Action::CycleLayout);
+    state.handle_action(&desktop, &Action::GoToWorkspace(2.into()));
+    state.handle_action(&desktop, &Action::CycleLayout);
+
+    // Assert
+    let mon = &state.monitors()[0];
+    assert_eq!(mon.workspaces[0].layout_kind(), &LayoutKind::VerticalStack);
+    assert_eq!(mon.workspaces[1].layout_kind(), &LayoutKind::Rows);
+}
+
+#[test]
+fn set_layout_switches_directly_and_retiles() {
+    // Arrange
+    let mut desktop = FakeDesktop::default();
+    desktop.add(10, 0);
+    desktop.add(20, 0);
+    let mut state = make_state(1);
+    state.adopt_existing(&desktop, &[10, 20]);
+
+    // Act
+    let effects = state.handle_action(&desktop, &Action::SetLayout(LayoutKind::Rows));
+
+    // Assert
+    assert_eq!(
+        state.monitors()[0].active_ws().layout_kind(),
+        &LayoutKind::Rows
+    );
+    assert_eq!(rect_for(&effects, 10), Some(Rect::new(0, 0, 1920, 540)));
+    assert_eq!(rect_for(&effects, 20), Some(Rect::new(0, 540, 1920, 540)));
+}
+
+#[test]
+fn reloaded_custom_layout_can_be_selected_by_name() {
+    // Arrange
+    let mut desktop = FakeDesktop::default();
+    desktop.add(10, 0);
+    desktop.add(20, 0);
+    let mut state = make_state(1);
+    state.adopt_existing(&desktop, &[10, 20]);
+    let custom = LayoutKind::Custom("focus".into());
+    state.handle_action(&desktop, &Action::SetLayout(custom.clone()));
+    let layouts = crate::config::validate_layouts(
+        r#"
+        [[layout]]
+        name = "focus"
+        root = { split = "horizontal", weights = [3, 1], children = [
+            { slot = 0 }, { rest = "vertical" },
+        ] }
+        "#,
+    )
+    .unwrap();
+
+    // Act
+    let effects = state.reload_layouts(&desktop, layouts);
+
+    // Assert
+    assert_eq!(state.monitors()[0].active_ws().layout_kind(), &custom);
+    assert_eq!(rect_for(&effects, 10), Some(Rect::new(0, 0, 1440, 1080)));
+    assert_eq!(rect_for(&effects, 20), Some(Rect::new(1440, 0, 480, 1080)));
+}
+
+#[test]
+fn unknown_custom_layout_falls_back_to_bsp() {
+    // Arrange
+    let mut desktop = FakeDesktop::default();
+    desktop.add(10, 0);
+    desktop.add(20, 0);
+    let mut state = make_state(1);
+    state.adopt_existing(&desktop, &[10, 20]);
+
+    // Act
+    let effects = state.handle_action(
+        &desktop,
+        &Action::SetLayout(LayoutKind::Custom("missing".into())),
+    );
+
+    // Assert
+    assert_eq!(rect_for(&effects, 10), Some(Rect::new(0, 0, 960, 1080)));
+    assert_eq!(rect_for(&effects, 20), Some(Rect::new(960, 0, 960, 1080)));
+}
+
+#[test]
+fn increase_master_retiles_stack_layout() {
+    // Arrange
+    let mut desktop = FakeDesktop::default();
+    for hwnd in [10, 20, 30] {
+        desktop.add(hwnd, 0);
+    }
+    let mut state = make_state(1);
+    state.adopt_existing(&desktop, &[10, 20, 30]);
+    state.handle_action(&desktop, &Action::SetLayout(LayoutKind::VerticalStack));
+
+    // Act
+    let effects = state.handle_action(&desktop, &Action::IncreaseMaster);
+
+    // Assert
+    assert_eq!(state.monitors()[0].active_ws().master_count(), 2);
+    assert_eq!(rect_for(&effects, 10), Some(Rect::new(0, 0, 960, 540)));
+    assert_eq!(rect_for(&effects, 20), Some(Rect::new(0, 540, 960, 540)));
+    assert_eq!(rect_for(&effects, 30), Some(Rect::new(960, 0, 960, 1080)));
+}
+
+#[test]
+fn promote_to_master_moves_focused_window_first() {
+    // Arrange
+    let mut desktop = FakeDesktop::default();
+    for hwnd in [10, 20, 30] {
+        desktop.add(hwnd, 0);
+    }
+    let mut state = make_state(1);
+    state.adopt_existing(&desktop, &[10, 20, 30]);
+    state.handle_action(&desktop, &Action::SetLayout(LayoutKind::VerticalStack));
+    state.handle_event(&desktop, &WindowEvent::Focused { hwnd: 30 });
+
+    // Act
+    let effects = state.handle_action(&desktop, &Action::PromoteToMaster);
+
+    // Assert
+    assert_eq!(state.monitors()[0].active_ws().handles(), &[30, 20, 10]);
+    assert_eq!(rect_for(&effects, 30), Some(Rect::new(0, 0, 960, 1080)));
+    assert_eq!(state.focused_window(), Some(30));
+}
+
+#[test]
+fn snapshot_reports_monitors_workspaces_and_windows() {
+    // Arrange
+    let mut desktop = FakeDesktop::default();
+    desktop.add(10, 0);
+    desktop.add(20, 1);
+    let mut state = make_state(2);
+    state.adopt_existing(&desktop, &[10, 20]);
+    state.handle_event(&desktop, &WindowEvent::Focused { hwnd: 20 });
+    state.handle_action(&desktop, &Action::ToggleMonocle);
+
+    // Act
+    let snapshot = state.snapshot(&desktop);
+
+    // Assert
+    assert_eq!(snapshot.monitors.len(), 2);
+    let mon = &snapshot.monitors[1];
+    assert_eq!(mon.name, r"\\.\DISPLAY2");
+    assert_eq!(mon.work_area, Rect::new(1920, 0, 1920, 1080));
+    assert_eq!(mon.active_workspace, 1);
+    let ws = &mon.workspaces[0];
+    assert!(ws.visible && ws.monocle);
+    assert_eq!(ws.monocle_window, Some(20));
+    assert_eq!(ws.layout, LayoutKind::Bsp);
+    let window = &ws.windows[0];
+    assert_eq!((window.hwnd, window.monitor, window.workspace), (20, 1, 1));
+    assert_eq!(window.class, "App");
+    assert_eq!(window.process, "app.exe");
+    assert_eq!(snapshot.windows().len(), 2);
+    let focused = snapshot.focused();
+    assert_eq!(focused.monitor, 1);
+    assert_eq!(focused.window.map(|w| w.title), Some("Window 20".into()));
+}
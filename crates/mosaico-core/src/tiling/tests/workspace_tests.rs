@@ -0,0 +1,236 @@
+use super::super::*;
+use super::{FakeDesktop, make_monitor, make_state};
+use crate::{Config, WindowEvent};
+
+#[test]
+fn goto_workspace_logic() {
+    let mut mon = make_monitor(1);
+    mon.workspaces[0].add(10);
+    mon.workspaces[0].add(20);
+    mon.workspaces[1].add(30);
+
+    // Switch from ws 0 to ws 1
+    let mut hidden = HashSet::new();
+    for &hwnd in mon.active_ws().handles() {
+        hidden.insert(hwnd);
+    }
+    mon.active_workspace = 1;
+
+    // After switch: ws 1 is active, hidden set has ws 0's windows
+    assert_eq!(mon.active_ws().len(), 1);
+    assert!(mon.active_ws().contains(30));
+    assert!(hidden.contains(&10));
+    assert!(hidden.contains(&20));
+
+    // Show ws 1 windows — remove from hidden
+    for &hwnd in mon.active_ws().handles() {
+        hidden.remove(&hwnd);
+    }
+    // 30 was not hidden, so set unchanged (still has 10, 20)
+    assert_eq!(hidden.len(), 2);
+}
+
+#[test]
+fn send_to_workspace_logic() {
+    let mut mon = make_monitor(1);
+    mon.workspaces[0].add(10);
+    mon.workspaces[0].add(20);
+    mon.workspaces[0].add(30);
+    mon.active_workspace = 0;
+
+    // Send window 20 from ws 0 to ws 2
+    let target_ws = 2;
+    assert!(mon.active_ws().contains(20));
+    mon.active_ws_mut().remove(20);
+    mon.workspaces[target_ws].add(20);
+
+    assert_eq!(mon.workspaces[0].len(), 2);
+    assert_eq!(mon.workspaces[target_ws].len(), 1);
+    assert!(mon.workspaces[target_ws].contains(20));
+    assert!(!mon.workspaces[0].contains(20));
+}
+
+#[test]
+fn send_to_same_workspace_is_noop() {
+    let mut mon = make_monitor(1);
+    mon.workspaces[0].add(10);
+    mon.active_workspace = 0;
+
+    // Sending to active workspace should be a no-op
+    let target = mon.active_workspace;
+    assert_eq!(target, 0);
+    // The real code returns early; simulate by checking condition
+    assert!(mon.active_workspace == target);
+    assert_eq!(mon.workspaces[0].len(), 1);
+}
+
+#[test]
+fn goto_same_workspace_is_noop() {
+    let mon = make_monitor(1);
+    // Switching to already-active workspace should be a no-op
+    assert_eq!(mon.active_workspace, 0);
+    // The real code returns early when active_workspace == target
+}
+
+#[test]
+fn goto_workspace_by_name_uses_configured_names() {
+    // Arrange
+    let desktop = FakeDesktop::default();
+    let mut state = make_state(1);
+    let mut config = Config::default();
+    config.workspaces.names = vec!["web".into(), "code".into()];
+    state.reload_config(&desktop, &config);
+
+    // Act
+    state.handle_action(&desktop, &Action::GoToWorkspace("code".parse().unwrap()));
+    state.handle_action(&desktop, &Action::GoToWorkspace("chat".parse().unwrap()));
+
+    // Assert
+    assert_eq!(state.monitors()[0].active_workspace, 1);
+    assert_eq!(
+        state.workspace_labels(0)[..3],
+        ["web".to_string(), "code".into(), "3".into()]
+    );
+}
+
+#[test]
+fn per_monitor_count_limits_workspaces() {
+    // Arrange
+    let desktop = FakeDesktop::default();
+    let mut state = make_state(2);
+    let mut config = Config::default();
+    config.workspaces.count = 3;
+    config.workspaces.monitors.insert(2, 5);
+
+    // Act
+    state.reload_config(&desktop, &config);
+    state.handle_action(&desktop, &Action::GoToWorkspace(4.into()));
+
+    // Assert
+    assert_eq!(state.monitors()[0].workspaces.len(), 3);
+    assert_eq!(state.monitors()[1].workspaces.len(), 5);
+    assert_eq!(state.monitors()[0].active_workspace, 0);
+}
+
+#[test]
+fn workspaces_past_sixteen_are_reachable_and_zero_is_ignored() {
+    // Arrange
+    let desktop = FakeDesktop::default();
+    let mut state = make_state(1);
+    let mut config = Config::default();
+    config.workspaces.count = 40;
+    state.reload_config(&desktop, &config);
+
+    // Act
+    state.handle_action(&desktop, &Action::GoToWorkspace(40.into()));
+    state.handle_action(&desktop, &Action::GoToWorkspace(0.into()));
+
+    // Assert
+    assert_eq!(state.monitors()[0].workspaces.len(), 40);
+    assert_eq!(state.monitors()[0].active_workspace, 39);
+}
+
+#[test]
+fn shrinking_count_moves_windows_to_last_workspace() {
+    // Arrange
+    let mut desktop = FakeDesktop::default();
+    desktop.add(10, 0);
+    desktop.add(20, 0);
+    let mut state = make_state(1);
+    state.adopt_existing(&desktop, &[10, 20]);
+    state.handle_event(&desktop, &WindowEvent::Focused { hwnd: 20 });
+    state.handle_action(&desktop, &Action::SendToWorkspace(5.into()));
+    let mut config = Config::default();
+    config.layout.gap = 0;
+    config.workspaces.count = 3;
+
+    // Act
+    let effects = state.reload_config(&desktop, &config);
+
+    // Assert
+    let mon = &state.monitors()[0];
+    assert_eq!(mon.workspaces.len(), 3);
+    assert_eq!(mon.active_workspace, 2);
+    assert!(mon.workspaces[2].contains(20));
+    assert!(
+        effects
+            .iter()
+            .any(|e| matches!(e, Effect::Show { hwnd: 20, .. }))
+    );
+}
+
+#[test]
+fn goto_workspace_next_and_prev_wrap_around() {
+    // Arrange
+    let desktop = FakeDesktop::default();
+    let mut state = make_state(1);
+
+    // Act
+    state.handle_action(&desktop, &Action::GoToWorkspacePrev);
+    let after_prev = state.monitors()[0].active_workspace;
+    state.handle_action(&desktop, &Action::GoToWorkspaceNext);
+
+    // Assert
+    assert_eq!(after_prev, 7);
+    assert_eq!(state.monitors()[0].active_workspace, 0);
+}
+
+#[test]
+fn goto_workspace_next_skips_empty_workspaces() {
+    // Arrange
+    let mut desktop = FakeDesktop::default();
+    desktop.add(10, 0);
+    desktop.add(20, 0);
+    let mut state = make_state(1);
+    let mut config = Config::default();
+    config.workspaces.skip_empty = true;
+    state.reload_config(&desktop, &config);
+    state.adopt_existing(&desktop, &[10, 20]);
+    state.handle_event(&desktop, &WindowEvent::Focused { hwnd: 20 });
+    state.handle_action(&desktop, &Action::SendToWorkspace(4.into()));
+
+    // Act
+    state.handle_action(&desktop, &Action::GoToWorkspaceNext);
+    let first = state.monitors()[0].active_workspace;
+    state.handle_action(&desktop, &Action::GoToWorkspaceNext);
+
+    // Assert
+    assert_eq!(first, 0);
+    assert_eq!(state.monitors()[0].active_workspace, 3);
+}
+
+#[test]
+fn goto_workspace_last_toggles_between_recent_workspaces() {
+    // Arrange
+    let desktop = FakeDesktop::default();
+    let mut state = make_state(1);
+    state.handle_action(&desktop, &Action::GoToWorkspace(3.into()));
+    state.handle_action(&desktop, &Action::GoToWorkspace(5.into()));
+
+    // Act
+    state.handle_action(&desktop, &Action::GoToWorkspaceLast);
+    let first = state.monitors()[0].active_workspace;
+    state.handle_action(&desktop, &Action::GoToWorkspaceLast);
+
+    // Assert
+    assert_eq!(first, 2);
+    assert_eq!(state.monitors()[0].active_workspace, 4);
+    assert_eq!(state.monitors()[0].workspace_history, [0, 2]);
+}
+
+#[test]
+fn back_and_forth_returns_from_visible_workspace() {
+    // Arrange
+    let desktop = FakeDesktop::default();
+    let mut state = make_state(1);
+    let mut config = Config::default();
+    config.workspaces.back_and_forth = true;
+    state.reload_config(&desktop, &config);
+    state.handle_action(&desktop, &Action::GoToWorkspace(3.into()));
+
+    // Act
+    state.handle_action(&desktop, &Action::GoToWorkspace(3.into()));
+
+    // Assert
+    assert_eq!(state.monitors()[0].active_workspace, 0);
+}
@@ -0,0 +1,253 @@
+use super::super::*;
+use super::{FakeDesktop, make_state, rect_for};
+use crate::WindowEvent;
+use crate::action::{Direction, MonitorTarget};
+
+#[test]
+fn move_workspace_carries_windows_layout_and_monocle() {
+    // Arrange
+    let mut desktop = FakeDesktop::default();
+    desktop.add(10, 0);
+    desktop.add(20, 0);
+    let mut state = make_state(2);
+    state.adopt_existing(&desktop, &[10, 20]);
+    state.handle_event(&desktop, &WindowEvent::Focused { hwnd: 10 });
+    state.handle_action(&desktop, &Action::SetLayout(LayoutKind::Rows));
+    state.handle_action(&desktop, &Action::ToggleMonocle);
+
+    // Act
+    let effects = state.handle_action(
+        &desktop,
+        &Action::MoveWorkspaceToMonitor(MonitorTarget::Direction(Direction::Right)),
+    );
+
+    // Assert
+    let moved = state.monitors()[1].active_ws();
+    assert_eq!(moved.handles(), &[10, 20]);
+    assert_eq!(moved.layout_kind(), &LayoutKind::Rows);
+    assert!(moved.monocle());
+    assert!(state.monitors()[0].active_ws().is_empty());
+    assert_eq!(state.focused_monitor(), 1);
+    assert_eq!(rect_for(&effects, 10), Some(Rect::new(1920, 0, 1920, 1080)));
+}
+
+#[test]
+fn move_workspace_takes_empty_slot_and_hides_target_windows() {
+    // Arrange
+    let mut desktop = FakeDesktop::default();
+    desktop.add(10, 0);
+    desktop.add(30, 1);
+    let mut state = make_state(2);
+    state.adopt_existing(&desktop, &[10, 30]);
+    state.handle_event(&desktop, &WindowEvent::Focused { hwnd: 10 });
+
+    // Act
+    let effects = state.handle_action(
+        &desktop,
+        &Action::MoveWorkspaceToMonitor(MonitorTarget::Number(2)),
+    );
+
+    // Assert
+    let target = &state.monitors()[1];
+    assert_eq!(target.active_workspace, 1);
+    assert!(target.active_ws().contains(10));
+    assert!(target.workspaces[0].contains(30));
+    assert!(
+        effects
+            .iter()
+            .any(|e| matches!(e, Effect::Hide { hwnd: 30, .. }))
+    );
+}
+
+#[test]
+fn swap_workspaces_exchanges_visible_workspaces() {
+    // Arrange
+    let mut desktop = FakeDesktop::default();
+    desktop.add(10, 0);
+    desktop.add(30, 1);
+    let mut state = make_state(2);
+    state.adopt_existing(&desktop, &[10, 30]);
+    state.handle_event(&desktop, &WindowEvent::Focused { hwnd: 10 });
+
+    // Act
+    let effects = state.handle_action(
+        &desktop,
+        &Action::SwapWorkspacesWithMonitor(Direction::Right),
+    );
+
+    // Assert
+    assert!(state.monitors()[0].active_ws().contains(30));
+    assert!(state.monitors()[1].active_ws().contains(10));
+    assert_eq!(rect_for(&effects, 30), Some(Rect::new(0, 0, 1920, 1080)));
+    assert_eq!(rect_for(&effects, 10), Some(Rect::new(1920, 0, 1920, 1080)));
+}
+
+#[test]
+fn moved_floating_windows_keep_their_relative_position() {
+    // Arrange
+    let mut desktop = FakeDesktop::default();
+    desktop.add(10, 0);
+    let mut state = make_state(2);
+    state.adopt_existing(&desktop, &[10]);
+    state.handle_event(&desktop, &WindowEvent::Focused { hwnd: 10 });
+    state.handle_action(&desktop, &Action::ToggleFloat);
+    let floating = state.monitors()[0].active_ws().floating_rect(10).unwrap();
+
+    // Act
+    let effects = state.handle_action(
+        &desktop,
+        &Action::MoveWorkspaceToMonitor(MonitorTarget::Direction(Direction::Right)),
+    );
+
+    // Assert
+    let expected = Rect::new(
+        floating.x + 1920,
+        floating.y,
+        floating.width,
+        floating.height,
+    );
+    assert_eq!(rect_for(&effects, 10), Some(expected));
+    assert_eq!(
+        state.monitors()[1].active_ws().floating_rect(10),
+        Some(expected)
+    );
+}
+
+#[test]
+fn move_workspace_to_focused_monitor_does_nothing() {
+    // Arrange
+    let mut desktop = FakeDesktop::default();
+    desktop.add(10, 0);
+    let mut state = make_state(2);
+    state.adopt_existing(&desktop, &[10]);
+
+    // Act
+    let effects = state.handle_action(
+        &desktop,
+        &Action::MoveWorkspaceToMonitor(MonitorTarget::Number(1)),
+    );
+
+    // Assert
+    assert!(effects.is_empty());
+    assert!(state.monitors()[0].active_ws().contains(10));
+}
+
+#[test]
+fn send_to_monitor_by_number_keeps_focus_on_source() {
+    // Arrange
+    let mut desktop = FakeDesktop::default();
+    desktop.add(10, 0);
+    desktop.add(20, 0);
+    let mut state = make_state(3);
+    state.adopt_existing(&desktop, &[10, 20]);
+    state.handle_event(&desktop, &WindowEvent::Focused { hwnd: 20 });
+
+    // Act
+    let effects = state.handle_action(
+        &desktop,
+        &Action::SendToMonitor {
+            monitor: MonitorTarget::Number(3),
+            workspace: None,
+            follow: false,
+        },
+    );
+
+    // Assert
+    assert!(state.monitors()[2].active_ws().contains(20));
+    assert_eq!(state.focused_monitor(), 0);
+    assert_eq!(state.focused_window(), Some(10));
+    assert_eq!(rect_for(&effects, 20), Some(Rect::new(3840, 0, 1920, 1080)));
+    assert_eq!(rect_for(&effects, 10), Some(Rect::new(0, 0, 1920, 1080)));
+}
+
+#[test]
+fn send_to_monitor_returns_focus_to_last_focused_window() {
+    // Arrange
+    let mut desktop = FakeDesktop::default();
+    desktop.add(10, 0);
+    desktop.add(20, 0);
+    desktop.add(30, 0);
+    let mut state = make_state(2);
+    state.adopt_existing(&desktop, &[10, 20, 30]);
+    state.handle_event(&desktop, &WindowEvent::Focused { hwnd: 30 });
+    state.handle_action(&desktop, &Action::GoToWorkspace(2.into()));
+    state.handle_action(&desktop, &Action::GoToWorkspace(1.into()));
+    state.handle_event(&desktop, &WindowEvent::Focused { hwnd: 20 });
+
+    // Act
+    state.handle_action(
+        &desktop,
+        &Action::SendToMonitor {
+            monitor: MonitorTarget::Number(2),
+            workspace: None,
+            follow: false,
+        },
+    );
+
+    // Assert
+    assert!(state.monitors()[1].active_ws().contains(20));
+    assert_eq!(state.focused_window(), Some(30));
+}
+
+#[test]
+fn send_and_follow_to_monitor_workspace_switches_there() {
+    // Arrange
+    let mut desktop = FakeDesktop::default();
+    desktop.add(10, 0);
+    desktop.add(30, 1);
+    let mut state = make_state(2);
+    state.adopt_existing(&desktop, &[10, 30]);
+    state.handle_event(&desktop, &WindowEvent::Focused { hwnd: 10 });
+
+    // Act
+    let effects = state.handle_action(
+        &desktop,
+        &Action::SendToMonitor {
+            monitor: MonitorTarget::Direction(Direction::Right),
+            workspace: Some(3.into()),
+            follow: true,
+        },
+    );
+
+    // Assert
+    let target = &state.monitors()[1];
+    assert_eq!(target.active_workspace, 2);
+    assert!(target.active_ws().contains(10));
+    assert_eq!(state.focused_monitor(), 1);
+    assert_eq!(state.focused_window(), Some(10));
+    assert!(
+        effects
+            .iter()
+            .any(|e| matches!(e, Effect::Hide { hwnd: 30, .. }))
+    );
+}
+
+#[test]
+fn send_to_hidden_monitor_workspace_hides_window() {
+    // Arrange
+    let mut desktop = FakeDesktop::default();
+    desktop.add(10, 0);
+    let mut state = make_state(2);
+    state.adopt_existing(&desktop, &[10]);
+    state.handle_event(&desktop, &WindowEvent::Focused { hwnd: 10 });
+
+    // Act
+    let effects = state.handle_action(
+        &desktop,
+        &Action::SendToMonitor {
+            monitor: MonitorTarget::Number(2),
+            workspace: Some(2.into()),
+            follow: false,
+        },
+    );
+
+    // Assert
+    assert!(state.monitors()[1].workspaces[1].contains(10));
+    assert_eq!(state.monitors()[1].active_workspace, 0);
+    assert_eq!(state.focused_window(), None);
+    assert!(
+        effects
+            .iter()
+            .any(|e| matches!(e, Effect::Hide { hwnd: 10, .. }))
+    );
+}
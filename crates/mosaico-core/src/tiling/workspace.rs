@@ -0,0 +1,315 @@
+//! Workspace switching: go-to and send-to operations.
+//!
+//! Manages hiding/showing windows when switching workspaces and
+//! tracks programmatically hidden windows to prevent spurious
+//! `Hidden` event removals.
+
+use std::time::Duration;
+
+use crate::WorkspaceRef;
+use crate::config::HidingBehaviour;
+
+use super::{Desktop, Effect, TilingState};
+
+/// How long after a workspace switch to suppress focus-triggered switches.
+///
+/// Platform events (e.g. `EVENT_SYSTEM_FOREGROUND`) fired during the switch
+/// are queued asynchronously and may arrive after the switch completes. A
+/// 500 ms cooldown is long enough to absorb these deferred events without
+/// being noticeable to the user.
+const WS_SWITCH_COOLDOWN: Duration = Duration::from_millis(500);
+
+impl TilingState {
+    /// Switches to workspace `idx` (0-based) on the focused monitor.
+    ///
+    /// Hides windows on the current workspace, shows windows on the
+    /// target, retiles, and focuses the first window.
+    pub(super) fn goto_workspace(&mut self, desktop: &dyn Desktop, idx: usize) {
+        let mon_idx = self.focused_monitor;
+        let Some(mon) = self.monitors.get(mon_idx) else {
+            return;
+        };
+        if mon.active_workspace == idx || idx >= mon.workspaces.len() {
+            return; // already there, or no such workspace here
+        }
+
+        self.ws_switch_cooldown = Some(desktop.now() + WS_SWITCH_COOLDOWN);
+
+        // Remember which window was focused before leaving.
+        self.monitors[mon_idx]
+            .active_ws_mut()
+            .set_last_focused(self.focused_window);
+
+        // Collect the previous workspace's handles so we can hide them
+        // AFTER the target workspace's window is foregrounded. Hiding
+        // first would leave the old foreground window cloaked, and DWM
+        // may auto-uncloak it during rapid switching if no new window
+        // takes foreground before the next switch.
+        let prev_handles = self.monitors[mon_idx].active_ws().windows();
+        let prev_ws = self.monitors[mon_idx].active_workspace;
+
+        // Switch active workspace.
+        self.monitors[mon_idx].switch_workspace(idx);
+
+        // Show windows on the target workspace and unmark them.
+        let target_handles = self.monitors[mon_idx].active_ws().windows();
+        for hwnd in target_handles {
+            self.show_window(hwnd);
+        }
+
+        self.apply_layout_on(desktop, mon_idx);
+
+        // Restore focus to the last focused window on this workspace.
+        // In monocle mode, prefer the monocle window. Fall back to the
+        // first window if the remembered window is no longer present.
+        let ws = self.monitors[mon_idx].active_ws();
+        let target = if ws.monocle() {
+            ws.monocle_window()
+                .filter(|&h| ws.contains(h))
+                .or_else(|| ws.last_focused().filter(|&h| ws.contains(h)))
+                .or_else(|| ws.handles().first().copied())
+        } else {
+            ws.last_focused()
+                .filter(|&h| ws.contains(h))
+                .or_else(|| ws.handles().first().copied())
+        };
+
+        if let Some(hwnd) = target {
+            self.focus_and_update_border(desktop, hwnd);
+        } else {
+            self.focused_window = None;
+            self.update_border();
+        }
+
+        // Now hide the previous workspace's windows. Doing this AFTER
+        // focusing the target window ensures the foreground has moved
+        // away, preventing DWM from auto-uncloaking the old window.
+        for hwnd in prev_handles {
+            self.hide_window(hwnd);
+        }
+
+        crate::log_debug!(
+            "goto-workspace {} on mon {} (from ws {}, {} windows)",
+            idx + 1,
+            mon_idx,
+            prev_ws + 1,
+            self.monitors[mon_idx].active_ws().len()
+        );
+    }
+
+    /// Switches to workspace `idx` (0-based), or back to the previous
+    /// workspace when `idx` is already visible and `[workspaces]
+    /// back_and_forth` is on.
+    pub(super) fn goto_or_back(&mut self, desktop: &dyn Desktop, idx: usize) {
+        let visible = self
+            .monitors
+            .get(self.focused_monitor)
+            .is_some_and(|m| m.active_workspace == idx);
+        if visible && self.workspaces_config.back_and_forth {
+            self.goto_last_workspace(desktop);
+        } else {
+            self.goto_workspace(desktop, idx);
+        }
+    }
+
+    /// Switches to the next (or previous) workspace on the focused
+    /// monitor, wrapping around. With `[workspaces] skip_empty`, empty
+    /// workspaces are passed over.
+    pub(super) fn goto_adjacent_workspace(&mut self, desktop: &dyn Desktop, forward: bool) {
+        let Some(mon) = self.monitors.get(self.focused_monitor) else {
+            return;
+        };
+        let len = mon.workspaces.len();
+        let target = (1..len)
+            .map(|step| {
+                if forward {
+                    (mon.active_workspace + step) % len
+                } else {
+                    (mon.active_workspace + len - step) % len
+                }
+            })
+            .find(|&i| !self.workspaces_config.skip_empty || !mon.workspaces[i].is_empty());
+        if let Some(idx) = target {
+            self.goto_workspace(desktop, idx);
+        }
+    }
+
+    /// Switches back to the previously visible workspace on the focused
+    /// monitor.
+    pub(super) fn goto_last_workspace(&mut self, desktop: &dyn Desktop) {
+        let last = self
+            .monitors
+            .get(self.focused_monitor)
+            .and_then(|m| m.workspace_history.last().copied());
+        if let Some(idx) = last {
+            self.goto_workspace(desktop, idx);
+        }
+    }
+
+    /// Sends the focused window to workspace `target_ws` (0-based) on
+    /// the same monitor, then follows it there.
+    ///
+    /// Moves the window to the target workspace, switches to that
+    /// workspace, and focuses the moved window.
+    pub(super) fn send_to_workspace(&mut self, desktop: &dyn Desktop, target_ws: usize) {
+        let Some(hwnd) = self.focused_window else {
+            return;
+        };
+        let mon_idx = self.focused_monitor;
+        let Some(mon) = self.monitors.get(mon_idx) else {
+            return;
+        };
+        if mon.active_workspace == target_ws || target_ws >= mon.workspaces.len() {
+            return; // already on target workspace, or no such workspace
+        }
+        if !mon.active_ws().contains(hwnd) {
+            return; // focused window is not on the active workspace
+        }
+        let src_ws_num = mon.active_workspace + 1;
+
+        self.ws_switch_cooldown = Some(desktop.now() + WS_SWITCH_COOLDOWN);
+
+        // Remove from current workspace, add to target. Floating
+        // windows keep floating where they are.
+        let floating = self.monitors[mon_idx].active_ws().floating_rect(hwnd);
+        self.monitors[mon_idx].active_ws_mut().remove(hwnd);
+        let target = &mut self.monitors[mon_idx].workspaces[target_ws];
+        match floating {
+            Some(rect) => target.add_floating(hwnd, rect),
+            None => target.add(hwnd),
+        };
+
+        // Hide remaining windows on the source workspace.
+        let source_handles = self.monitors[mon_idx].active_ws().windows();
+        for h in source_handles {
+            self.hide_window(h);
+        }
+
+        // Switch to the target workspace.
+        self.monitors[mon_idx].switch_workspace(target_ws);
+
+        // Show all windows on the target workspace.
+        let target_handles = self.monitors[mon_idx].active_ws().windows();
+        for h in target_handles {
+            self.show_window(h);
+        }
+
+        crate::log_info!(
+            "send-to-workspace {} 0x{:X} on mon {} (ws {} -> ws {})",
+            target_ws + 1,
+            hwnd,
+            mon_idx,
+            src_ws_num,
+            target_ws + 1
+        );
+
+        self.apply_layout_on(desktop, mon_idx);
+        self.focus_and_update_border(desktop, hwnd);
+    }
+
+    /// Resolves a workspace reference to its 0-based index.
+    ///
+    /// Names are looked up in `[workspaces] names`; an unknown name, or
+    /// the number 0, resolves to nothing.
+    pub(super) fn resolve_workspace(&self, ws: &WorkspaceRef) -> Option<usize> {
+        match ws {
+            WorkspaceRef::Number(n) => usize::from(*n).checked_sub(1),
+            WorkspaceRef::Name(name) => self.workspaces_config.index_of(name),
+        }
+    }
+
+    /// Grows or shrinks the monitor's workspaces to its configured count.
+    ///
+    /// Windows on removed workspaces move to the last remaining one. If
+    /// the visible workspace is removed, the last one becomes visible.
+    pub(super) fn fit_workspaces(&mut self, mon_idx: usize) {
+        let overrides = self.overrides(mon_idx);
+        let count = self.workspace_count(&overrides, mon_idx);
+        let len = self.monitors[mon_idx].workspaces.len();
+        if count > len {
+            let added: Vec<_> = (len..count)
+                .map(|i| self.new_workspace_with(&overrides, i))
+                .collect();
+            self.monitors[mon_idx].workspaces.extend(added);
+            return;
+        }
+        if count == len {
+            return;
+        }
+
+        let last = count - 1;
+        let mon = &mut self.monitors[mon_idx];
+        let was_visible = mon.active_workspace > last;
+        if was_visible {
+            mon.active_workspace = last;
+        }
+        let active = mon.active_workspace;
+        mon.workspace_history.retain(|&i| i < count && i != active);
+        let removed: Vec<_> = mon.workspaces.drain(count..).collect();
+        let mut moved = Vec::new();
+        for ws in &removed {
+            for hwnd in ws.windows() {
+                let target = &mut self.monitors[mon_idx].workspaces[last];
+                match ws.floating_rect(hwnd) {
+                    Some(rect) => target.add_floating(hwnd, rect),
+                    None => target.add(hwnd),
+                };
+                moved.push(hwnd);
+            }
+        }
+        crate::log_info!(
+            "mon {} now has {} workspaces ({} windows moved to ws {})",
+            mon_idx,
+            count,
+            moved.len(),
+            count
+        );
+
+        let visible = self.monitors[mon_idx].active_workspace == last;
+        if was_visible {
+            // The last workspace just became visible: show all of it.
+            for hwnd in self.monitors[mon_idx].workspaces[last].windows() {
+                self.show_window(hwnd);
+            }
+        } else {
+            for hwnd in moved {
+                self.set_visible(hwnd, visible);
+            }
+        }
+    }
+
+    /// Shows or hides a window that moved between workspaces without a
+    /// workspace switch.
+    pub(super) fn set_visible(&mut self, hwnd: usize, visible: bool) {
+        if visible {
+            self.show_window(hwnd);
+        } else {
+            self.hide_window(hwnd);
+        }
+    }
+
+    /// Hides a window using the configured strategy.
+    ///
+    /// Strategies other than cloaking fire hide/minimize events, so the
+    /// window is remembered in `hidden_by_switch` to ignore them.
+    pub(super) fn hide_window(&mut self, hwnd: usize) {
+        if self.hiding != HidingBehaviour::Cloak {
+            self.hidden_by_switch.insert(hwnd);
+        }
+        self.effects.push(Effect::Hide {
+            hwnd,
+            hiding: self.hiding,
+        });
+    }
+
+    /// Shows a window, reversing the configured hiding strategy.
+    pub(super) fn show_window(&mut self, hwnd: usize) {
+        if self.hiding != HidingBehaviour::Cloak {
+            self.hidden_by_switch.remove(&hwnd);
+        }
+        self.effects.push(Effect::Show {
+            hwnd,
+            hiding: self.hiding,
+        });
+    }
+}
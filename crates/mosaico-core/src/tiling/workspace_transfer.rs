@@ -0,0 +1,246 @@
+//! Sending windows and whole workspaces straight to other monitors.
+//!
+//! A workspace travels as a unit: its windows, split tree, layout kind,
+//! monocle state and floating rects all go with it. Floating windows
+//! keep their position relative to the monitor they land on.
+
+use crate::action::{Direction, MonitorTarget};
+use crate::{Rect, Workspace, WorkspaceRef};
+
+use super::{Desktop, Effect, TilingState};
+
+impl TilingState {
+    /// Resolves a monitor target to the index of a monitor other than
+    /// the focused one.
+    pub(super) fn resolve_monitor(&self, target: MonitorTarget) -> Option<usize> {
+        let idx = match target {
+            MonitorTarget::Direction(dir) => self.find_adjacent_monitor(dir)?,
+            MonitorTarget::Number(n) => usize::from(n) - 1,
+        };
+        (idx < self.monitors.len() && idx != self.focused_monitor).then_some(idx)
+    }
+
+    /// Moves the focused monitor's visible workspace to `target`.
+    ///
+    /// The workspace takes the target's visible slot if that is empty,
+    /// otherwise its first empty slot, and becomes visible there. The
+    /// source keeps an empty workspace in its place. Does nothing if
+    /// the workspace is empty or the target has no empty slot.
+    pub(super) fn move_workspace_to_monitor(
+        &mut self,
+        desktop: &dyn Desktop,
+        target: MonitorTarget,
+    ) {
+        let source = self.focused_monitor;
+        let Some(target) = self.resolve_monitor(target) else {
+            return;
+        };
+        if self.monitors[source].active_ws().is_empty() {
+            return;
+        }
+        let dest = &self.monitors[target];
+        let slot = if dest.active_ws().is_empty() {
+            Some(dest.active_workspace)
+        } else {
+            dest.workspaces.iter().position(Workspace::is_empty)
+        };
+        let Some(slot) = slot else {
+            crate::log_info!("move-workspace: mon {} has no empty workspace", target);
+            return;
+        };
+
+        let src_ws = self.monitors[source].active_workspace;
+        let fresh = self.new_workspace(source, src_ws);
+        let mut ws = std::mem::replace(&mut self.monitors[source].workspaces[src_ws], fresh);
+        self.shift_floating(&mut ws, source, target);
+
+        // The target's visible windows are hidden once focus has moved,
+        // as in goto_workspace.
+        let dest = &mut self.monitors[target];
+        let prev_handles = if slot == dest.active_workspace {
+            Vec::new()
+        } else {
+            dest.active_ws().windows()
+        };
+        dest.workspaces[slot] = ws;
+        if slot != dest.active_workspace {
+            dest.switch_workspace(slot);
+        }
+
+        crate::log_info!(
+            "move-workspace ws {} on mon {} -> ws {} on mon {}",
+            src_ws + 1,
+            source,
+            slot + 1,
+            target
+        );
+        self.finish_transfer(desktop, source, target);
+        for hwnd in prev_handles {
+            self.hide_window(hwnd);
+        }
+    }
+
+    /// Swaps the visible workspaces of the focused monitor and its
+    /// neighbor in `dir`. Focus follows the focused monitor's workspace.
+    pub(super) fn swap_workspaces_with_monitor(&mut self, desktop: &dyn Desktop, dir: Direction) {
+        let source = self.focused_monitor;
+        let Some(target) = self.resolve_monitor(MonitorTarget::Direction(dir)) else {
+            return;
+        };
+
+        let src_ws = self.monitors[source].active_workspace;
+        let dest_ws = self.monitors[target].active_workspace;
+        let mut ours = std::mem::take(&mut self.monitors[source].workspaces[src_ws]);
+        let mut theirs = std::mem::take(&mut self.monitors[target].workspaces[dest_ws]);
+        self.shift_floating(&mut ours, source, target);
+        self.shift_floating(&mut theirs, target, source);
+        self.monitors[source].workspaces[src_ws] = theirs;
+        self.monitors[target].workspaces[dest_ws] = ours;
+
+        crate::log_info!(
+            "swap-workspaces ws {} on mon {} <-> ws {} on mon {}",
+            src_ws + 1,
+            source,
+            dest_ws + 1,
+            target
+        );
+        self.finish_transfer(desktop, source, target);
+    }
+
+    /// Sends the focused window to `target`'s visible workspace, or to
+    /// `workspace` there.
+    ///
+    /// With `follow`, focus goes along and the target switches to that
+    /// workspace. Otherwise focus stays on the source monitor and the
+    /// window is hidden if it landed on a workspace that is not visible.
+    pub(super) fn send_to_monitor(
+        &mut self,
+        desktop: &dyn Desktop,
+        target: MonitorTarget,
+        workspace: Option<&WorkspaceRef>,
+        follow: bool,
+    ) {
+        let Some(hwnd) = self.focused_window else {
+            return;
+        };
+        let source = self.focused_monitor;
+        let Some(target) = self.resolve_monitor(target) else {
+            return;
+        };
+        if !self.monitors[source].active_ws().contains(hwnd) {
+            return;
+        }
+        let dest_ws = match workspace {
+            None => self.monitors[target].active_workspace,
+            Some(ws) => match self.resolve_workspace(ws) {
+                Some(idx) => idx,
+                None => return,
+            },
+        };
+        if dest_ws >= self.monitors[target].workspaces.len() {
+            return;
+        }
+
+        let ws = self.monitors[source].active_ws_mut();
+        let floating = ws.floating_rect(hwnd);
+        ws.remove(hwnd);
+        if ws.monocle_window() == Some(hwnd) {
+            ws.set_monocle(false);
+            ws.set_monocle_window(None);
+        }
+        let from = self.monitors[source].work_area;
+        let to = self.monitors[target].work_area;
+        let dest = &mut self.monitors[target].workspaces[dest_ws];
+        match floating {
+            Some(rect) => {
+                let rect = shifted(rect, &from, &to);
+                dest.add_floating(hwnd, rect);
+                self.effects.push(Effect::SetRect { hwnd, rect });
+            }
+            None => {
+                dest.add(hwnd);
+            }
+        }
+        crate::log_info!(
+            "send-to-monitor 0x{:X} mon {} -> mon {} ws {}",
+            hwnd,
+            source,
+            target,
+            dest_ws + 1
+        );
+
+        let visible = self.monitors[target].active_workspace == dest_ws;
+        if follow {
+            let prev_handles = if visible {
+                Vec::new()
+            } else {
+                let prev = self.monitors[target].active_ws().windows();
+                self.monitors[target].switch_workspace(dest_ws);
+                for h in self.monitors[target].active_ws().windows() {
+                    self.show_window(h);
+                }
+                prev
+            };
+            self.focused_monitor = target;
+            self.apply_layout_on(desktop, source);
+            self.apply_layout_on(desktop, target);
+            self.focus_and_update_border(desktop, hwnd);
+            for h in prev_handles {
+                self.hide_window(h);
+            }
+        } else {
+            if !visible {
+                self.hide_window(hwnd);
+            }
+            self.apply_layout_on(desktop, source);
+            self.apply_layout_on(desktop, target);
+            let ws = self.monitors[source].active_ws();
+            let next = ws
+                .last_focused()
+                .filter(|&h| ws.contains(h))
+                .or_else(|| ws.handles().first().copied());
+            match next {
+                Some(next) => self.focus_and_update_border(desktop, next),
+                None => {
+                    self.focused_window = None;
+                    self.update_border();
+                }
+            }
+        }
+    }
+
+    /// Retiles both monitors after a transfer and follows the moved
+    /// workspace to `target`.
+    fn finish_transfer(&mut self, desktop: &dyn Desktop, source: usize, target: usize) {
+        self.focused_monitor = target;
+        self.apply_layout_on(desktop, source);
+        self.apply_layout_on(desktop, target);
+        self.update_border();
+        self.move_cursor_to_focused();
+    }
+
+    /// Moves a workspace's floating windows from monitor `from` to the
+    /// same relative position on monitor `to`.
+    fn shift_floating(&mut self, ws: &mut Workspace, from: usize, to: usize) {
+        let from = self.monitors[from].work_area;
+        let to = self.monitors[to].work_area;
+        for hwnd in ws.floating().to_vec() {
+            let Some(rect) = ws.floating_rect(hwnd) else {
+                continue;
+            };
+            let rect = shifted(rect, &from, &to);
+            ws.set_floating_rect(hwnd, rect);
+            self.effects.push(Effect::SetRect { hwnd, rect });
+        }
+    }
+}
+
+/// Moves `rect` from work area `from` to the same offset in `to`.
+pub(super) fn shifted(rect: Rect, from: &Rect, to: &Rect) -> Rect {
+    Rect::new(
+        rect.x - from.x + to.x,
+        rect.y - from.y + to.y,
+        rect.width,
+        rect.height,
+    )
+}
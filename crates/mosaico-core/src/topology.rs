@@ -0,0 +1,131 @@
+//! Monitor topology: which monitor lies in each direction of another.
+//!
+//! Built from monitor work areas, so side-by-side and stacked
+//! arrangements (a laptop below an external display) navigate the same
+//! way. Neighbors are scored like windows in [`crate::spatial`]:
+//! monitors sharing perpendicular space win, nearest edge first. A
+//! monitor with no overlapping candidate falls back to the nearest
+//! monitor whose center lies in that direction, so diagonal setups
+//! remain reachable.
+
+use crate::spatial::find_neighbor;
+use crate::{Direction, Rect};
+
+const DIRECTIONS: [Direction; 4] = [
+    Direction::Left,
+    Direction::Right,
+    Direction::Up,
+    Direction::Down,
+];
+
+/// Precomputed neighbors of every monitor in all four directions.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MonitorTopology {
+    /// Per monitor, its neighbor in each of [`DIRECTIONS`].
+    neighbors: Vec<[Option<usize>; 4]>,
+}
+
+impl MonitorTopology {
+    /// Builds the topology from monitor rects, indexed like `rects`.
+    pub fn new(rects: &[Rect]) -> Self {
+        let neighbors = rects
+            .iter()
+            .enumerate()
+            .map(|(idx, rect)| DIRECTIONS.map(|dir| nearest(rects, idx, rect, dir)))
+            .collect();
+        Self { neighbors }
+    }
+
+    /// Returns the monitor next to `idx` in `dir`, if any.
+    pub fn neighbor(&self, idx: usize, dir: Direction) -> Option<usize> {
+        let slot = DIRECTIONS.iter().position(|&d| d == dir)?;
+        self.neighbors.get(idx)?[slot]
+    }
+}
+
+/// Finds the monitor nearest to `rects[idx]` in `dir`.
+fn nearest(rects: &[Rect], idx: usize, rect: &Rect, dir: Direction) -> Option<usize> {
+    let others: Vec<(usize, Rect)> = rects
+        .iter()
+        .copied()
+        .enumerate()
+        .filter(|(i, _)| *i != idx)
+        .collect();
+    find_neighbor(&others, rect, dir).or_else(|| {
+        let (dx, dy) = match dir {
+            Direction::Left => (-1, 0),
+            Direction::Right => (1, 0),
+            Direction::Up => (0, -1),
+            Direction::Down => (0, 1),
+        };
+        others
+            .iter()
+            .filter(|(_, r)| {
+                let ahead =
+                    (r.center_x() - rect.center_x()) * dx + (r.center_y() - rect.center_y()) * dy;
+                ahead > 0
+            })
+            .min_by_key(|(_, r)| {
+                let ddx = i64::from(r.center_x() - rect.center_x());
+                let ddy = i64::from(r.center_y() - rect.center_y());
+                ddx * ddx + ddy * ddy
+            })
+            .map(|(i, _)| *i)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn side_by_side_monitors_link_horizontally() {
+        let topo =
+            MonitorTopology::new(&[Rect::new(0, 0, 1920, 1080), Rect::new(1920, 0, 1920, 1080)]);
+        assert_eq!(topo.neighbor(0, Direction::Right), Some(1));
+        assert_eq!(topo.neighbor(1, Direction::Left), Some(0));
+        assert_eq!(topo.neighbor(0, Direction::Left), None);
+        assert_eq!(topo.neighbor(0, Direction::Up), None);
+        assert_eq!(topo.neighbor(0, Direction::Down), None);
+    }
+
+    #[test]
+    fn stacked_monitors_link_vertically() {
+        // External display above a smaller, centered laptop panel.
+        let topo = MonitorTopology::new(&[
+            Rect::new(0, 0, 2560, 1440),
+            Rect::new(320, 1440, 1920, 1080),
+        ]);
+        assert_eq!(topo.neighbor(0, Direction::Down), Some(1));
+        assert_eq!(topo.neighbor(1, Direction::Up), Some(0));
+        assert_eq!(topo.neighbor(1, Direction::Left), None);
+        assert_eq!(topo.neighbor(0, Direction::Right), None);
+    }
+
+    #[test]
+    fn overlapping_neighbor_beats_nearer_diagonal_one() {
+        // [0][1]
+        //    [2]   monitor 2 is below 1, diagonal to 0.
+        let topo = MonitorTopology::new(&[
+            Rect::new(0, 0, 1920, 1080),
+            Rect::new(1920, 0, 1920, 1080),
+            Rect::new(1920, 1080, 1920, 1080),
+        ]);
+        assert_eq!(topo.neighbor(0, Direction::Right), Some(1));
+        assert_eq!(topo.neighbor(1, Direction::Down), Some(2));
+        assert_eq!(topo.neighbor(2, Direction::Up), Some(1));
+        // Nothing overlaps 0 below, so the diagonal monitor is used.
+        assert_eq!(topo.neighbor(0, Direction::Down), Some(2));
+    }
+
+    #[test]
+    fn picks_nearest_edge_among_overlapping_monitors() {
+        let topo = MonitorTopology::new(&[
+            Rect::new(0, 0, 1920, 1080),
+            Rect::new(3840, 0, 1920, 1080),
+            Rect::new(1920, 0, 1920, 1080),
+        ]);
+        assert_eq!(topo.neighbor(0, Direction::Right), Some(2));
+        assert_eq!(topo.neighbor(1, Direction::Left), Some(2));
+    }
+}
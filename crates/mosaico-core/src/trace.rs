@@ -0,0 +1,238 @@
+//! Recorded window-event traces.
+//!
+//! A trace is a JSON-lines file: one [`TraceEntry`] per line, each
+//! timestamped in milliseconds since the recording started. Traces are
+//! written by `mosaico debug events --record` and replayed headlessly by
+//! `mosaico-sim`, so a bug report from an exotic application can become
+//! a regression test.
+//!
+//! ```text
+//! {"at_ms":0,"kind":"Monitors","monitors":[{"id":1,"work_area":{"x":0,"y":0,"width":1920,"height":1040}}]}
+//! {"at_ms":0,"kind":"Window","hwnd":4096,"class":"Notepad","title":"a.txt","rect":{"x":0,"y":0,"width":800,"height":600}}
+//! {"at_ms":125,"kind":"Event","event":"Created","hwnd":4096}
+//! {"at_ms":900,"kind":"Action","action":"focus-right"}
+//! ```
+
+use serde::{Deserialize, Serialize};
+
+use crate::tiling::MonitorInfo;
+use crate::{Action, Rect, WindowEvent};
+
+/// One timestamped line of a trace.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TraceEntry {
+    /// Milliseconds since the start of the recording.
+    pub at_ms: u64,
+    /// What happened at this point in time.
+    #[serde(flatten)]
+    pub record: TraceRecord,
+}
+
+/// The payload of a [`TraceEntry`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind")]
+pub enum TraceRecord {
+    /// The connected monitors changed (or were captured at start-up).
+    Monitors {
+        /// Every connected monitor, primary first.
+        monitors: Vec<MonitorInfo>,
+    },
+    /// A window's observable state, captured before the events that
+    /// reference it so a replay can reconstruct the window.
+    Window(TraceWindow),
+    /// A window event delivered by the platform event loop.
+    Event(WindowEvent),
+    /// A user action (hotkey or CLI).
+    Action {
+        /// The action, in its CLI string form.
+        action: Action,
+    },
+}
+
+/// A snapshot of a window's properties as the tiling logic sees them.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TraceWindow {
+    /// Window handle.
+    pub hwnd: usize,
+    /// Window class name.
+    pub class: String,
+    /// Window title.
+    pub title: String,
+    /// Full path of the owning executable.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub exe_path: String,
+    /// Outer window rectangle.
+    pub rect: Rect,
+    /// Whether the window is visible.
+    #[serde(default = "default_true")]
+    pub visible: bool,
+    /// Whether the window is minimized.
+    #[serde(default)]
+    pub minimized: bool,
+    /// Whether the window is maximized.
+    #[serde(default)]
+    pub maximized: bool,
+    /// Whether the window is cloaked by DWM.
+    #[serde(default)]
+    pub cloaked: bool,
+    /// Whether the window has the styles of a top-level application
+    /// window that mosaico is allowed to manage.
+    #[serde(default = "default_true")]
+    pub app_window: bool,
+    /// Handle of the owner window, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner: Option<usize>,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+impl TraceEntry {
+    /// Creates an entry at `at_ms` milliseconds.
+    pub fn new(at_ms: u64, record: TraceRecord) -> Self {
+        Self { at_ms, record }
+    }
+
+    /// Serializes the entry as a single JSON line (without the newline).
+    pub fn to_line(&self) -> String {
+        serde_json::to_string(self).expect("trace entries always serialize")
+    }
+}
+
+/// Parses a JSON-lines trace.
+///
+/// Blank lines are skipped. Returns an error naming the first line
+/// that is not a valid entry.
+pub fn parse(content: &str) -> Result<Vec<TraceEntry>, String> {
+    content
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| {
+            serde_json::from_str(line).map_err(|e| format!("trace line {}: {e}", i + 1))
+        })
+        .collect()
+}
+
+/// Reads and parses a trace file.
+pub fn load(path: &std::path::Path) -> Result<Vec<TraceEntry>, String> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| format!("failed to read {}: {e}", path.display()))?;
+    parse(&content)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn window(hwnd: usize) -> TraceWindow {
+        TraceWindow {
+            hwnd,
+            class: "Notepad".into(),
+            title: "a.txt".into(),
+            exe_path: String::new(),
+            rect: Rect::new(0, 0, 800, 600),
+            visible: true,
+            minimized: false,
+            maximized: false,
+            cloaked: false,
+            app_window: true,
+            owner: None,
+        }
+    }
+
+    #[test]
+    fn entries_round_trip_through_lines() {
+        // Arrange
+        let entries = vec![
+            TraceEntry::new(
+                0,
+                TraceRecord::Monitors {
+                    monitors: vec![MonitorInfo {
+                        id: 1,
+                        work_area: Rect::new(0, 0, 1920, 1040),
+                        ..Default::default()
+                    }],
+                },
+            ),
+            TraceEntry::new(0, TraceRecord::Window(window(0x1000))),
+            TraceEntry::new(5, TraceRecord::Event(WindowEvent::Created { hwnd: 0x1000 })),
+            TraceEntry::new(7, TraceRecord::Event(WindowEvent::DisplayChanged)),
+            TraceEntry::new(
+                9,
+                TraceRecord::Action {
+                    action: Action::GoToWorkspace(2.into()),
+                },
+            ),
+        ];
+
+        // Act
+        let text: String = entries.iter().map(|e| e.to_line() + "\n").collect();
+        let parsed = parse(&text).unwrap();
+
+        // Assert
+        assert_eq!(parsed, entries);
+    }
+
+    #[test]
+    fn events_are_flattened_into_the_line() {
+        // Arrange
+        let entry = TraceEntry::new(3, TraceRecord::Event(WindowEvent::Focused { hwnd: 16 }));
+
+        // Act
+        let line = entry.to_line();
+
+        // Assert
+        assert_eq!(
+            line,
+            r#"{"at_ms":3,"kind":"Event","event":"Focused","hwnd":16}"#
+        );
+    }
+
+    #[test]
+    fn window_flags_default_to_a_plain_app_window() {
+        // Arrange
+        let line = r#"{"at_ms":0,"kind":"Window","hwnd":4096,"class":"Notepad","title":"a.txt","rect":{"x":0,"y":0,"width":800,"height":600}}"#;
+
+        // Act
+        let parsed = parse(line).unwrap();
+
+        // Assert
+        assert_eq!(
+            parsed,
+            vec![TraceEntry::new(0, TraceRecord::Window(window(4096)))]
+        );
+    }
+
+    #[test]
+    fn parse_skips_blank_lines() {
+        // Arrange
+        let content = "\n{\"at_ms\":1,\"kind\":\"Event\",\"event\":\"WorkAreaChanged\"}\n\n";
+
+        // Act
+        let parsed = parse(content).unwrap();
+
+        // Assert
+        assert_eq!(
+            parsed,
+            vec![TraceEntry::new(
+                1,
+                TraceRecord::Event(WindowEvent::WorkAreaChanged)
+            )]
+        );
+    }
+
+    #[test]
+    fn parse_reports_the_offending_line() {
+        // Arrange
+        let content =
+            "{\"at_ms\":1,\"kind\":\"Event\",\"event\":\"WorkAreaChanged\"}\n\nnot json\n";
+
+        // Act
+        let err = parse(content).unwrap_err();
+
+        // Assert
+        assert!(err.starts_with("trace line 3:"), "{err}");
+    }
+}
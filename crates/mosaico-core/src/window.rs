@@ -16,6 +16,14 @@ pub trait Window {
     /// Returns the window class name.
     fn class(&self) -> WindowResult<String>;
 
+    /// Returns the full path of the executable that owns the window.
+    fn exe_path(&self) -> WindowResult<String>;
+
+    /// Returns the file name of the owning executable (e.g. `firefox.exe`).
+    fn process_name(&self) -> WindowResult<String> {
+        self.exe_path().map(|path| process_name(&path).to_string())
+    }
+
     /// Returns the window bounding rectangle.
     fn rect(&self) -> WindowResult<Rect>;
 
@@ -34,3 +42,8 @@ pub trait Window {
     /// Returns whether the window is currently visible.
     fn is_visible(&self) -> bool;
 }
+
+/// Returns the file name part of an executable path.
+pub fn process_name(exe_path: &str) -> &str {
+    exe_path.rsplit(['\\', '/']).next().unwrap_or(exe_path)
+}
@@ -1,13 +1,35 @@
+use std::collections::HashMap;
+
 use crate::Rect;
+use crate::action::Direction;
 use crate::layout::{Layout, LayoutKind};
+use crate::split_tree::{Orientation, SplitTree};
 
 /// A workspace manages a set of tiled windows on a single monitor.
 ///
 /// It maintains the window ordering (which determines layout positions)
-/// and delegates positioning to a `Layout` implementation.
+/// and a [`SplitTree`] for the BSP layout, and delegates positioning for
+/// the other layouts to a `Layout` implementation. The handle list is
+/// always the tree's leaf order.
+///
+/// Floating windows belong to the workspace too, so they follow it
+/// when switching, but take no part in the layout.
 pub struct Workspace {
-    /// Ordered list of managed window handles.
+    /// Ordered list of tiled window handles.
     handles: Vec<usize>,
+    /// Floating window handles, in the order they started floating.
+    floating: Vec<usize>,
+    /// Last known rect of every window that floated on this workspace.
+    ///
+    /// Kept while the window is tiled so floating it again restores
+    /// its previous position.
+    float_rects: HashMap<usize, Rect>,
+    /// Split tree used by the BSP layout.
+    tree: SplitTree,
+    /// Size ratio of the master pane in the master/stack layouts.
+    master_ratio: f64,
+    /// Number of windows in the master pane of the master/stack layouts.
+    master_count: usize,
     /// Whether monocle (single-window fullscreen) mode is active.
     monocle: bool,
     /// The window shown fullscreen in monocle mode.
@@ -27,6 +49,11 @@ impl Workspace {
     pub fn new() -> Self {
         Self {
             handles: Vec::new(),
+            floating: Vec::new(),
+            float_rects: HashMap::new(),
+            tree: SplitTree::default(),
+            master_ratio: 0.5,
+            master_count: 1,
             monocle: false,
             monocle_window: None,
             last_focused: None,
@@ -73,8 +100,8 @@ impl Workspace {
     }
 
     /// Returns the active layout kind for this workspace.
-    pub fn layout_kind(&self) -> LayoutKind {
-        self.layout_kind
+    pub fn layout_kind(&self) -> &LayoutKind {
+        &self.layout_kind
     }
 
     /// Sets the active layout kind for this workspace.
@@ -86,61 +113,167 @@ impl Workspace {
     ///
     /// Returns `false` if the window is already managed.
     pub fn add(&mut self, hwnd: usize) -> bool {
-        if self.handles.contains(&hwnd) {
+        if self.is_floating(hwnd) || !self.tree.push(hwnd) {
             return false;
         }
         self.handles.push(hwnd);
         true
     }
 
+    /// Adds a window by splitting `target`, placing it right after.
+    ///
+    /// Falls back to [`Workspace::add`] if `target` is not on this
+    /// workspace. Returns `false` if the window is already managed.
+    pub fn add_after(&mut self, target: usize, hwnd: usize) -> bool {
+        if !self.is_tiled(target) {
+            return self.add(hwnd);
+        }
+        if self.is_floating(hwnd) || !self.tree.insert_after(target, hwnd) {
+            return false;
+        }
+        self.sync_handles();
+        true
+    }
+
     /// Inserts a window at a specific position in the workspace.
     ///
     /// The index is clamped to the current length.
     /// Returns `false` if the window is already managed.
     pub fn insert(&mut self, index: usize, hwnd: usize) -> bool {
-        if self.handles.contains(&hwnd) {
+        if self.is_floating(hwnd) {
             return false;
         }
         let pos = index.min(self.handles.len());
-        self.handles.insert(pos, hwnd);
-        true
+        let inserted = match pos {
+            0 => match self.handles.first() {
+                Some(&first) => self.tree.insert_before(first, hwnd),
+                None => self.tree.push(hwnd),
+            },
+            _ => self.tree.insert_after(self.handles[pos - 1], hwnd),
+        };
+        if inserted {
+            self.sync_handles();
+        }
+        inserted
     }
 
-    /// Removes a window from the workspace.
+    /// Removes a window, tiled or floating, from the workspace.
     ///
     /// Returns `true` if the window was found and removed.
     pub fn remove(&mut self, hwnd: usize) -> bool {
-        if let Some(pos) = self.handles.iter().position(|&h| h == hwnd) {
+        let removed = if let Some(pos) = self.handles.iter().position(|&h| h == hwnd) {
             self.handles.remove(pos);
-            if self.last_focused == Some(hwnd) {
-                self.last_focused = None;
-            }
+            self.tree.remove(hwnd);
+            true
+        } else if let Some(pos) = self.floating.iter().position(|&h| h == hwnd) {
+            self.floating.remove(pos);
             true
         } else {
             false
+        };
+        if removed {
+            self.float_rects.remove(&hwnd);
+            if self.last_focused == Some(hwnd) {
+                self.last_focused = None;
+            }
         }
+        removed
     }
 
-    /// Returns whether the workspace manages the given window.
+    /// Returns whether the workspace manages the given window, tiled or
+    /// floating.
     pub fn contains(&self, hwnd: usize) -> bool {
+        self.is_tiled(hwnd) || self.is_floating(hwnd)
+    }
+
+    /// Returns whether the given window is tiled on this workspace.
+    pub fn is_tiled(&self, hwnd: usize) -> bool {
         self.handles.contains(&hwnd)
     }
 
-    /// Returns the number of managed windows.
+    /// Returns the number of managed windows, tiled and floating.
     pub fn len(&self) -> usize {
-        self.handles.len()
+        self.handles.len() + self.floating.len()
     }
 
     /// Returns whether the workspace has no managed windows.
     pub fn is_empty(&self) -> bool {
-        self.handles.is_empty()
+        self.handles.is_empty() && self.floating.is_empty()
     }
 
-    /// Returns the ordered list of managed window handles.
+    /// Returns the ordered list of tiled window handles.
     pub fn handles(&self) -> &[usize] {
         &self.handles
     }
 
+    /// Returns the floating window handles.
+    pub fn floating(&self) -> &[usize] {
+        &self.floating
+    }
+
+    /// Returns every managed window: tiled ones in layout order, then
+    /// floating ones.
+    pub fn windows(&self) -> Vec<usize> {
+        self.handles.iter().chain(&self.floating).copied().collect()
+    }
+
+    /// Returns whether the given window floats on this workspace.
+    pub fn is_floating(&self, hwnd: usize) -> bool {
+        self.floating.contains(&hwnd)
+    }
+
+    /// Adds a floating window at `rect`.
+    ///
+    /// Returns `false` if the window is already managed.
+    pub fn add_floating(&mut self, hwnd: usize, rect: Rect) -> bool {
+        if self.contains(hwnd) {
+            return false;
+        }
+        self.floating.push(hwnd);
+        self.float_rects.insert(hwnd, rect);
+        true
+    }
+
+    /// Returns the remembered rect of a floating window.
+    pub fn floating_rect(&self, hwnd: usize) -> Option<Rect> {
+        if !self.is_floating(hwnd) {
+            return None;
+        }
+        self.float_rects.get(&hwnd).copied()
+    }
+
+    /// Remembers where a floating window is, e.g. after the user moved it.
+    pub fn set_floating_rect(&mut self, hwnd: usize, rect: Rect) {
+        if self.is_floating(hwnd) {
+            self.float_rects.insert(hwnd, rect);
+        }
+    }
+
+    /// Takes a tiled window out of the layout and floats it.
+    ///
+    /// Returns the rect the window should move to: where it last
+    /// floated on this workspace, or `default`. Returns `None` if the
+    /// window is not tiled here.
+    pub fn float(&mut self, hwnd: usize, default: Rect) -> Option<Rect> {
+        let pos = self.index_of(hwnd)?;
+        self.handles.remove(pos);
+        self.tree.remove(hwnd);
+        self.floating.push(hwnd);
+        Some(*self.float_rects.entry(hwnd).or_insert(default))
+    }
+
+    /// Puts a floating window back at the end of the layout.
+    ///
+    /// Its floating rect is kept for the next time it floats. Returns
+    /// `false` if the window does not float here.
+    pub fn tile(&mut self, hwnd: usize) -> bool {
+        let Some(pos) = self.floating.iter().position(|&h| h == hwnd) else {
+            return false;
+        };
+        self.floating.remove(pos);
+        self.add(hwnd)
+    }
+
     /// Computes the layout for all managed windows in the given work area.
     ///
     /// Returns a list of (handle, rect) pairs.
@@ -151,6 +284,7 @@ impl Workspace {
     /// Swaps two windows by their position indices.
     pub fn swap(&mut self, a: usize, b: usize) {
         if a < self.handles.len() && b < self.handles.len() {
+            self.tree.swap(self.handles[a], self.handles[b]);
             self.handles.swap(a, b);
         }
     }
@@ -159,6 +293,121 @@ impl Workspace {
     pub fn index_of(&self, hwnd: usize) -> Option<usize> {
         self.handles.iter().position(|&h| h == hwnd)
     }
+
+    /// Returns the split tree used by the BSP layout.
+    pub fn tree(&self) -> &SplitTree {
+        &self.tree
+    }
+
+    /// Returns the master pane ratio used by the master/stack layouts.
+    pub fn master_ratio(&self) -> f64 {
+        self.master_ratio
+    }
+
+    /// Returns how many windows share the master pane.
+    pub fn master_count(&self) -> usize {
+        self.master_count
+    }
+
+    /// Sets how many windows share the master pane (at least 1).
+    pub fn set_master_count(&mut self, count: usize) {
+        self.master_count = count.max(1);
+    }
+
+    /// Grows or shrinks the master pane by one window.
+    ///
+    /// The count stays between 1 and the number of tiled windows.
+    /// Returns `false` if it did not change.
+    pub fn adjust_master_count(&mut self, increase: bool) -> bool {
+        if increase && self.master_count < self.handles.len() {
+            self.master_count += 1;
+        } else if !increase && self.master_count > 1 {
+            self.master_count -= 1;
+        } else {
+            return false;
+        }
+        true
+    }
+
+    /// Swaps `hwnd` into the first (master) slot.
+    ///
+    /// If `hwnd` already is the master it trades places with the next
+    /// window instead. Returns `false` if nothing moved.
+    pub fn promote(&mut self, hwnd: usize) -> bool {
+        match self.index_of(hwnd) {
+            Some(0) if self.handles.len() > 1 => self.swap(0, 1),
+            Some(i) if i > 0 => self.swap(0, i),
+            _ => return false,
+        }
+        true
+    }
+
+    /// Resets the master pane and every split in the tree to `ratio`.
+    pub fn reset_ratios(&mut self, ratio: f64) {
+        self.master_ratio = ratio;
+        self.tree.set_ratio(ratio);
+    }
+
+    /// Moves the divider next to `hwnd` in `dir` by `step`.
+    ///
+    /// BSP shifts the nearest split of matching orientation; the master
+    /// layouts grow the master pane along their main axis (Right, or
+    /// Down for the horizontal stack) and shrink it the other way. The
+    /// equal-share and custom layouts have nothing to resize. Ratios
+    /// live on the workspace, so they survive layout cycling. Returns
+    /// `false` if nothing could be resized.
+    pub fn resize(&mut self, hwnd: usize, dir: Direction, step: f64) -> bool {
+        if !self.is_tiled(hwnd) {
+            return false;
+        }
+        let delta = match dir {
+            Direction::Left | Direction::Up => -step,
+            Direction::Right | Direction::Down => step,
+        };
+        match self.layout_kind {
+            LayoutKind::Bsp => {
+                let orientation = match dir {
+                    Direction::Left | Direction::Right => Orientation::Horizontal,
+                    Direction::Up | Direction::Down => Orientation::Vertical,
+                };
+                self.tree.resize(hwnd, orientation, delta)
+            }
+            LayoutKind::VerticalStack | LayoutKind::ThreeColumn => match dir {
+                Direction::Left | Direction::Right => {
+                    self.master_ratio = (self.master_ratio + delta).clamp(0.1, 0.9);
+                    true
+                }
+                Direction::Up | Direction::Down => false,
+            },
+            LayoutKind::HorizontalStack => match dir {
+                Direction::Up | Direction::Down => {
+                    self.master_ratio = (self.master_ratio + delta).clamp(0.1, 0.9);
+                    true
+                }
+                Direction::Left | Direction::Right => false,
+            },
+            LayoutKind::Grid | LayoutKind::Columns | LayoutKind::Rows | LayoutKind::Custom(_) => {
+                false
+            }
+        }
+    }
+
+    /// Rotates the split tree 90 degrees clockwise.
+    pub fn rotate(&mut self) {
+        self.tree.rotate();
+        self.sync_handles();
+    }
+
+    /// Mirrors every split with the given orientation.
+    pub fn flip(&mut self, orientation: Orientation) {
+        self.tree.flip(orientation);
+        self.sync_handles();
+    }
+
+    /// Re-derives the handle order from the tree's leaves.
+    fn sync_handles(&mut self) {
+        self.handles = self.tree.leaves();
+    }
 }
 
 impl Default for Workspace {
@@ -206,6 +455,139 @@ mod tests {
         // Index clamped to len
         assert!(ws.insert(100, 4));
         assert_eq!(ws.handles(), &[3, 1, 2, 4]);
+        assert_eq!(ws.tree().leaves(), vec![3, 1, 2, 4]);
+    }
+
+    #[test]
+    fn add_after_splits_the_target_window() {
+        // Arrange
+        let mut ws = Workspace::new();
+        ws.add(1);
+        ws.add(2);
+
+        // Act
+        assert!(ws.add_after(1, 3));
+        assert!(ws.add_after(99, 4));
+
+        // Assert
+        assert_eq!(ws.handles(), &[1, 3, 2, 4]);
+        assert_eq!(ws.tree().leaves(), vec![1, 3, 2, 4]);
+    }
+
+    #[test]
+    fn resize_ratios_survive_layout_cycling() {
+        // Arrange
+        let mut ws = Workspace::new();
+        ws.add(1);
+        ws.add(2);
+        let area = Rect::new(0, 0, 1000, 1000);
+
+        // Act
+        assert!(ws.resize(1, Direction::Right, 0.1));
+        ws.set_layout_kind(LayoutKind::VerticalStack);
+        assert!(ws.resize(1, Direction::Left, 0.2));
+        assert!(!ws.resize(1, Direction::Up, 0.2));
+        ws.set_layout_kind(LayoutKind::Bsp);
+
+        // Assert
+        assert_eq!(ws.tree().layout(&area, 0)[0].1, Rect::new(0, 0, 600, 1000));
+        assert!((ws.master_ratio() - 0.3).abs() < 1e-9);
+    }
+
+    #[test]
+    fn master_count_stays_between_one_and_window_count() {
+        // Arrange
+        let mut ws = Workspace::new();
+        ws.add(1);
+        ws.add(2);
+
+        // Act
+        let first = ws.adjust_master_count(true);
+        let second = ws.adjust_master_count(true);
+        let shrink = ws.adjust_master_count(false);
+        let floor = ws.adjust_master_count(false);
+
+        // Assert
+        assert!(first && !second && shrink && !floor);
+        assert_eq!(ws.master_count(), 1);
+    }
+
+    #[test]
+    fn promote_swaps_into_master_slot() {
+        // Arrange
+        let mut ws = Workspace::new();
+        ws.add(1);
+        ws.add(2);
+        ws.add(3);
+
+        // Act
+        assert!(ws.promote(3));
+        let after_promote = ws.handles().to_vec();
+        assert!(ws.promote(3));
+
+        // Assert
+        assert_eq!(after_promote, vec![3, 2, 1]);
+        assert_eq!(ws.handles(), &[2, 3, 1]);
+        assert_eq!(ws.tree().leaves(), vec![2, 3, 1]);
+    }
+
+    #[test]
+    fn floating_windows_stay_out_of_the_layout() {
+        // Arrange
+        let mut ws = Workspace::new();
+        ws.add(1);
+        ws.add(2);
+        let rect = Rect::new(100, 100, 400, 300);
+
+        // Act
+        assert_eq!(ws.float(2, rect), Some(rect));
+        assert!(ws.add_floating(3, rect));
+        assert!(!ws.add(3));
+
+        // Assert
+        assert_eq!(ws.handles(), &[1]);
+        assert_eq!(ws.tree().leaves(), vec![1]);
+        assert_eq!(ws.windows(), vec![1, 2, 3]);
+        assert!(ws.contains(2) && !ws.is_tiled(2));
+        assert_eq!(ws.len(), 3);
+    }
+
+    #[test]
+    fn floating_rect_is_remembered_across_tiling() {
+        // Arrange
+        let mut ws = Workspace::new();
+        ws.add(1);
+        let first = Rect::new(0, 0, 400, 300);
+        let moved = Rect::new(50, 60, 400, 300);
+
+        // Act
+        ws.float(1, first);
+        ws.set_floating_rect(1, moved);
+        assert!(ws.tile(1));
+        let refloated = ws.float(1, first);
+
+        // Assert
+        assert_eq!(refloated, Some(moved));
+        assert!(ws.remove(1));
+        ws.add(1);
+        assert_eq!(ws.float(1, first), Some(first));
+    }
+
+    #[test]
+    fn remove_and_swap_keep_tree_in_sync() {
+        // Arrange
+        let mut ws = Workspace::new();
+        ws.add(1);
+        ws.add(2);
+        ws.add(3);
+
+        // Act
+        ws.swap(0, 2);
+        ws.remove(2);
+
+        // Assert
+        assert_eq!(ws.handles(), &[3, 1]);
+        assert_eq!(ws.tree().leaves(), vec![3, 1]);
     }
 
     #[test]
@@ -0,0 +1,517 @@
+use std::cell::{Cell, RefCell};
+use std::collections::BTreeMap;
+use std::time::{Duration, Instant};
+
+use mosaico_core::config::{CornerStyle, HidingBehaviour};
+use mosaico_core::tiling::MonitorInfo;
+use mosaico_core::{Desktop, Effect, Rect, WindowEvent};
+
+use crate::SimWindow;
+
+/// First handle handed out by [`SimDesktop::spawn`].
+const FIRST_HWND: usize = 0x1000;
+
+/// Size of a freshly spawned window before the tiler positions it.
+const SPAWN_SIZE: (i32, i32) = (800, 600);
+
+/// Everything the simulator knows about a single window.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WindowState {
+    /// Window class name.
+    pub class: String,
+    /// Window title.
+    pub title: String,
+    /// Full path of the owning executable (empty if unknown).
+    pub exe_path: String,
+    /// Current position and size.
+    pub rect: Rect,
+    /// Whether the window is shown (`SW_HIDE` clears this).
+    pub visible: bool,
+    /// Whether the window is minimized.
+    pub minimized: bool,
+    /// Whether the window is maximized.
+    pub maximized: bool,
+    /// Whether the window is cloaked (hidden without firing events).
+    pub cloaked: bool,
+    /// Whether this looks like a real application window.
+    ///
+    /// `false` models tool windows and captionless popups.
+    pub app_window: bool,
+    /// Owner window, for dialogs.
+    pub owner: Option<usize>,
+    /// Corner style applied by the tiler, if any.
+    pub corners: Option<CornerStyle>,
+    /// Number of times the window was asked to repaint.
+    pub repaints: u32,
+}
+
+impl WindowState {
+    /// Creates a visible, unowned application window.
+    pub fn new(class: &str, title: &str, rect: Rect) -> Self {
+        Self {
+            class: class.to_string(),
+            title: title.to_string(),
+            exe_path: String::new(),
+            rect,
+            visible: true,
+            minimized: false,
+            maximized: false,
+            cloaked: false,
+            app_window: true,
+            owner: None,
+            corners: None,
+            repaints: 0,
+        }
+    }
+}
+
+/// Where the focus border is currently drawn.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BorderState {
+    /// Window the border surrounds.
+    pub hwnd: usize,
+    /// Rectangle the border was drawn at.
+    pub rect: Rect,
+    /// Whether the monocle color is in use.
+    pub monocle: bool,
+}
+
+/// An in-memory desktop: windows, monitors, focus, cursor and border.
+///
+/// Implements [`Desktop`] so it can be handed straight to a
+/// [`mosaico_core::TilingState`], and executes the returned [`Effect`]s
+/// via [`SimDesktop::apply`]. Like the real OS, applying an effect may
+/// fire follow-up [`WindowEvent`]s, which are returned to the caller.
+///
+/// The model uses interior mutability because windows are mutated
+/// through shared handles, just like Win32 `HWND`s.
+pub struct SimDesktop {
+    monitors: RefCell<Vec<MonitorInfo>>,
+    windows: RefCell<BTreeMap<usize, WindowState>>,
+    next_hwnd: Cell<usize>,
+    foreground: Cell<Option<usize>>,
+    cursor: Cell<(i32, i32)>,
+    border: Cell<Option<BorderState>>,
+    now: Cell<Instant>,
+}
+
+impl SimDesktop {
+    /// Creates an empty desktop with the given monitors.
+    ///
+    /// The first monitor is the primary one.
+    pub fn new(monitors: Vec<MonitorInfo>) -> Self {
+        Self {
+            monitors: RefCell::new(monitors),
+            windows: RefCell::new(BTreeMap::new()),
+            next_hwnd: Cell::new(FIRST_HWND),
+            foreground: Cell::new(None),
+            cursor: Cell::new((0, 0)),
+            border: Cell::new(None),
+            now: Cell::new(Instant::now()),
+        }
+    }
+
+    /// Creates a desktop with `count` 1920x1080 monitors side by side.
+    pub fn with_monitors(count: usize) -> Self {
+        let monitors = (0..count)
+            .map(|i| {
+                let rect = Rect::new(i as i32 * 1920, 0, 1920, 1080);
+                MonitorInfo {
+                    id: i + 1,
+                    work_area: rect,
+                    name: format!(r"\\.\DISPLAY{}", i + 1),
+                    bounds: rect,
+                }
+            })
+            .collect();
+        Self::new(monitors)
+    }
+
+    /// Creates a window on the primary monitor and returns its handle.
+    ///
+    /// No event is fired; see [`crate::Simulation::open`] for that.
+    pub fn spawn(&self, class: &str, title: &str) -> usize {
+        self.spawn_on(0, class, title)
+    }
+
+    /// Creates a window on the monitor at `monitor_idx`.
+    pub fn spawn_on(&self, monitor_idx: usize, class: &str, title: &str) -> usize {
+        let (x, y) = self
+            .monitors
+            .borrow()
+            .get(monitor_idx)
+            .map_or((0, 0), |m| (m.work_area.x, m.work_area.y));
+        let rect = Rect::new(x, y, SPAWN_SIZE.0, SPAWN_SIZE.1);
+        self.insert(WindowState::new(class, title, rect))
+    }
+
+    /// Adds a fully specified window and returns its handle.
+    pub fn insert(&self, state: WindowState) -> usize {
+        let hwnd = self.next_hwnd.get();
+        self.next_hwnd.set(hwnd + 0x10);
+        self.windows.borrow_mut().insert(hwnd, state);
+        hwnd
+    }
+
+    /// Adds or replaces the window with a specific handle.
+    ///
+    /// Used to rebuild windows from a recorded trace. Later calls to
+    /// [`SimDesktop::insert`] never reuse `hwnd`.
+    pub fn insert_at(&self, hwnd: usize, state: WindowState) {
+        if hwnd >= self.next_hwnd.get() {
+            self.next_hwnd.set(hwnd + 0x10);
+        }
+        self.windows.borrow_mut().insert(hwnd, state);
+    }
+
+    /// Removes a window. Returns `false` if it did not exist.
+    pub fn remove(&self, hwnd: usize) -> bool {
+        if self.foreground.get() == Some(hwnd) {
+            self.foreground.set(None);
+        }
+        self.windows.borrow_mut().remove(&hwnd).is_some()
+    }
+
+    /// Returns a snapshot of a window's state.
+    pub fn window(&self, hwnd: usize) -> Option<WindowState> {
+        self.windows.borrow().get(&hwnd).cloned()
+    }
+
+    /// Mutates a window in place. Returns `false` if it did not exist.
+    pub fn update(&self, hwnd: usize, f: impl FnOnce(&mut WindowState)) -> bool {
+        match self.windows.borrow_mut().get_mut(&hwnd) {
+            Some(w) => {
+                f(w);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Returns a [`mosaico_core::Window`] handle for `hwnd`.
+    pub fn handle(&self, hwnd: usize) -> SimWindow<'_> {
+        SimWindow::new(self, hwnd)
+    }
+
+    /// Returns every window handle in creation order.
+    pub fn handles(&self) -> Vec<usize> {
+        self.windows.borrow().keys().copied().collect()
+    }
+
+    /// Returns the connected monitors.
+    pub fn monitors(&self) -> Vec<MonitorInfo> {
+        self.monitors.borrow().clone()
+    }
+
+    /// Replaces the connected monitors.
+    pub fn set_monitors(&self, monitors: Vec<MonitorInfo>) {
+        *self.monitors.borrow_mut() = monitors;
+    }
+
+    /// Returns the foreground window.
+    pub fn foreground(&self) -> Option<usize> {
+        self.foreground.get()
+    }
+
+    /// Makes `hwnd` the foreground window.
+    pub fn set_foreground(&self, hwnd: Option<usize>) {
+        self.foreground.set(hwnd);
+    }
+
+    /// Returns the cursor position.
+    pub fn cursor(&self) -> (i32, i32) {
+        self.cursor.get()
+    }
+
+    /// Returns where the focus border is drawn, or `None` if hidden.
+    pub fn border(&self) -> Option<BorderState> {
+        self.border.get()
+    }
+
+    /// Advances the simulated clock.
+    pub fn advance(&self, by: Duration) {
+        self.now.set(self.now.get() + by);
+    }
+
+    /// Executes an effect against the model.
+    ///
+    /// Returns the events the OS would fire in response, in order.
+    pub fn apply(&self, effect: &Effect) -> Vec<WindowEvent> {
+        match *effect {
+            Effect::SetRect { hwnd, rect } => {
+                let Some(w) = self.window(hwnd) else {
+                    return Vec::new();
+                };
+                // Same short-circuit as the Win32 executor.
+                if !w.maximized && w.rect == rect {
+                    return Vec::new();
+                }
+                self.update(hwnd, |w| {
+                    w.rect = rect;
+                    w.maximized = false;
+                    w.repaints += 1;
+                });
+                vec![WindowEvent::LocationChanged { hwnd }]
+            }
+            Effect::Hide { hwnd, hiding } => match hiding {
+                HidingBehaviour::Cloak => {
+                    self.update(hwnd, |w| w.cloaked = true);
+                    Vec::new()
+                }
+                HidingBehaviour::Hide => self.fire_if(hwnd, WindowEvent::Hidden { hwnd }, |w| {
+                    let changed = w.visible;
+                    w.visible = false;
+                    changed
+                }),
+                HidingBehaviour::Minimize => self.minimize(hwnd),
+            },
+            Effect::Show { hwnd, hiding } => match hiding {
+                HidingBehaviour::Cloak => {
+                    self.update(hwnd, |w| w.cloaked = false);
+                    Vec::new()
+                }
+                HidingBehaviour::Hide => self.fire_if(hwnd, WindowEvent::Created { hwnd }, |w| {
+                    let changed = !w.visible;
+                    w.visible = true;
+                    changed
+                }),
+                HidingBehaviour::Minimize => {
+                    self.fire_if(hwnd, WindowEvent::Restored { hwnd }, |w| {
+                        let changed = w.minimized;
+                        w.minimized = false;
+                        changed
+                    })
+                }
+            },
+            Effect::Restore { hwnd } => {
+                self.update(hwnd, |w| {
+                    w.cloaked = false;
+                    w.visible = true;
+                });
+                Vec::new()
+            }
+            Effect::Focus { hwnd } => {
+                if self.window(hwnd).is_none() || self.foreground.get() == Some(hwnd) {
+                    return Vec::new();
+                }
+                self.foreground.set(Some(hwnd));
+                vec![WindowEvent::Focused { hwnd }]
+            }
+            Effect::MoveCursor { hwnd } => {
+                if let Some(w) = self.window(hwnd) {
+                    self.cursor.set((w.rect.center_x(), w.rect.center_y()));
+                }
+                Vec::new()
+            }
+            Effect::ShowBorder { hwnd, monocle } => {
+                let border = self
+                    .window(hwnd)
+                    .filter(|w| !w.maximized && !w.minimized)
+                    .map(|w| BorderState {
+                        hwnd,
+                        rect: w.rect,
+                        monocle,
+                    });
+                self.border.set(border);
+                Vec::new()
+            }
+            Effect::HideBorder => {
+                self.border.set(None);
+                Vec::new()
+            }
+            Effect::Close { hwnd } => {
+                if self.remove(hwnd) {
+                    vec![WindowEvent::Destroyed { hwnd }]
+                } else {
+                    Vec::new()
+                }
+            }
+            Effect::Minimize { hwnd } => self.minimize(hwnd),
+            Effect::SetCorners { hwnd, style } => {
+                self.update(hwnd, |w| w.corners = Some(style));
+                Vec::new()
+            }
+            Effect::ResetCorners { hwnd } => {
+                self.update(hwnd, |w| w.corners = None);
+                Vec::new()
+            }
+        }
+    }
+
+    /// Minimizes a window, firing `Minimized` if it was not already.
+    fn minimize(&self, hwnd: usize) -> Vec<WindowEvent> {
+        if self.foreground.get() == Some(hwnd) {
+            self.foreground.set(None);
+        }
+        self.fire_if(hwnd, WindowEvent::Minimized { hwnd }, |w| {
+            let changed = !w.minimized;
+            w.minimized = true;
+            changed
+        })
+    }
+
+    /// Applies `change` and returns `event` if it reported a change.
+    fn fire_if(
+        &self,
+        hwnd: usize,
+        event: WindowEvent,
+        change: impl FnOnce(&mut WindowState) -> bool,
+    ) -> Vec<WindowEvent> {
+        let mut changed = false;
+        self.update(hwnd, |w| changed = change(w));
+        if changed { vec![event] } else { Vec::new() }
+    }
+}
+
+impl Desktop for SimDesktop {
+    fn is_manageable(&self, hwnd: usize) -> bool {
+        self.window(hwnd)
+            .is_some_and(|w| w.visible && w.app_window && w.owner.is_none() && !w.cloaked)
+    }
+
+    fn class(&self, hwnd: usize) -> String {
+        self.window(hwnd).map(|w| w.class).unwrap_or_default()
+    }
+
+    fn title(&self, hwnd: usize) -> String {
+        self.window(hwnd).map(|w| w.title).unwrap_or_default()
+    }
+
+    fn exe_path(&self, hwnd: usize) -> String {
+        self.window(hwnd).map(|w| w.exe_path).unwrap_or_default()
+    }
+
+    fn is_visible(&self, hwnd: usize) -> bool {
+        self.window(hwnd).is_some_and(|w| w.visible)
+    }
+
+    fn is_minimized(&self, hwnd: usize) -> bool {
+        self.window(hwnd).is_some_and(|w| w.minimized)
+    }
+
+    fn is_maximized(&self, hwnd: usize) -> bool {
+        self.window(hwnd).is_some_and(|w| w.maximized)
+    }
+
+    fn rect(&self, hwnd: usize) -> Option<Rect> {
+        self.window(hwnd).map(|w| w.rect)
+    }
+
+    /// Returns the monitor containing the window's center, falling back
+    /// to the primary monitor like `MONITOR_DEFAULTTOPRIMARY`.
+    fn monitor_id(&self, hwnd: usize) -> usize {
+        let monitors = self.monitors.borrow();
+        let primary = monitors.first().map_or(0, |m| m.id);
+        let Some(w) = self.window(hwnd) else {
+            return primary;
+        };
+        let (cx, cy) = (w.rect.center_x(), w.rect.center_y());
+        monitors
+            .iter()
+            .find(|m| {
+                let a = m.work_area;
+                cx >= a.x && cx < a.x + a.width && cy >= a.y && cy < a.y + a.height
+            })
+            .map_or(primary, |m| m.id)
+    }
+
+    fn owner(&self, hwnd: usize) -> Option<usize> {
+        self.window(hwnd).and_then(|w| w.owner)
+    }
+
+    fn now(&self) -> Instant {
+        self.now.get()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn monitor_id_follows_window_center() {
+        // Arrange
+        let desktop = SimDesktop::with_monitors(2);
+        let hwnd = desktop.spawn("Notepad", "a.txt");
+
+        // Act
+        desktop.update(hwnd, |w| w.rect = Rect::new(1800, 0, 400, 300));
+
+        // Assert
+        assert_eq!(desktop.monitor_id(hwnd), 2);
+    }
+
+    #[test]
+    fn monitor_id_falls_back_to_primary() {
+        let desktop = SimDesktop::with_monitors(2);
+        let hwnd = desktop.spawn("Notepad", "a.txt");
+
+        desktop.update(hwnd, |w| w.rect = Rect::new(-5000, -5000, 100, 100));
+
+        assert_eq!(desktop.monitor_id(hwnd), 1);
+    }
+
+    #[test]
+    fn set_rect_skips_unchanged_window() {
+        let desktop = SimDesktop::with_monitors(1);
+        let hwnd = desktop.spawn("Notepad", "a.txt");
+        let rect = Rect::new(0, 0, 960, 1080);
+
+        let first = desktop.apply(&Effect::SetRect { hwnd, rect });
+        let second = desktop.apply(&Effect::SetRect { hwnd, rect });
+
+        assert_eq!(first, vec![WindowEvent::LocationChanged { hwnd }]);
+        assert!(second.is_empty());
+        assert_eq!(desktop.window(hwnd).map(|w| w.repaints), Some(1));
+    }
+
+    #[test]
+    fn hiding_strategies_fire_platform_events() {
+        let desktop = SimDesktop::with_monitors(1);
+        let hwnd = desktop.spawn("Notepad", "a.txt");
+
+        let cloak = desktop.apply(&Effect::Hide {
+            hwnd,
+            hiding: HidingBehaviour::Cloak,
+        });
+        let hide = desktop.apply(&Effect::Hide {
+            hwnd,
+            hiding: HidingBehaviour::Hide,
+        });
+        let show = desktop.apply(&Effect::Show {
+            hwnd,
+            hiding: HidingBehaviour::Hide,
+        });
+
+        assert!(cloak.is_empty());
+        assert_eq!(hide, vec![WindowEvent::Hidden { hwnd }]);
+        assert_eq!(show, vec![WindowEvent::Created { hwnd }]);
+    }
+
+    #[test]
+    fn close_removes_window_and_fires_destroyed() {
+        let desktop = SimDesktop::with_monitors(1);
+        let hwnd = desktop.spawn("Notepad", "a.txt");
+        desktop.set_foreground(Some(hwnd));
+
+        let events = desktop.apply(&Effect::Close { hwnd });
+
+        assert_eq!(events, vec![WindowEvent::Destroyed { hwnd }]);
+        assert!(desktop.window(hwnd).is_none());
+        assert_eq!(desktop.foreground(), None);
+    }
+
+    #[test]
+    fn border_is_hidden_for_maximized_window() {
+        let desktop = SimDesktop::with_monitors(1);
+        let hwnd = desktop.spawn("Notepad", "a.txt");
+        desktop.update(hwnd, |w| w.maximized = true);
+
+        desktop.apply(&Effect::ShowBorder {
+            hwnd,
+            monocle: false,
+        });
+
+        assert_eq!(desktop.border(), None);
+    }
+}
@@ -0,0 +1,30 @@
+#![deny(missing_docs)]
+
+//! Headless simulated desktop for deterministic Mosaico testing.
+//!
+//! [`SimDesktop`] models windows and monitors in memory and implements
+//! the same queries and effects the Win32 backend provides.
+//! [`Simulation`] drives a [`mosaico_core::TilingState`] against it the
+//! way the daemon loop does, so tiling behavior can be exercised
+//! end-to-end on any platform. [`replay()`] feeds a recorded trace
+//! through the same machinery.
+
+/// In-memory desktop model: windows, monitors, focus, cursor and border.
+pub mod desktop;
+/// Replay of recorded window-event traces.
+pub mod replay;
+/// Scripted event streams.
+pub mod script;
+/// Daemon-like driver that wires a `TilingState` to a `SimDesktop`.
+pub mod simulation;
+/// Serializable layout snapshots for assertions.
+pub mod snapshot;
+/// `mosaico_core::Window` implementation for simulated windows.
+pub mod window;
+
+pub use desktop::{BorderState, SimDesktop, WindowState};
+pub use replay::replay;
+pub use script::Step;
+pub use simulation::Simulation;
+pub use snapshot::{LayoutSnapshot, MonitorLayout, TiledWindow};
+pub use window::SimWindow;
@@ -0,0 +1,91 @@
+use std::time::Duration;
+
+use mosaico_core::WindowEvent;
+use mosaico_core::config::{Config, WindowRule};
+use mosaico_core::trace::{TraceEntry, TraceRecord, TraceWindow};
+
+use crate::{SimDesktop, Simulation, WindowState};
+
+/// Replays a recorded trace and returns the simulation in its final state.
+///
+/// Entries before the first event or action describe the desktop at the
+/// moment recording started: the last `Monitors` entry becomes the
+/// monitor layout (one 1920x1080 monitor if absent) and every `Window`
+/// entry a pre-existing window, adopted just like on daemon start-up.
+/// The rest of the trace is delivered in order, with the simulated clock
+/// advanced by the recorded gaps.
+///
+/// Follow-up events are disabled because the trace already holds the
+/// events the OS fired in response to mosaico's own window moves.
+pub fn replay(entries: &[TraceEntry], config: &Config, rules: Vec<WindowRule>) -> Simulation {
+    let split = entries
+        .iter()
+        .position(|e| matches!(e.record, TraceRecord::Event(_) | TraceRecord::Action { .. }))
+        .unwrap_or(entries.len());
+    let (setup, rest) = entries.split_at(split);
+
+    let monitors = setup.iter().rev().find_map(|e| match &e.record {
+        TraceRecord::Monitors { monitors } => Some(monitors.clone()),
+        _ => None,
+    });
+    let desktop = match monitors {
+        Some(monitors) => SimDesktop::new(monitors),
+        None => SimDesktop::with_monitors(1),
+    };
+    for entry in setup {
+        if let TraceRecord::Window(window) = &entry.record {
+            place(&desktop, window);
+        }
+    }
+
+    let mut sim = Simulation::start(desktop, config, rules);
+    sim.set_feedback(false);
+
+    let mut last_ms = setup.last().map_or(0, |e| e.at_ms);
+    for entry in rest {
+        sim.desktop()
+            .advance(Duration::from_millis(entry.at_ms.saturating_sub(last_ms)));
+        last_ms = last_ms.max(entry.at_ms);
+        apply(&mut sim, &entry.record);
+    }
+    sim
+}
+
+/// Feeds one trace record into the simulation.
+fn apply(sim: &mut Simulation, record: &TraceRecord) {
+    match record {
+        TraceRecord::Monitors { monitors } => sim.desktop().set_monitors(monitors.clone()),
+        TraceRecord::Window(window) => place(sim.desktop(), window),
+        TraceRecord::Event(event) => {
+            // The recorder cannot capture a destroyed window's state.
+            if let WindowEvent::Destroyed { hwnd } = event {
+                sim.desktop().remove(*hwnd);
+            }
+            sim.dispatch(event.clone());
+        }
+        TraceRecord::Action { action } => sim.action(action),
+    }
+}
+
+/// Creates or updates a window from its recorded state.
+///
+/// Updating keeps simulator-only state such as applied corners.
+fn place(desktop: &SimDesktop, window: &TraceWindow) {
+    let copy = |w: &mut WindowState| {
+        w.class.clone_from(&window.class);
+        w.title.clone_from(&window.title);
+        w.exe_path.clone_from(&window.exe_path);
+        w.rect = window.rect;
+        w.visible = window.visible;
+        w.minimized = window.minimized;
+        w.maximized = window.maximized;
+        w.cloaked = window.cloaked;
+        w.app_window = window.app_window;
+        w.owner = window.owner;
+    };
+    if !desktop.update(window.hwnd, copy) {
+        let mut state = WindowState::new(&window.class, &window.title, window.rect);
+        copy(&mut state);
+        desktop.insert_at(window.hwnd, state);
+    }
+}
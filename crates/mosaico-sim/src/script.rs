@@ -0,0 +1,30 @@
+use std::time::Duration;
+
+use mosaico_core::{Action, WindowEvent};
+
+/// One entry in a scripted input stream for [`crate::Simulation::run`].
+///
+/// Scripts replay exactly what the daemon would receive: raw window
+/// events from the event loop, actions from hotkeys or the CLI, and the
+/// passage of time between them.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Step {
+    /// Deliver a window event, as the platform event loop would.
+    Event(WindowEvent),
+    /// Execute a user action, as a hotkey or `mosaico action` would.
+    Action(Action),
+    /// Advance the simulated clock.
+    Wait(Duration),
+}
+
+impl From<WindowEvent> for Step {
+    fn from(event: WindowEvent) -> Self {
+        Self::Event(event)
+    }
+}
+
+impl From<Action> for Step {
+    fn from(action: Action) -> Self {
+        Self::Action(action)
+    }
+}
@@ -0,0 +1,305 @@
+use std::collections::VecDeque;
+
+use mosaico_core::config::{Config, WindowRule};
+use mosaico_core::tiling::MonitorInfo;
+use mosaico_core::{Action, Effect, LayoutDefinition, Rect, TilingState, WindowEvent};
+
+use crate::{SimDesktop, Step};
+
+/// Upper bound on events processed by a single [`Simulation::settle`].
+///
+/// Effects can fire follow-up events which produce more effects. A real
+/// feedback loop would hang the daemon; here it fails the test instead.
+const MAX_SETTLE_EVENTS: usize = 10_000;
+
+/// Drives a [`TilingState`] against a [`SimDesktop`] like the daemon loop.
+///
+/// Every input is processed to completion: effects are applied to the
+/// desktop, the events they fire are fed back in, and so on until the
+/// desktop is quiet. Results are therefore fully deterministic.
+pub struct Simulation {
+    desktop: SimDesktop,
+    state: TilingState,
+    queue: VecDeque<WindowEvent>,
+    bar_height: i32,
+    log: Vec<Effect>,
+    feedback: bool,
+}
+
+impl Simulation {
+    /// Starts tiling on `desktop`, adopting the windows already on it.
+    pub fn start(desktop: SimDesktop, config: &Config, rules: Vec<WindowRule>) -> Self {
+        let state = TilingState::new(
+            &config.layout,
+            &config.workspaces,
+            &config.monitor,
+            rules,
+            config.borders.clone(),
+            config.mouse.follows_focus,
+            desktop.monitors(),
+        );
+        let mut sim = Self {
+            desktop,
+            state,
+            queue: VecDeque::new(),
+            bar_height: 0,
+            log: Vec::new(),
+            feedback: true,
+        };
+        let handles = sim.desktop.handles();
+        let effects = sim.state.adopt_existing(&sim.desktop, &handles);
+        sim.execute(effects);
+        sim.settle();
+        sim
+    }
+
+    /// Returns the simulated desktop.
+    pub fn desktop(&self) -> &SimDesktop {
+        &self.desktop
+    }
+
+    /// Returns the tiling state.
+    pub fn state(&self) -> &TilingState {
+        &self.state
+    }
+
+    /// Drains the log of effects applied so far.
+    pub fn take_effects(&mut self) -> Vec<Effect> {
+        std::mem::take(&mut self.log)
+    }
+
+    /// Returns the current rectangle of a window.
+    pub fn rect(&self, hwnd: usize) -> Option<Rect> {
+        self.desktop.window(hwnd).map(|w| w.rect)
+    }
+
+    /// Sets whether events fired by applied effects are fed back in.
+    ///
+    /// Enabled by default. Replays disable it because a recorded trace
+    /// already contains the events the OS fired in response.
+    pub fn set_feedback(&mut self, enabled: bool) {
+        self.feedback = enabled;
+    }
+
+    /// Delivers a window event and processes everything it triggers.
+    pub fn dispatch(&mut self, event: WindowEvent) {
+        self.queue.push_back(event);
+        self.settle();
+    }
+
+    /// Executes a user action and processes everything it triggers.
+    pub fn action(&mut self, action: &Action) {
+        // TogglePause only affects hotkey delivery in the daemon.
+        if *action == Action::TogglePause {
+            return;
+        }
+        let effects = self.state.handle_action(&self.desktop, action);
+        self.execute(effects);
+        self.settle();
+    }
+
+    /// Runs a scripted input stream.
+    pub fn run(&mut self, steps: impl IntoIterator<Item = Step>) {
+        for step in steps {
+            match step {
+                Step::Event(event) => self.dispatch(event),
+                Step::Action(action) => self.action(&action),
+                Step::Wait(duration) => self.desktop.advance(duration),
+            }
+        }
+    }
+
+    /// Reserves `height` pixels at the top of every monitor for a bar.
+    pub fn set_bar_height(&mut self, height: i32) {
+        self.bar_height = height;
+        let monitors = self.desktop.monitors();
+        let effects = self
+            .state
+            .reset_and_adjust_work_areas(&self.desktop, &monitors, height, &[]);
+        self.execute(effects);
+        self.settle();
+    }
+
+    /// Applies a reloaded configuration.
+    pub fn reload_config(&mut self, config: &Config) {
+        let effects = self.state.reload_config(&self.desktop, config);
+        self.execute(effects);
+        self.settle();
+    }
+
+    /// Applies reloaded window rules.
+    pub fn reload_rules(&mut self, rules: Vec<WindowRule>) {
+        let effects = self.state.reload_rules(&self.desktop, rules);
+        self.execute(effects);
+        self.settle();
+    }
+
+    /// Applies reloaded custom layouts.
+    pub fn reload_layouts(&mut self, layouts: Vec<LayoutDefinition>) {
+        let effects = self.state.reload_layouts(&self.desktop, layouts);
+        self.execute(effects);
+        self.settle();
+    }
+
+    /// Shuts down like the daemon, restoring every managed window.
+    pub fn stop(&mut self) {
+        let effects = self.state.restore_all_windows();
+        self.execute(effects);
+    }
+
+    /// Opens a new application window on the primary monitor.
+    pub fn open(&mut self, class: &str, title: &str) -> usize {
+        self.open_on(0, class, title)
+    }
+
+    /// Opens a new application window on the monitor at `monitor_idx`.
+    pub fn open_on(&mut self, monitor_idx: usize, class: &str, title: &str) -> usize {
+        let hwnd = self.desktop.spawn_on(monitor_idx, class, title);
+        self.desktop.set_foreground(Some(hwnd));
+        self.dispatch(WindowEvent::Created { hwnd });
+        hwnd
+    }
+
+    /// Closes a window as the user would (e.g. via its close button).
+    pub fn close(&mut self, hwnd: usize) {
+        if self.desktop.remove(hwnd) {
+            self.dispatch(WindowEvent::Destroyed { hwnd });
+        }
+    }
+
+    /// Minimizes a window as the user would.
+    pub fn minimize(&mut self, hwnd: usize) {
+        let events = self.desktop.apply(&Effect::Minimize { hwnd });
+        self.deliver(events);
+    }
+
+    /// Restores a minimized window (e.g. by clicking its taskbar button).
+    pub fn restore(&mut self, hwnd: usize) {
+        if !self.desktop.update(hwnd, |w| w.minimized = false) {
+            return;
+        }
+        self.desktop.set_foreground(Some(hwnd));
+        self.deliver(vec![
+            WindowEvent::Restored { hwnd },
+            WindowEvent::Focused { hwnd },
+        ]);
+    }
+
+    /// Maximizes a window to fill its monitor's work area.
+    pub fn maximize(&mut self, hwnd: usize) {
+        let area = self.monitor_of(hwnd).map(|m| m.work_area);
+        let found = self.desktop.update(hwnd, |w| {
+            w.maximized = true;
+            if let Some(area) = area {
+                w.rect = area;
+            }
+        });
+        if found {
+            self.dispatch(WindowEvent::LocationChanged { hwnd });
+        }
+    }
+
+    /// Restores a maximized window to its previous, unmaximized state.
+    pub fn unmaximize(&mut self, hwnd: usize) {
+        if self.desktop.update(hwnd, |w| w.maximized = false) {
+            self.dispatch(WindowEvent::LocationChanged { hwnd });
+        }
+    }
+
+    /// Drags a window to `rect`, as the user would with the mouse.
+    pub fn drag(&mut self, hwnd: usize, rect: Rect) {
+        if self.desktop.update(hwnd, |w| w.rect = rect) {
+            self.dispatch(WindowEvent::Moved { hwnd });
+        }
+    }
+
+    /// Focuses a window by clicking it.
+    pub fn click(&mut self, hwnd: usize) {
+        self.desktop.set_foreground(Some(hwnd));
+        self.dispatch(WindowEvent::Focused { hwnd });
+    }
+
+    /// Moves the mouse over a window (focus-follows-mouse).
+    pub fn hover(&mut self, hwnd: usize) {
+        self.dispatch(WindowEvent::MouseHover { hwnd });
+    }
+
+    /// Changes a window's title.
+    pub fn rename(&mut self, hwnd: usize, title: &str) {
+        if self.desktop.update(hwnd, |w| w.title = title.to_string()) {
+            self.dispatch(WindowEvent::TitleChanged { hwnd });
+        }
+    }
+
+    /// Replaces the connected monitors (connect, disconnect, rearrange).
+    pub fn set_monitors(&mut self, monitors: Vec<MonitorInfo>) {
+        self.desktop.set_monitors(monitors);
+        self.dispatch(WindowEvent::DisplayChanged);
+    }
+
+    /// Processes queued events until the desktop is quiet.
+    ///
+    /// # Panics
+    ///
+    /// Panics if more than `MAX_SETTLE_EVENTS` events are processed,
+    /// which indicates an event feedback loop.
+    pub fn settle(&mut self) {
+        let mut processed = 0;
+        while let Some(event) = self.queue.pop_front() {
+            processed += 1;
+            assert!(
+                processed <= MAX_SETTLE_EVENTS,
+                "event feedback loop: still processing after {MAX_SETTLE_EVENTS} events (last: {event})"
+            );
+            let effects = self.handle_event(&event);
+            self.execute(effects);
+        }
+    }
+
+    /// Routes one event the same way the daemon loop does.
+    fn handle_event(&mut self, event: &WindowEvent) -> Vec<Effect> {
+        match event {
+            WindowEvent::WorkAreaChanged => {
+                let monitors = self.desktop.monitors();
+                self.state.reset_and_adjust_work_areas(
+                    &self.desktop,
+                    &monitors,
+                    self.bar_height,
+                    &[],
+                )
+            }
+            WindowEvent::DisplayChanged => self.state.handle_display_change(
+                &self.desktop,
+                self.desktop.monitors(),
+                self.bar_height,
+                &[],
+            ),
+            other => self.state.handle_event(&self.desktop, other),
+        }
+    }
+
+    /// Applies effects to the desktop and queues the events they fire.
+    fn execute(&mut self, effects: Vec<Effect>) {
+        for effect in effects {
+            let events = self.desktop.apply(&effect);
+            if self.feedback {
+                self.queue.extend(events);
+            }
+            self.log.push(effect);
+        }
+    }
+
+    /// Queues events and processes them.
+    fn deliver(&mut self, events: Vec<WindowEvent>) {
+        self.queue.extend(events);
+        self.settle();
+    }
+
+    /// Returns the monitor currently containing a window.
+    fn monitor_of(&self, hwnd: usize) -> Option<MonitorInfo> {
+        use mosaico_core::Desktop;
+
+        let id = self.desktop.monitor_id(hwnd);
+        self.desktop.monitors().into_iter().find(|m| m.id == id)
+    }
+}
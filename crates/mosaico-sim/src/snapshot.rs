@@ -0,0 +1,96 @@
+use std::collections::BTreeMap;
+
+use mosaico_core::Rect;
+use serde::{Deserialize, Serialize};
+
+use crate::Simulation;
+
+/// The observable tiling layout at one point in time.
+///
+/// Serializable so replay tests can keep the expected layout next to the
+/// trace that produces it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LayoutSnapshot {
+    /// Index of the focused monitor.
+    pub focused_monitor: usize,
+    /// The focused window, if any.
+    pub focused_window: Option<usize>,
+    /// Every monitor, in tiling order.
+    pub monitors: Vec<MonitorLayout>,
+}
+
+/// One monitor in a [`LayoutSnapshot`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MonitorLayout {
+    /// Platform monitor identifier.
+    pub id: usize,
+    /// Visible workspace number (1-based).
+    pub active_workspace: usize,
+    /// Whether monocle mode is on for the visible workspace.
+    pub monocle: bool,
+    /// Tiled windows of the visible workspace, in layout order, with the
+    /// rectangles they currently occupy on the desktop.
+    pub windows: Vec<TiledWindow>,
+    /// Floating windows of the visible workspace.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub floating: Vec<TiledWindow>,
+    /// Handles on every non-empty workspace, keyed by number (1-based).
+    pub workspaces: BTreeMap<usize, Vec<usize>>,
+}
+
+/// A window on the visible workspace of a [`MonitorLayout`], tiled
+/// or floating.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TiledWindow {
+    /// Window handle.
+    pub hwnd: usize,
+    /// Current window rectangle, or `None` if the window no longer exists.
+    pub rect: Option<Rect>,
+}
+
+impl Simulation {
+    /// Captures the current layout.
+    pub fn snapshot(&self) -> LayoutSnapshot {
+        let monitors = self
+            .state()
+            .monitors()
+            .iter()
+            .map(|mon| MonitorLayout {
+                id: mon.id,
+                active_workspace: mon.active_workspace + 1,
+                monocle: mon.active_ws().monocle(),
+                windows: mon
+                    .active_ws()
+                    .handles()
+                    .iter()
+                    .map(|&hwnd| TiledWindow {
+                        hwnd,
+                        rect: self.rect(hwnd),
+                    })
+                    .collect(),
+                floating: mon
+                    .active_ws()
+                    .floating()
+                    .iter()
+                    .map(|&hwnd| TiledWindow {
+                        hwnd,
+                        rect: self.rect(hwnd),
+                    })
+                    .collect(),
+                workspaces: mon
+                    .workspaces
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, ws)| !ws.is_empty())
+                    .map(|(i, ws)| (i + 1, ws.windows()))
+                    .collect(),
+            })
+            .collect();
+
+        LayoutSnapshot {
+            focused_monitor: self.state().focused_monitor(),
+            focused_window: self.state().focused_window(),
+            monitors,
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use mosaico_core::{Rect, Window, WindowResult};
+
+use crate::SimDesktop;
+
+/// A handle to a window living in a [`SimDesktop`].
+///
+/// Mirrors `mosaico_windows::Window`: a cheap handle whose operations
+/// act on the shared desktop model.
+pub struct SimWindow<'a> {
+    desktop: &'a SimDesktop,
+    hwnd: usize,
+}
+
+impl<'a> SimWindow<'a> {
+    /// Wraps a handle belonging to `desktop`.
+    pub fn new(desktop: &'a SimDesktop, hwnd: usize) -> Self {
+        Self { desktop, hwnd }
+    }
+
+    /// Returns the raw window handle.
+    pub fn hwnd(&self) -> usize {
+        self.hwnd
+    }
+}
+
+impl Window for SimWindow<'_> {
+    fn title(&self) -> WindowResult<String> {
+        self.desktop
+            .window(self.hwnd)
+            .map(|w| w.title)
+            .ok_or_else(|| format!("no such window 0x{:X}", self.hwnd).into())
+    }
+
+    fn class(&self) -> WindowResult<String> {
+        self.desktop
+            .window(self.hwnd)
+            .map(|w| w.class)
+            .ok_or_else(|| format!("no such window 0x{:X}", self.hwnd).into())
+    }
+
+    fn exe_path(&self) -> WindowResult<String> {
+        self.desktop
+            .window(self.hwnd)
+            .map(|w| w.exe_path)
+            .ok_or_else(|| format!("no such window 0x{:X}", self.hwnd).into())
+    }
+
+    fn rect(&self) -> WindowResult<Rect> {
+        self.desktop
+            .window(self.hwnd)
+            .map(|w| w.rect)
+            .ok_or_else(|| format!("no such window 0x{:X}", self.hwnd).into())
+    }
+
+    fn set_rect(&self, rect: &Rect) -> WindowResult<()> {
+        let found = self.desktop.update(self.hwnd, |w| {
+            w.rect = *rect;
+            w.maximized = false;
+        });
+        if found {
+            Ok(())
+        } else {
+            Err(format!("no such window 0x{:X}", self.hwnd).into())
+        }
+    }
+
+    fn invalidate(&self) {
+        self.desktop.update(self.hwnd, |w| w.repaints += 1);
+    }
+
+    fn is_visible(&self) -> bool {
+        self.desktop.window(self.hwnd).is_some_and(|w| w.visible)
+    }
+}
@@ -0,0 +1,186 @@
+//! The IPC protocol end to end over a Unix domain socket.
+//!
+//! A `mosaico_client::Client` talks to `ipc::serve()`, whose requests a
+//! miniature daemon loop answers from the simulated desktop — the same
+//! path the CLI and the Windows daemon take over the named pipe.
+#![cfg(unix)]
+
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+use mosaico_client::{Client, ClientError};
+use mosaico_core::config::Config;
+use mosaico_core::ipc::{
+    self, ErrorCode, IpcConnection, IpcError, IpcRequest, IpcTransport, PROTOCOL_VERSION, Response,
+    ResponseStatus, UnixSocketTransport,
+};
+use mosaico_core::{Action, Command, DaemonEvent, LayoutKind};
+use mosaico_sim::{SimDesktop, Simulation};
+
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+fn transport(name: &str) -> UnixSocketTransport {
+    let file = format!("mosaico-sim-{}-{name}.sock", std::process::id());
+    UnixSocketTransport::new(std::env::temp_dir().join(file))
+}
+
+/// Starts the accept loop on its own thread, returning the requests it
+/// forwards.
+fn start_server(transport: &UnixSocketTransport) -> mpsc::Receiver<IpcRequest> {
+    let mut listener = transport.bind().unwrap();
+    let (tx, rx) = mpsc::channel();
+    thread::spawn(move || ipc::serve(&mut listener, |request| tx.send(request).is_ok()));
+    rx
+}
+
+/// Answers requests like the daemon's main loop until `Stop`: commands
+/// get a response, subscribers get the changes each command causes.
+fn run_daemon(sim: &mut Simulation, requests: mpsc::Receiver<IpcRequest>) {
+    let mut subscribers = Vec::new();
+    for request in requests {
+        let (command, reply) = match request {
+            IpcRequest::Subscribe(_, events) => {
+                subscribers.push(events);
+                continue;
+            }
+            IpcRequest::Command(command, reply) => (command, reply),
+        };
+
+        let before = sim.state().snapshot(sim.desktop());
+        let response = match &command {
+            Command::Action { action } => {
+                sim.action(action);
+                Response::ok()
+            }
+            Command::Query => Response::ok_with_state(sim.state().snapshot(sim.desktop())),
+            _ => Response::ok_with_message("Daemon stopping"),
+        };
+        let after = sim.state().snapshot(sim.desktop());
+        for event in DaemonEvent::changes(&before, &after) {
+            subscribers.retain(|s| s.send(event.clone()).is_ok());
+        }
+        let _ = reply.send(response);
+
+        if matches!(command, Command::Stop) {
+            return;
+        }
+    }
+}
+
+fn start_sim() -> Simulation {
+    let desktop = SimDesktop::with_monitors(1);
+    desktop.spawn("Notepad", "a.txt");
+    desktop.spawn("Notepad", "b.txt");
+    let mut config = Config::default();
+    config.layout.gap = 0;
+    Simulation::start(desktop, &config, Vec::new())
+}
+
+#[test]
+fn client_commands_reach_the_daemon_and_back() {
+    // Arrange
+    let transport = transport("commands");
+    let requests = start_server(&transport);
+    let mut sim = start_sim();
+    let client = Client::with_transport(transport.clone());
+
+    // Act
+    let client = thread::spawn(move || {
+        let action = client.action(Action::ToggleMonocle).unwrap();
+        let state = client.query().unwrap();
+        client.stop().unwrap();
+        (action, state)
+    });
+    run_daemon(&mut sim, requests);
+    let (action, state) = client.join().unwrap();
+
+    // Assert
+    assert_eq!(action, None);
+    assert_eq!(state.windows().len(), 2);
+    assert!(state.monitors[0].workspaces[0].monocle);
+}
+
+#[test]
+fn subscriber_receives_events_until_the_daemon_stops() {
+    // Arrange
+    let transport = transport("subscribe");
+    let requests = start_server(&transport);
+    let mut sim = start_sim();
+    let mut subscriber = transport.connect(TIMEOUT).unwrap();
+    subscriber
+        .send(&Command::Subscribe { topics: Vec::new() })
+        .unwrap();
+    let ack: Response = subscriber.recv().unwrap().unwrap();
+    let client = Client::with_transport(transport.clone());
+
+    // Act
+    let client = thread::spawn(move || {
+        client.action(Action::ToggleMonocle).unwrap();
+        client.stop().unwrap();
+    });
+    run_daemon(&mut sim, requests);
+    client.join().unwrap();
+    drop(sim);
+
+    // Assert
+    assert_eq!(ack.message.as_deref(), Some("Subscribed"));
+    let event: DaemonEvent = subscriber.recv().unwrap().unwrap();
+    assert_eq!(
+        event,
+        DaemonEvent::LayoutChanged {
+            monitor: 0,
+            workspace: 1,
+            layout: LayoutKind::Bsp,
+            monocle: true,
+        }
+    );
+    assert!(subscriber.recv::<DaemonEvent>().unwrap().is_none());
+}
+
+#[test]
+fn client_reports_a_missing_daemon() {
+    let client = Client::with_transport(transport("missing")).retries(0, Duration::ZERO);
+
+    let result = client.status();
+
+    assert!(matches!(
+        result,
+        Err(ClientError::Ipc(IpcError::NotRunning))
+    ));
+    assert!(!client.is_running());
+}
+
+#[test]
+fn server_answers_handshake_and_rejects_newer_clients() {
+    // Arrange
+    let transport = transport("handshake");
+    let requests = start_server(&transport);
+    let mut sim = start_sim();
+    let client_transport = transport.clone();
+
+    // Act
+    let client = thread::spawn(move || {
+        let client = Client::with_transport(client_transport.clone());
+        let hello = client.hello().unwrap();
+        // A newer client is simulated by hand, as `Client` always sends
+        // this build's version.
+        let mut conn = client_transport.connect(TIMEOUT).unwrap();
+        conn.write_line(r#"{"version":99,"id":5,"command":"Status"}"#)
+            .unwrap();
+        let rejected: Response = conn.recv().unwrap().unwrap();
+        let stop = client.send(&Command::Stop).unwrap();
+        (hello, rejected, stop)
+    });
+    run_daemon(&mut sim, requests);
+    let (hello, rejected, stop) = client.join().unwrap();
+
+    // Assert
+    assert_eq!(hello.protocol, PROTOCOL_VERSION);
+    assert!(hello.supports("Subscribe"));
+    assert_eq!(rejected.status, ResponseStatus::Error);
+    assert_eq!(rejected.code, Some(ErrorCode::UnsupportedVersion));
+    assert_eq!(rejected.id, Some(5));
+    assert_eq!(stop.version, PROTOCOL_VERSION);
+    assert!(stop.id.is_some());
+}
@@ -0,0 +1,63 @@
+//! Regression tests replaying recorded window-event traces.
+//!
+//! Each `tests/traces/<name>.jsonl` trace is replayed on the simulated
+//! desktop and the final layout compared with `<name>.snapshot.json`.
+//! To add a case, record a trace with `mosaico debug events --record`,
+//! drop it here, and write down the layout you expect.
+
+use std::path::PathBuf;
+
+use mosaico_core::config::Config;
+use mosaico_core::{Rect, trace};
+use mosaico_sim::{LayoutSnapshot, replay};
+
+/// Default config with gaps disabled so expected rects are round numbers.
+fn config() -> Config {
+    let mut config = Config::default();
+    config.layout.gap = 0;
+    config
+}
+
+fn traces_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/traces")
+}
+
+/// Replays `<name>.jsonl` and compares against `<name>.snapshot.json`.
+fn assert_replay(name: &str) -> LayoutSnapshot {
+    let dir = traces_dir();
+    let entries = trace::load(&dir.join(format!("{name}.jsonl"))).unwrap();
+    let expected = std::fs::read_to_string(dir.join(format!("{name}.snapshot.json"))).unwrap();
+    let expected: LayoutSnapshot = serde_json::from_str(&expected).unwrap();
+
+    let actual = replay(&entries, &config(), Vec::new()).snapshot();
+
+    assert_eq!(
+        actual,
+        expected,
+        "layout after replaying {name} differs; actual:\n{}",
+        serde_json::to_string_pretty(&actual).unwrap()
+    );
+    actual
+}
+
+#[test]
+fn chromium_hide_storm_keeps_window_tiled() {
+    // Arrange / Act
+    let snapshot = assert_replay("chromium_hide_storm");
+
+    // Assert
+    let windows = &snapshot.monitors[0].windows;
+    assert_eq!(windows.len(), 2);
+    assert_eq!(windows[1].rect, Some(Rect::new(960, 0, 960, 1080)));
+}
+
+#[test]
+fn wpf_window_is_tiled_once_shown() {
+    // Arrange / Act
+    let snapshot = assert_replay("wpf_late_show");
+
+    // Assert
+    assert_eq!(snapshot.focused_window, Some(0x3000));
+    assert!(snapshot.monitors[0].monocle);
+    assert!(snapshot.monitors[1].windows.is_empty());
+}
@@ -0,0 +1,442 @@
+//! End-to-end window management tests on the simulated desktop.
+//!
+//! Headless counterparts of `crates/mosaico/tests/window_management.rs`:
+//! the same scenarios, driven through the real tiling state machine,
+//! without a Windows session or notepad.exe.
+
+use std::time::Duration;
+
+use mosaico_core::config::{Config, HidingBehaviour, RuleMatch, WindowRule};
+use mosaico_core::tiling::MonitorInfo;
+use mosaico_core::{Action, Direction, Effect, Rect, WindowEvent};
+use mosaico_sim::{SimDesktop, Simulation, Step, WindowState};
+
+/// Default config with gaps disabled so expected rects are round numbers.
+fn config() -> Config {
+    let mut config = Config::default();
+    config.layout.gap = 0;
+    config
+}
+
+fn start(monitors: usize) -> Simulation {
+    Simulation::start(SimDesktop::with_monitors(monitors), &config(), Vec::new())
+}
+
+#[test]
+fn adopts_existing_windows_at_startup() {
+    // Arrange
+    let desktop = SimDesktop::with_monitors(1);
+    let a = desktop.spawn("Notepad", "a.txt");
+    let b = desktop.spawn("Notepad", "b.txt");
+
+    // Act
+    let sim = Simulation::start(desktop, &config(), Vec::new());
+
+    // Assert
+    assert_eq!(sim.state().window_count(), 2);
+    assert_eq!(sim.rect(a), Some(Rect::new(0, 0, 960, 1080)));
+    assert_eq!(sim.rect(b), Some(Rect::new(960, 0, 960, 1080)));
+    assert_eq!(sim.desktop().foreground(), Some(a));
+}
+
+#[test]
+fn new_windows_are_tiled_and_focused() {
+    let mut sim = start(1);
+
+    let a = sim.open("Notepad", "a.txt");
+    let b = sim.open("Notepad", "b.txt");
+
+    assert_eq!(sim.rect(a), Some(Rect::new(0, 0, 960, 1080)));
+    assert_eq!(sim.rect(b), Some(Rect::new(960, 0, 960, 1080)));
+    assert_eq!(sim.state().focused_window(), Some(b));
+    assert_eq!(sim.desktop().border().map(|b| b.hwnd), Some(b));
+}
+
+#[test]
+fn new_window_splits_the_focused_window() {
+    let mut sim = start(1);
+    let a = sim.open("Notepad", "a.txt");
+    let b = sim.open("Notepad", "b.txt");
+    sim.click(a);
+
+    let c = sim.open("Notepad", "c.txt");
+
+    assert_eq!(sim.rect(a), Some(Rect::new(0, 0, 960, 540)));
+    assert_eq!(sim.rect(c), Some(Rect::new(0, 540, 960, 540)));
+    assert_eq!(sim.rect(b), Some(Rect::new(960, 0, 960, 1080)));
+}
+
+#[test]
+fn closing_a_window_promotes_its_sibling() {
+    let mut sim = start(1);
+    let a = sim.open("Notepad", "a.txt");
+    let b = sim.open("Notepad", "b.txt");
+    sim.click(a);
+    let c = sim.open("Notepad", "c.txt");
+
+    sim.close(a);
+
+    assert_eq!(sim.rect(c), Some(Rect::new(0, 0, 960, 1080)));
+    assert_eq!(sim.rect(b), Some(Rect::new(960, 0, 960, 1080)));
+}
+
+#[test]
+fn closing_a_window_retiles_the_rest() {
+    let mut sim = start(1);
+    let a = sim.open("Notepad", "a.txt");
+    let b = sim.open("Notepad", "b.txt");
+
+    sim.close(b);
+
+    assert_eq!(sim.state().window_count(), 1);
+    assert_eq!(sim.rect(a), Some(Rect::new(0, 0, 1920, 1080)));
+    assert_eq!(sim.state().focused_window(), Some(a));
+}
+
+#[test]
+fn window_rules_exclude_matching_windows() {
+    let rules = vec![WindowRule {
+        matcher: RuleMatch {
+            match_class: Some("#32770".into()),
+            ..Default::default()
+        },
+        manage: false,
+        ..Default::default()
+    }];
+    let mut sim = Simulation::start(SimDesktop::with_monitors(1), &config(), rules);
+
+    let a = sim.open("Notepad", "a.txt");
+    let dialog = sim.open("#32770", "Save As");
+
+    assert_eq!(sim.state().window_count(), 1);
+    assert_eq!(sim.rect(a), Some(Rect::new(0, 0, 1920, 1080)));
+    assert_eq!(sim.desktop().window(dialog).map(|w| w.corners), Some(None));
+}
+
+#[test]
+fn dragged_floating_window_keeps_its_place_across_workspaces() {
+    // Arrange
+    let mut config = config();
+    config.layout.hiding = HidingBehaviour::Hide;
+    let mut sim = Simulation::start(SimDesktop::with_monitors(1), &config, Vec::new());
+    let a = sim.open("Notepad", "a.txt");
+    let b = sim.open("Notepad", "b.txt");
+    sim.action(&Action::ToggleFloat);
+    let dropped = Rect::new(300, 200, 640, 480);
+    sim.drag(b, dropped);
+
+    // Act
+    sim.action(&Action::GoToWorkspace(2.into()));
+    sim.action(&Action::GoToWorkspace(1.into()));
+
+    // Assert
+    assert_eq!(sim.rect(a), Some(Rect::new(0, 0, 1920, 1080)));
+    assert_eq!(sim.rect(b), Some(dropped));
+    assert!(sim.desktop().window(b).is_some_and(|w| w.visible));
+    assert_eq!(sim.state().monitors()[0].active_ws().floating(), &[b]);
+}
+
+#[test]
+fn keyboard_focus_moves_cursor_to_focused_window() {
+    // Arrange
+    let mut sim = start(1);
+    let a = sim.open("Notepad", "a.txt");
+    let b = sim.open("Notepad", "b.txt");
+    sim.click(a);
+
+    // Act
+    sim.action(&Action::Focus(Direction::Right));
+
+    // Assert
+    assert_eq!(sim.state().focused_window(), Some(b));
+    assert_eq!(sim.desktop().foreground(), Some(b));
+    assert_eq!(sim.desktop().cursor(), (1440, 540));
+}
+
+#[test]
+fn mouse_hover_focuses_window_without_moving_cursor() {
+    let mut sim = start(1);
+    let a = sim.open("Notepad", "a.txt");
+    let b = sim.open("Notepad", "b.txt");
+    sim.click(a);
+    let cursor = sim.desktop().cursor();
+
+    sim.hover(b);
+
+    assert_eq!(sim.state().focused_window(), Some(b));
+    assert_eq!(sim.desktop().border().map(|b| b.hwnd), Some(b));
+    assert_eq!(sim.desktop().cursor(), cursor);
+}
+
+#[test]
+fn minimize_and_restore_updates_border() {
+    // Arrange
+    let mut sim = start(1);
+    let a = sim.open("Notepad", "a.txt");
+    assert!(sim.desktop().border().is_some());
+
+    // Act
+    sim.minimize(a);
+
+    // Assert
+    assert_eq!(sim.state().window_count(), 0);
+    assert_eq!(sim.desktop().border(), None);
+
+    // Act
+    sim.restore(a);
+
+    // Assert
+    assert_eq!(sim.state().window_count(), 1);
+    let border = sim.desktop().border().expect("border after restore");
+    assert_eq!(border.hwnd, a);
+    assert_eq!(border.rect, Rect::new(0, 0, 1920, 1080));
+}
+
+#[test]
+fn minimize_focused_action_retiles_remaining_window() {
+    let mut sim = start(1);
+    let a = sim.open("Notepad", "a.txt");
+    let b = sim.open("Notepad", "b.txt");
+
+    sim.action(&Action::MinimizeFocused);
+
+    assert!(sim.desktop().window(b).is_some_and(|w| w.minimized));
+    assert_eq!(sim.rect(a), Some(Rect::new(0, 0, 1920, 1080)));
+    assert_eq!(sim.state().focused_window(), Some(a));
+}
+
+#[test]
+fn close_focused_action_destroys_window() {
+    let mut sim = start(1);
+    let a = sim.open("Notepad", "a.txt");
+    let b = sim.open("Notepad", "b.txt");
+
+    sim.action(&Action::CloseFocused);
+
+    assert!(sim.desktop().window(b).is_none());
+    assert_eq!(sim.state().window_count(), 1);
+    assert_eq!(sim.rect(a), Some(Rect::new(0, 0, 1920, 1080)));
+}
+
+#[test]
+fn monocle_new_window_gets_monocle_size() {
+    let mut sim = start(1);
+    let a = sim.open("Notepad", "a.txt");
+    sim.action(&Action::ToggleMonocle);
+    let monocle_rect = sim.rect(a);
+
+    let b = sim.open("Notepad", "b.txt");
+
+    assert_eq!(sim.rect(b), monocle_rect);
+    let border = sim.desktop().border().expect("border in monocle");
+    assert_eq!(border.hwnd, b);
+    assert!(border.monocle);
+}
+
+#[test]
+fn monocle_persists_after_workspace_switch() {
+    // Arrange
+    let mut sim = start(1);
+    let a = sim.open("Notepad", "a.txt");
+    sim.open("Notepad", "b.txt");
+    sim.click(a);
+    sim.action(&Action::ToggleMonocle);
+    let monocle_rect = sim.rect(a);
+
+    // Act
+    sim.action(&Action::GoToWorkspace(7.into()));
+    sim.action(&Action::GoToWorkspace(1.into()));
+
+    // Assert
+    assert_eq!(sim.rect(a), monocle_rect);
+    assert_eq!(sim.desktop().border().map(|b| b.hwnd), Some(a));
+}
+
+#[test]
+fn maximize_hides_border_and_preserves_maximize() {
+    let mut sim = start(1);
+    let a = sim.open("Notepad", "a.txt");
+    sim.open("Notepad", "b.txt");
+    sim.click(a);
+
+    sim.maximize(a);
+
+    assert!(sim.desktop().window(a).is_some_and(|w| w.maximized));
+    assert_eq!(sim.rect(a), Some(Rect::new(0, 0, 1920, 1080)));
+    assert_eq!(sim.desktop().border(), None);
+}
+
+#[test]
+fn restore_from_maximize_retiles_and_shows_border() {
+    let mut sim = start(1);
+    let a = sim.open("Notepad", "a.txt");
+    sim.open("Notepad", "b.txt");
+    sim.click(a);
+    sim.maximize(a);
+
+    sim.unmaximize(a);
+
+    assert_eq!(sim.rect(a), Some(Rect::new(0, 0, 960, 1080)));
+    let border = sim.desktop().border().expect("border after restore");
+    assert_eq!(border.hwnd, a);
+    assert_eq!(border.rect, Rect::new(0, 0, 960, 1080));
+}
+
+#[test]
+fn owned_dialog_is_not_tiled_and_border_moves_to_owner() {
+    // Arrange
+    let mut sim = start(1);
+    let a = sim.open("Notepad", "a.txt");
+    let b = sim.open("Notepad", "b.txt");
+    let mut dialog = WindowState::new("#32770", "Save As", Rect::new(100, 100, 400, 300));
+    dialog.owner = Some(a);
+    let dialog = sim.desktop().insert(dialog);
+
+    // Act
+    sim.dispatch(WindowEvent::Created { hwnd: dialog });
+    sim.click(dialog);
+
+    // Assert
+    assert_eq!(sim.state().window_count(), 2);
+    assert_eq!(sim.rect(b), Some(Rect::new(960, 0, 960, 1080)));
+    assert_eq!(sim.desktop().border().map(|b| b.hwnd), Some(a));
+}
+
+#[test]
+fn workspace_switch_cloaks_previous_windows() {
+    let mut sim = start(1);
+    let a = sim.open("Notepad", "a.txt");
+
+    sim.action(&Action::GoToWorkspace(2.into()));
+    let b = sim.open("Notepad", "b.txt");
+
+    assert!(sim.desktop().window(a).is_some_and(|w| w.cloaked));
+    assert_eq!(sim.rect(b), Some(Rect::new(0, 0, 1920, 1080)));
+    assert_eq!(sim.state().monitors()[0].workspaces[0].handles(), &[a]);
+    assert_eq!(sim.state().monitors()[0].workspaces[1].handles(), &[b]);
+}
+
+#[test]
+fn hide_mode_survives_hide_events_fired_by_switch() {
+    // Arrange
+    let mut config = config();
+    config.layout.hiding = HidingBehaviour::Hide;
+    let mut sim = Simulation::start(SimDesktop::with_monitors(1), &config, Vec::new());
+    let a = sim.open("Notepad", "a.txt");
+
+    // Act: hiding fires Hidden, showing fires Created.
+    sim.action(&Action::GoToWorkspace(2.into()));
+    sim.action(&Action::GoToWorkspace(1.into()));
+
+    // Assert
+    assert!(sim.desktop().window(a).is_some_and(|w| w.visible));
+    assert_eq!(sim.state().monitors()[0].workspaces[0].handles(), &[a]);
+    assert_eq!(sim.state().focused_window(), Some(a));
+}
+
+#[test]
+fn clicking_hidden_window_after_cooldown_switches_workspace() {
+    // Arrange
+    let mut sim = start(1);
+    let a = sim.open("Notepad", "a.txt");
+    sim.action(&Action::GoToWorkspace(2.into()));
+
+    // Act
+    sim.run([
+        Step::Wait(Duration::from_secs(1)),
+        Step::Event(WindowEvent::Focused { hwnd: a }),
+    ]);
+
+    // Assert
+    assert_eq!(sim.state().monitors()[0].active_workspace, 0);
+    assert!(sim.desktop().window(a).is_some_and(|w| !w.cloaked));
+}
+
+#[test]
+fn stale_focus_during_cooldown_does_not_switch_workspace() {
+    let mut sim = start(1);
+    let a = sim.open("Notepad", "a.txt");
+    sim.action(&Action::GoToWorkspace(2.into()));
+
+    sim.run([Step::Event(WindowEvent::Focused { hwnd: a })]);
+
+    assert_eq!(sim.state().monitors()[0].active_workspace, 1);
+}
+
+#[test]
+fn focus_and_move_across_monitors() {
+    // Arrange
+    let mut sim = start(2);
+    let a = sim.open_on(0, "Notepad", "a.txt");
+    let b = sim.open_on(0, "Notepad", "b.txt");
+
+    // Act
+    sim.action(&Action::Move(Direction::Right));
+
+    // Assert
+    assert_eq!(sim.rect(b), Some(Rect::new(1920, 0, 1920, 1080)));
+    assert_eq!(sim.rect(a), Some(Rect::new(0, 0, 1920, 1080)));
+    assert_eq!(sim.state().focused_monitor(), 1);
+
+    // Act
+    sim.action(&Action::Focus(Direction::Left));
+
+    // Assert
+    assert_eq!(sim.state().focused_window(), Some(a));
+    assert_eq!(sim.state().focused_monitor(), 0);
+}
+
+#[test]
+fn dragging_window_to_other_monitor_reassigns_it() {
+    let mut sim = start(2);
+    let a = sim.open("Notepad", "a.txt");
+    let b = sim.open("Notepad", "b.txt");
+
+    sim.drag(b, Rect::new(2500, 200, 800, 600));
+
+    assert_eq!(sim.rect(a), Some(Rect::new(0, 0, 1920, 1080)));
+    assert_eq!(sim.rect(b), Some(Rect::new(1920, 0, 1920, 1080)));
+}
+
+#[test]
+fn disconnecting_monitor_migrates_windows() {
+    let mut sim = start(2);
+    let a = sim.open_on(0, "Notepad", "a.txt");
+    sim.action(&Action::Focus(Direction::Right));
+    let b = sim.open_on(1, "Notepad", "b.txt");
+
+    sim.set_monitors(vec![MonitorInfo {
+        id: 1,
+        work_area: Rect::new(0, 0, 1920, 1080),
+        ..Default::default()
+    }]);
+
+    assert_eq!(sim.state().monitors().len(), 1);
+    assert_eq!(sim.rect(a), Some(Rect::new(0, 0, 960, 1080)));
+    assert_eq!(sim.rect(b), Some(Rect::new(960, 0, 960, 1080)));
+}
+
+#[test]
+fn bar_height_shrinks_tiled_area() {
+    let mut sim = start(1);
+    let a = sim.open("Notepad", "a.txt");
+
+    sim.set_bar_height(30);
+
+    assert_eq!(sim.rect(a), Some(Rect::new(0, 30, 1920, 1050)));
+}
+
+#[test]
+fn stop_restores_windows_on_hidden_workspaces() {
+    let mut sim = start(1);
+    let a = sim.open("Notepad", "a.txt");
+    sim.action(&Action::GoToWorkspace(2.into()));
+    sim.take_effects();
+
+    sim.stop();
+
+    let w = sim.desktop().window(a).expect("window still exists");
+    assert!(!w.cloaked && w.visible);
+    assert_eq!(w.corners, None);
+    assert_eq!(sim.take_effects()[0], Effect::HideBorder);
+}
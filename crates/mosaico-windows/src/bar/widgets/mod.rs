@@ -27,7 +27,8 @@ use super::text::{DrawCtx, draw_pill, draw_text, measure_text};
 #[derive(Debug, Clone)]
 pub struct BarState {
     pub active_workspace: usize,
-    pub workspace_count: usize,
+    /// One label per workspace: its configured name, or its number.
+    pub workspace_labels: Vec<String>,
     pub layout_name: String,
     pub monocle: bool,
     pub cpu_usage: u32,
@@ -45,7 +46,7 @@ impl Default for BarState {
     fn default() -> Self {
         Self {
             active_workspace: 0,
-            workspace_count: 8,
+            workspace_labels: (1..=8).map(|n| n.to_string()).collect(),
             layout_name: "BSP".into(),
             monocle: false,
             cpu_usage: 0,
@@ -1,8 +1,9 @@
 //! Workspaces widget — grouped container with inner active highlight.
 //!
-//! All workspace numbers sit inside a single container pill. The
-//! active workspace gets a smaller inner accent pill; inactive
-//! numbers are plain text on the container background.
+//! All workspace labels (names, or numbers for unnamed workspaces) sit
+//! inside a single container pill. The active workspace gets a smaller
+//! inner accent pill; inactive labels are plain text on the container
+//! background.
 
 use mosaico_core::config::bar::BarConfig;
 
@@ -11,9 +12,7 @@ use crate::bar::text::{DrawCtx, draw_pill, draw_text, measure_text};
 
 /// Draws the workspace container and returns the X after it.
 pub fn draw(ctx: &mut DrawCtx, start_x: i32, config: &BarConfig, state: &BarState) -> i32 {
-    let labels: Vec<String> = (0..state.workspace_count)
-        .map(|i| format!("{}", i + 1))
-        .collect();
+    let labels = &state.workspace_labels;
     let widths: Vec<i32> = labels.iter().map(|l| measure_text(ctx.dc, l)).collect();
 
     // Each cell is pill_padding * 2 + text width, spaced by workspace_gap.
@@ -22,6 +22,8 @@ pub struct BarManager {
     monitor_rects: Vec<Rect>,
     /// Which monitor indices actually have a bar displayed.
     bar_monitor_indices: Vec<usize>,
+    /// Per-monitor `bar` overrides from `[[monitor]]` blocks.
+    monitor_bars: Vec<Option<bool>>,
     /// Stateful CPU tracker — only allocated when the CPU widget is
     /// configured, so it consumes zero resources when hidden.
     cpu_tracker: Option<CpuTracker>,
@@ -30,12 +32,17 @@ pub struct BarManager {
 impl BarManager {
     /// Creates bars for selected monitors. Silently skips monitors
     /// where window creation fails.
-    pub fn new(config: BarConfig, monitor_rects: Vec<Rect>, theme: Theme) -> Self {
+    pub fn new(
+        config: BarConfig,
+        monitor_rects: Vec<Rect>,
+        monitor_bars: Vec<Option<bool>>,
+        theme: Theme,
+    ) -> Self {
         let raw_colors = config.colors.clone();
         let mut config = config;
         config.resolve_colors(theme);
 
-        let (bars, bar_monitor_indices) = Self::create_bars(&config, &monitor_rects);
+        let (bars, bar_monitor_indices) = Self::create_bars(&config, &monitor_rects, &monitor_bars);
 
         let cpu_tracker = if config.has_cpu_widget() {
             Some(CpuTracker::new())
@@ -49,21 +56,32 @@ impl BarManager {
             raw_colors,
             monitor_rects,
             bar_monitor_indices,
+            monitor_bars,
             cpu_tracker,
         }
     }
 
     /// Creates bars only for monitors that match the config filter.
-    fn create_bars(config: &BarConfig, rects: &[Rect]) -> (Vec<Bar>, Vec<usize>) {
+    ///
+    /// A monitor's `bar` override, when set, replaces the `monitors`
+    /// filter for that monitor.
+    fn create_bars(
+        config: &BarConfig,
+        rects: &[Rect],
+        monitor_bars: &[Option<bool>],
+    ) -> (Vec<Bar>, Vec<usize>) {
         let mut bars = Vec::new();
         let mut indices = Vec::new();
         if !config.enabled {
             return (bars, indices);
         }
         for (i, rect) in rects.iter().enumerate() {
-            if config.should_show_on(i)
-                && let Ok(bar) = Bar::new(*rect)
-            {
+            let show = monitor_bars
+                .get(i)
+                .copied()
+                .flatten()
+                .unwrap_or_else(|| config.should_show_on(i));
+            if show && let Ok(bar) = Bar::new(*rect) {
                 bars.push(bar);
                 indices.push(i);
             }
@@ -71,9 +89,9 @@ impl BarManager {
         (bars, indices)
     }
 
-    /// Returns the bar height if the bar is enabled, 0 otherwise.
+    /// Returns the bar height if any bar is shown, 0 otherwise.
     pub fn bar_height(&self) -> i32 {
-        if self.config.enabled {
+        if self.config.enabled && !self.bars.is_empty() {
             self.config.height
         } else {
             0
@@ -118,17 +136,39 @@ impl BarManager {
 
     /// Rebuilds bars for a new set of monitor rects (after display change).
     ///
-    /// Drops existing bars, stores new monitor rects, re-resolves colors,
-    /// and recreates bars.
-    pub fn rebuild_for_monitors(&mut self, monitor_rects: Vec<Rect>, theme: Theme) {
+    /// Drops existing bars, stores new monitor rects and overrides,
+    /// re-resolves colors, and recreates bars.
+    pub fn rebuild_for_monitors(
+        &mut self,
+        monitor_rects: Vec<Rect>,
+        monitor_bars: Vec<Option<bool>>,
+        theme: Theme,
+    ) {
         self.bars.clear();
         self.monitor_rects = monitor_rects;
+        self.monitor_bars = monitor_bars;
         self.config.colors = self.raw_colors.clone();
         self.config.resolve_colors(theme);
 
-        let (bars, indices) = Self::create_bars(&self.config, &self.monitor_rects);
+        let (bars, indices) =
+            Self::create_bars(&self.config, &self.monitor_rects, &self.monitor_bars);
+        self.bars = bars;
+        self.bar_monitor_indices = indices;
+    }
+
+    /// Applies new per-monitor `bar` overrides, recreating the bars if
+    /// they changed. Returns `true` if the bars were recreated.
+    pub fn set_monitor_bars(&mut self, monitor_bars: Vec<Option<bool>>) -> bool {
+        if self.monitor_bars == monitor_bars {
+            return false;
+        }
+        self.bars.clear();
+        self.monitor_bars = monitor_bars;
+        let (bars, indices) =
+            Self::create_bars(&self.config, &self.monitor_rects, &self.monitor_bars);
         self.bars = bars;
         self.bar_monitor_indices = indices;
+        true
     }
 
     /// Recreates bars with a new config. Returns the new bar height.
@@ -137,7 +177,8 @@ impl BarManager {
         self.raw_colors = config.colors.clone();
         self.config = config;
 
-        let (bars, indices) = Self::create_bars(&self.config, &self.monitor_rects);
+        let (bars, indices) =
+            Self::create_bars(&self.config, &self.monitor_rects, &self.monitor_bars);
         self.bars = bars;
         self.bar_monitor_indices = indices;
 
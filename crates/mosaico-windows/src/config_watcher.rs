@@ -4,6 +4,7 @@
 //! for writes and renames. When a change is detected, mtimes are checked
 //! to identify which file changed and only valid configs are sent.
 
+use std::path::PathBuf;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::mpsc::Sender;
@@ -17,6 +18,7 @@ use windows::Win32::Storage::FileSystem::{
 use windows::Win32::System::Threading::WaitForSingleObject;
 use windows::core::HSTRING;
 
+use mosaico_core::LayoutDefinition;
 use mosaico_core::config::{self, BarConfig, Config, WindowRule};
 
 /// Timeout between stop-flag checks when no changes occur (ms).
@@ -25,11 +27,61 @@ const WAIT_TIMEOUT_MS: u32 = 5000;
 /// A validated config reload ready to be applied.
 pub enum ConfigReload {
     /// Layout and border settings changed.
-    Config(Config),
+    Config(Box<Config>),
     /// Window rules changed.
     Rules(Vec<WindowRule>),
     /// Bar configuration changed.
     Bar(Box<BarConfig>),
+    /// Custom layouts changed.
+    Layouts(Vec<LayoutDefinition>),
+}
+
+impl ConfigReload {
+    /// Returns which configuration was reloaded, as reported to event
+    /// subscribers.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::Config(_) => "config",
+            Self::Rules(_) => "rules",
+            Self::Bar(_) => "bar",
+            Self::Layouts(_) => "layouts",
+        }
+    }
+}
+
+/// A watched file and its last seen modification time.
+struct Watched {
+    path: Option<PathBuf>,
+    mtime: Option<SystemTime>,
+}
+
+impl Watched {
+    fn new(path: Option<PathBuf>) -> Self {
+        let mtime = mtime(path.as_deref());
+        Self { path, mtime }
+    }
+
+    /// Returns `true` (and records the new mtime) if the file changed
+    /// since the last call.
+    fn changed(&mut self) -> bool {
+        if self.path.is_none() {
+            return false;
+        }
+        let new = mtime(self.path.as_deref());
+        if new == self.mtime {
+            return false;
+        }
+        self.mtime = new;
+        true
+    }
+}
+
+/// Every file the watcher reloads.
+struct WatchedFiles {
+    config: Watched,
+    user_rules: Watched,
+    bar: Watched,
+    layouts: Watched,
 }
 
 /// Runs the config watcher loop. Blocks until the stop flag is set
@@ -40,13 +92,12 @@ pub fn watch(tx: Sender<ConfigReload>, stop: Arc<AtomicBool>) {
         return;
     };
 
-    let config_path = config::config_path();
-    let user_rules_path = config::user_rules_path();
-    let bar_path = config::bar_path();
-
-    let mut config_mtime = mtime(config_path.as_deref());
-    let mut user_rules_mtime = mtime(user_rules_path.as_deref());
-    let mut bar_mtime = mtime(bar_path.as_deref());
+    let mut files = WatchedFiles {
+        config: Watched::new(config::config_path()),
+        user_rules: Watched::new(config::user_rules_path()),
+        bar: Watched::new(config::bar_path()),
+        layouts: Watched::new(config::layouts_path()),
+    };
 
     let dir_str = HSTRING::from(dir.as_os_str());
     let flags = FILE_NOTIFY_CHANGE_LAST_WRITE | FILE_NOTIFY_CHANGE_FILE_NAME;
@@ -70,15 +121,7 @@ pub fn watch(tx: Sender<ConfigReload>, stop: Arc<AtomicBool>) {
             continue; // timeout or error — loop back to check stop flag
         }
 
-        if check_and_reload(
-            &config_path,
-            &mut config_mtime,
-            &user_rules_path,
-            &mut user_rules_mtime,
-            &bar_path,
-            &mut bar_mtime,
-            &tx,
-        ) {
+        if check_and_reload(&mut files, &tx) {
             break; // sender dropped
         }
 
@@ -92,62 +135,61 @@ pub fn watch(tx: Sender<ConfigReload>, stop: Arc<AtomicBool>) {
 
 /// Checks mtimes and sends reloads for changed files.
 /// Returns `true` if the sender has been dropped (caller should exit).
-fn check_and_reload(
-    config_path: &Option<std::path::PathBuf>,
-    config_mtime: &mut Option<SystemTime>,
-    user_rules_path: &Option<std::path::PathBuf>,
-    user_rules_mtime: &mut Option<SystemTime>,
-    bar_path: &Option<std::path::PathBuf>,
-    bar_mtime: &mut Option<SystemTime>,
-    tx: &Sender<ConfigReload>,
-) -> bool {
-    if let Some(path) = config_path {
-        let new = mtime(Some(path.as_path()));
-        if new != *config_mtime {
-            *config_mtime = new;
-            match config::try_load() {
-                Ok(cfg) => {
-                    mosaico_core::log_info!("config.toml changed, reloading");
-                    if tx.send(ConfigReload::Config(cfg)).is_err() {
-                        return true;
-                    }
-                }
-                Err(e) => {
-                    mosaico_core::log_info!("config.toml invalid, skipping: {e}");
+fn check_and_reload(files: &mut WatchedFiles, tx: &Sender<ConfigReload>) -> bool {
+    if files.config.changed() {
+        match config::try_load() {
+            Ok(cfg) => {
+                mosaico_core::log_info!("config.toml changed, reloading");
+                if tx.send(ConfigReload::Config(Box::new(cfg))).is_err() {
+                    return true;
                 }
             }
+            Err(e) => {
+                mosaico_core::log_info!("config.toml invalid, skipping: {e}");
+            }
         }
     }
 
     // Watch user-rules.toml (not rules.toml). Community rules.toml is
     // machine-managed (downloaded on startup) and not watched.
-    if let Some(path) = user_rules_path {
-        let new = mtime(Some(path.as_path()));
-        if new != *user_rules_mtime {
-            *user_rules_mtime = new;
-            mosaico_core::log_info!("user-rules.toml changed, reloading merged rules");
-            let merged = config::load_merged_rules();
-            if tx.send(ConfigReload::Rules(merged)).is_err() {
-                return true;
-            }
+    if files.user_rules.changed() {
+        mosaico_core::log_info!("user-rules.toml changed, reloading merged rules");
+        let merged = config::load_merged_rules();
+        if tx.send(ConfigReload::Rules(merged)).is_err() {
+            return true;
         }
     }
 
-    if let Some(path) = bar_path {
-        let new = mtime(Some(path.as_path()));
-        if new != *bar_mtime {
-            *bar_mtime = new;
-            match config::try_load_bar() {
-                Ok(bar) => {
-                    mosaico_core::log_info!("bar.toml changed, reloading");
-                    if tx.send(ConfigReload::Bar(Box::new(bar))).is_err() {
-                        return true;
-                    }
+    if files.bar.changed() {
+        match config::try_load_bar() {
+            Ok(bar) => {
+                mosaico_core::log_info!("bar.toml changed, reloading");
+                if tx.send(ConfigReload::Bar(Box::new(bar))).is_err() {
+                    return true;
                 }
-                Err(e) => {
-                    mosaico_core::log_info!("bar.toml invalid, skipping: {e}");
+            }
+            Err(e) => {
+                mosaico_core::log_info!("bar.toml invalid, skipping: {e}");
+            }
+        }
+    }
+
+    if files.layouts.changed() {
+        // A deleted layouts.toml removes every custom layout.
+        let loaded = match files.layouts.path.as_deref() {
+            Some(p) if !p.exists() => Ok(Vec::new()),
+            _ => config::try_load_layouts(),
+        };
+        match loaded {
+            Ok(layouts) => {
+                mosaico_core::log_info!("layouts.toml changed, reloading");
+                if tx.send(ConfigReload::Layouts(layouts)).is_err() {
+                    return true;
                 }
             }
+            Err(e) => {
+                mosaico_core::log_info!("layouts.toml invalid, skipping: {e}");
+            }
         }
     }
 
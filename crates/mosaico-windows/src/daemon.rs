@@ -10,6 +10,8 @@ mod daemon_ipc;
 mod daemon_loop;
 #[path = "daemon_loop_handlers.rs"]
 mod daemon_loop_handlers;
+#[path = "daemon_subscribers.rs"]
+mod daemon_subscribers;
 #[path = "daemon_threads.rs"]
 mod daemon_threads;
 #[path = "daemon_types.rs"]
@@ -1,44 +1,31 @@
 use std::sync::mpsc;
 
-use mosaico_core::ipc::Command;
+use mosaico_core::ipc::{self, IpcRequest, IpcTransport};
 
-use crate::ipc::PipeServer;
+use crate::ipc::NamedPipeTransport;
 
-use super::daemon_types::{DaemonMsg, ResponseSender};
+use super::daemon_types::DaemonMsg;
 
 /// Accepts IPC connections in a loop and forwards commands to the
 /// main daemon thread. Runs on a dedicated thread.
 pub(super) fn ipc_loop(tx: mpsc::Sender<DaemonMsg>) {
-    loop {
-        let server = match PipeServer::create() {
-            Ok(s) => s,
-            Err(e) => {
-                eprintln!("Failed to create pipe: {e}");
-                return;
-            }
-        };
-
-        let command = match server.accept_command() {
-            Ok(cmd) => cmd,
-            Err(e) => {
-                eprintln!("Error reading command: {e}");
-                continue;
-            }
-        };
-
-        let (reply_tx, reply_rx): (ResponseSender, _) = mpsc::channel();
-        let is_stop = matches!(command, Command::Stop);
-
-        if tx.send(DaemonMsg::Command(command, reply_tx)).is_err() {
+    let mut listener = match NamedPipeTransport::default().bind() {
+        Ok(listener) => listener,
+        Err(e) => {
+            eprintln!("Failed to create pipe: {e}");
             return;
         }
+    };
 
-        if let Ok(response) = reply_rx.recv() {
-            let _ = server.send_response(&response);
-        }
+    let forward = |request| {
+        let msg = match request {
+            IpcRequest::Command(command, reply_tx) => DaemonMsg::Command(command, reply_tx),
+            IpcRequest::Subscribe(topics, event_tx) => DaemonMsg::Subscribe(topics, event_tx),
+        };
+        tx.send(msg).is_ok()
+    };
 
-        if is_stop {
-            return;
-        }
+    if let Err(e) = ipc::serve(&mut listener, forward) {
+        eprintln!("IPC server stopped: {e}");
     }
 }
@@ -1,9 +1,9 @@
 use std::collections::HashMap;
 use std::sync::mpsc;
 
-use mosaico_core::WindowResult;
 use mosaico_core::config;
 use mosaico_core::ipc::Command;
+use mosaico_core::{DaemonEvent, WindowResult};
 
 use crate::bar_manager::BarManager;
 use crate::event_loop;
@@ -11,6 +11,7 @@ use crate::monitor;
 use crate::tiling::TilingManager;
 
 use super::daemon_loop_handlers;
+use super::daemon_subscribers::Subscribers;
 use super::daemon_threads;
 use super::daemon_types::DaemonMsg;
 
@@ -21,6 +22,7 @@ pub(super) fn daemon_loop() -> WindowResult<()> {
 
     let keybindings = config::merge_missing_keybindings();
     let rules = config::load_merged_rules();
+    let layouts = config::load_layouts();
 
     mosaico_core::log_info!("Daemon started (PID: {})", std::process::id());
     mosaico_core::log_info!(
@@ -33,21 +35,29 @@ pub(super) fn daemon_loop() -> WindowResult<()> {
 
     let mut current_theme = config.theme.resolve();
 
-    let bar_config = config::merge_missing_bar_widgets();
-    let monitor_rects: Vec<_> = monitor::enumerate_monitors()?
-        .iter()
-        .map(|m| m.work_area)
-        .collect();
-    let mut bar_mgr = BarManager::new(bar_config, monitor_rects, current_theme);
-
     let (tx, rx) = mpsc::channel::<DaemonMsg>();
 
     let mut manager = TilingManager::new(
         &config.layout,
+        &config.workspaces,
+        &config.monitor,
+        layouts,
         rules,
         config.borders,
         config.mouse.follows_focus,
     )?;
+
+    let bar_config = config::merge_missing_bar_widgets();
+    let monitor_rects: Vec<_> = monitor::enumerate_monitors()?
+        .iter()
+        .map(|m| m.work_area)
+        .collect();
+    let mut bar_mgr = BarManager::new(
+        bar_config,
+        monitor_rects,
+        manager.monitor_bars(),
+        current_theme,
+    );
     let bar_height = bar_mgr.bar_height();
     if bar_height > 0 {
         // Retile with bar-adjusted work areas so borders match final positions.
@@ -101,6 +111,7 @@ pub(super) fn daemon_loop() -> WindowResult<()> {
     let mut events = Vec::new();
     let mut should_stop = false;
     let mut hotkeys_paused = false;
+    let mut subscribers = Subscribers::default();
 
     while !should_stop {
         // Block until at least one message arrives.
@@ -118,6 +129,10 @@ pub(super) fn daemon_loop() -> WindowResult<()> {
 
         // Process priority messages (actions, commands, reloads, ticks)
         // first, deferring window events.
+        let was_paused = hotkeys_paused;
+        // Reloads can rename or resize workspaces without moving a
+        // window; everything else that matters runs effects.
+        let mut reloaded = false;
         for msg in batch {
             match msg {
                 DaemonMsg::Event(event) => events.push(event),
@@ -146,7 +161,11 @@ pub(super) fn daemon_loop() -> WindowResult<()> {
                         }
                     }
                 }
+                DaemonMsg::Subscribe(topics, event_tx) => {
+                    subscribers.add(topics, event_tx, &manager);
+                }
                 DaemonMsg::Reload(reload) => {
+                    let config = reload.kind().to_string();
                     daemon_loop_handlers::handle_reload(
                         *reload,
                         &mut manager,
@@ -156,6 +175,8 @@ pub(super) fn daemon_loop() -> WindowResult<()> {
                         hotkeys_paused,
                         &get_update,
                     );
+                    subscribers.publish(DaemonEvent::ConfigReloaded { config });
+                    reloaded = true;
                 }
                 DaemonMsg::Tick => {
                     daemon_loop_handlers::handle_tick(
@@ -175,6 +196,11 @@ pub(super) fn daemon_loop() -> WindowResult<()> {
 
         // Process deferred window events, then refresh the bar once.
         let mut needs_bar_update = false;
+        // A new title on the focused window runs no effects but is
+        // still reported to subscribers.
+        let retitled = events
+            .iter()
+            .any(|e| matches!(e, mosaico_core::WindowEvent::TitleChanged { .. }));
         for event in events.drain(..) {
             needs_bar_update |= daemon_loop_handlers::handle_event(
                 event,
@@ -187,6 +213,18 @@ pub(super) fn daemon_loop() -> WindowResult<()> {
         if needs_bar_update {
             bar_mgr.update(&manager.bar_states(&get_update(), hotkeys_paused));
         }
+
+        // Tell subscribers what the batch changed.
+        if hotkeys_paused != was_paused {
+            subscribers.publish(DaemonEvent::Paused {
+                paused: hotkeys_paused,
+            });
+        }
+        // Only diff against a new snapshot when the batch touched the
+        // tiling state.
+        if manager.take_changed() || reloaded || retitled {
+            subscribers.publish_changes(&manager);
+        }
     }
 
     manager.restore_all_windows();
@@ -194,6 +232,8 @@ pub(super) fn daemon_loop() -> WindowResult<()> {
     event_loop.stop();
     watcher_stop.store(true, std::sync::atomic::Ordering::Relaxed);
     drop(tx);
+    // Ends every event stream so subscribers see the daemon go away.
+    drop(subscribers);
     let _ = event_bridge.join();
     let _ = action_bridge.join();
     let _ = watcher_thread.join();
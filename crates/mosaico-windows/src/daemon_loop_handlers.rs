@@ -35,7 +35,7 @@ pub(super) fn handle_event(
                     .iter()
                     .map(|m| m.work_area)
                     .collect();
-                bar_mgr.rebuild_for_monitors(monitor_rects, *current_theme);
+                bar_mgr.rebuild_for_monitors(monitor_rects, manager.monitor_bars(), *current_theme);
                 true
             }
             Err(e) => {
@@ -103,6 +103,10 @@ pub(super) fn handle_command(
             Some(Response::ok())
         }
         Command::Inspect => Some(Response::ok_with_message(manager.inspect_state())),
+        Command::Query => Some(Response::ok_with_state(manager.snapshot())),
+        // `ipc::serve` answers the handshake itself and streams events
+        // for subscriptions instead of forwarding them here.
+        Command::Subscribe { .. } | Command::Hello => None,
         Command::PauseHotkeys => {
             if !*hotkeys_paused {
                 event_loop.pause_hotkeys();
@@ -137,6 +141,10 @@ pub(super) fn handle_reload(
         crate::config_watcher::ConfigReload::Config(cfg) => {
             *current_theme = cfg.theme.resolve();
             manager.reload_config(&cfg);
+            if bar_mgr.set_monitor_bars(manager.monitor_bars()) {
+                let indices = bar_mgr.bar_monitor_indices().to_vec();
+                manager.reset_and_adjust_work_areas(bar_mgr.bar_height(), &indices);
+            }
             event_loop.toggle_focus_follows_mouse(cfg.mouse.focus_follows_mouse);
             // Theme may have changed — re-resolve bar colors.
             bar_mgr.resolve_colors(*current_theme);
@@ -145,6 +153,9 @@ pub(super) fn handle_reload(
         crate::config_watcher::ConfigReload::Rules(rules) => {
             manager.reload_rules(rules);
         }
+        crate::config_watcher::ConfigReload::Layouts(layouts) => {
+            manager.reload_layouts(layouts);
+        }
         crate::config_watcher::ConfigReload::Bar(bar_cfg) => {
             let new_height = bar_mgr.reload(*bar_cfg);
             bar_mgr.resolve_colors(*current_theme);
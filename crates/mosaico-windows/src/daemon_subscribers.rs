@@ -0,0 +1,69 @@
+use mosaico_core::{DaemonEvent, EventTopic, StateSnapshot};
+
+use crate::tiling::TilingManager;
+
+use super::daemon_types::EventSender;
+
+/// A connected client waiting for events.
+struct Subscriber {
+    /// Topics the client asked for; empty means all.
+    topics: Vec<EventTopic>,
+    tx: EventSender,
+}
+
+impl Subscriber {
+    fn wants(&self, topic: EventTopic) -> bool {
+        self.topics.is_empty() || self.topics.contains(&topic)
+    }
+}
+
+/// Clients subscribed to daemon events, and the state last reported
+/// to them.
+#[derive(Default)]
+pub(super) struct Subscribers {
+    list: Vec<Subscriber>,
+    /// Baseline for the next diff. Only kept while someone listens, so
+    /// the daemon takes no snapshots otherwise.
+    last_state: Option<StateSnapshot>,
+}
+
+impl Subscribers {
+    /// Registers a subscriber.
+    pub(super) fn add(
+        &mut self,
+        topics: Vec<EventTopic>,
+        tx: EventSender,
+        manager: &TilingManager,
+    ) {
+        if self.last_state.is_none() {
+            self.last_state = Some(manager.snapshot());
+        }
+        mosaico_core::log_info!("Event subscriber connected ({} total)", self.list.len() + 1);
+        self.list.push(Subscriber { topics, tx });
+    }
+
+    /// Sends `event` to every subscriber of its topic, dropping clients
+    /// that have disconnected.
+    pub(super) fn publish(&mut self, event: DaemonEvent) {
+        let topic = event.topic();
+        self.list
+            .retain(|s| !s.wants(topic) || s.tx.send(event.clone()).is_ok());
+        if self.list.is_empty() {
+            self.last_state = None;
+        }
+    }
+
+    /// Publishes how the tiling state changed since the last call.
+    pub(super) fn publish_changes(&mut self, manager: &TilingManager) {
+        let Some(old) = self.last_state.take() else {
+            return;
+        };
+        let new = manager.snapshot();
+        for event in DaemonEvent::changes(&old, &new) {
+            self.publish(event);
+        }
+        if !self.list.is_empty() {
+            self.last_state = Some(new);
+        }
+    }
+}
@@ -1,7 +1,7 @@
 use std::sync::mpsc;
 
-use mosaico_core::Action;
 use mosaico_core::ipc::{Command, Response};
+use mosaico_core::{Action, DaemonEvent, EventTopic};
 
 use crate::config_watcher::ConfigReload;
 
@@ -13,6 +13,8 @@ pub(super) enum DaemonMsg {
     Action(Action),
     /// A CLI command with a callback to send the response.
     Command(Command, ResponseSender),
+    /// A new event subscriber and the topics it wants.
+    Subscribe(Vec<EventTopic>, EventSender),
     /// A validated config reload from the file watcher.
     Reload(Box<ConfigReload>),
     /// 1-second tick for refreshing bar system widgets.
@@ -21,3 +23,6 @@ pub(super) enum DaemonMsg {
 
 /// Sends a response back to the IPC thread for the connected client.
 pub(super) type ResponseSender = mpsc::Sender<Response>;
+
+/// Sends events to the IPC thread streaming them to one subscriber.
+pub(super) type EventSender = mpsc::Sender<DaemonEvent>;
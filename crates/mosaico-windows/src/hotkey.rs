@@ -61,7 +61,7 @@ impl HotkeyManager {
                 modifiers |= modifier_to_flag(m);
             }
 
-            self.register(id, modifiers, vk, binding.action);
+            self.register(id, modifiers, vk, binding.action.clone());
         }
 
         self.pause_hotkey_id = self
@@ -76,7 +76,7 @@ impl HotkeyManager {
     /// Called from the message pump when a `WM_HOTKEY` message arrives.
     pub fn dispatch(&self, hotkey_id: i32) {
         if let Some(hotkey) = self.hotkeys.iter().find(|h| h.id == hotkey_id) {
-            let _ = self.sender.send(hotkey.action);
+            let _ = self.sender.send(hotkey.action.clone());
         }
     }
 
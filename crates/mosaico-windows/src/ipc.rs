@@ -1,107 +1,257 @@
-use std::io::{BufRead, BufReader, Write};
+use std::io::BufReader;
 use std::os::windows::io::FromRawHandle;
+use std::time::{Duration, Instant};
 
-use mosaico_core::WindowResult;
-use mosaico_core::ipc::{Command, Response};
+use mosaico_core::ipc::transport::{read_line_from, write_line_to};
+use mosaico_core::ipc::{
+    self, Command, DEFAULT_TIMEOUT, HelloInfo, IpcConnection, IpcError, IpcListener, IpcTransport,
+    Response,
+};
+use mosaico_core::{DaemonEvent, EventTopic, WindowResult};
 
 /// The named pipe path used for IPC between CLI and daemon.
 const PIPE_NAME: &str = r"\\.\pipe\mosaico";
 use windows::Win32::Foundation::{
-    CloseHandle, DUPLICATE_SAME_ACCESS, DuplicateHandle, HANDLE, INVALID_HANDLE_VALUE,
+    CloseHandle, DUPLICATE_SAME_ACCESS, DuplicateHandle, ERROR_PIPE_BUSY, ERROR_PIPE_CONNECTED,
+    HANDLE, INVALID_HANDLE_VALUE,
 };
 use windows::Win32::Storage::FileSystem::{
     CreateFileW, FILE_SHARE_NONE, FlushFileBuffers, OPEN_EXISTING, PIPE_ACCESS_DUPLEX,
 };
 use windows::Win32::System::Pipes::{
     ConnectNamedPipe, CreateNamedPipeW, DisconnectNamedPipe, PIPE_READMODE_BYTE, PIPE_TYPE_BYTE,
-    PIPE_UNLIMITED_INSTANCES, PIPE_WAIT, WaitNamedPipeW,
+    PIPE_UNLIMITED_INSTANCES, PIPE_WAIT, PeekNamedPipe, WaitNamedPipeW,
 };
 use windows::Win32::System::Threading::GetCurrentProcess;
 use windows::core::HSTRING;
 
 const GENERIC_READ_WRITE: u32 = 0x80000000 | 0x40000000;
 
-/// A Named Pipe server that the daemon uses to accept CLI connections.
-///
-/// The server creates a pipe, waits for a client to connect, reads one
-/// command, and returns a response. Each connection handles one request.
-pub struct PipeServer {
-    handle: HANDLE,
+/// How often a read with a timeout checks the pipe for data (ms).
+const POLL_INTERVAL_MS: u64 = 10;
+
+/// IPC over a Windows named pipe.
+#[derive(Debug, Clone)]
+pub struct NamedPipeTransport {
+    name: String,
 }
 
-impl PipeServer {
-    /// Creates a new Named Pipe server.
-    ///
-    /// This creates the pipe but does not yet wait for connections.
-    pub fn create() -> WindowResult<Self> {
-        let pipe_name = HSTRING::from(PIPE_NAME);
-
-        // SAFETY: CreateNamedPipeW creates a new named pipe instance.
-        // We pass valid parameters and check for INVALID_HANDLE_VALUE.
-        let handle = unsafe {
-            CreateNamedPipeW(
-                &pipe_name,
-                PIPE_ACCESS_DUPLEX,
-                PIPE_TYPE_BYTE | PIPE_READMODE_BYTE | PIPE_WAIT,
-                PIPE_UNLIMITED_INSTANCES,
-                512, // output buffer size
-                512, // input buffer size
-                0,   // default timeout
-                None,
-            )
-        };
-
-        if handle == INVALID_HANDLE_VALUE {
-            return Err("Failed to create named pipe".into());
-        }
+impl NamedPipeTransport {
+    /// Creates a transport for the pipe at `name`, e.g. `\\.\pipe\mosaico`.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self { name: name.into() }
+    }
+}
 
-        Ok(Self { handle })
+impl Default for NamedPipeTransport {
+    fn default() -> Self {
+        Self::new(PIPE_NAME)
     }
+}
 
-    /// Blocks until a client connects, reads a command, and returns it.
-    pub fn accept_command(&self) -> WindowResult<Command> {
-        // SAFETY: ConnectNamedPipe blocks until a client connects.
-        unsafe {
-            ConnectNamedPipe(self.handle, None)?;
+impl IpcTransport for NamedPipeTransport {
+    type Connection = PipeConnection;
+    type Listener = PipeListener;
+
+    /// Pipe instances are created per client, so binding cannot fail.
+    fn bind(&self) -> Result<Self::Listener, IpcError> {
+        Ok(PipeListener {
+            name: HSTRING::from(&self.name),
+        })
+    }
+
+    /// Opens the pipe as a client, waiting up to `timeout` while every
+    /// instance is busy serving other clients.
+    fn connect(&self, timeout: Duration) -> Result<Self::Connection, IpcError> {
+        let pipe_name = HSTRING::from(&self.name);
+        let deadline = Instant::now() + timeout;
+
+        loop {
+            // SAFETY: CreateFileW opens an existing named pipe as a client.
+            let result = unsafe {
+                CreateFileW(
+                    &pipe_name,
+                    GENERIC_READ_WRITE,
+                    FILE_SHARE_NONE,
+                    None,
+                    OPEN_EXISTING,
+                    Default::default(),
+                    None,
+                )
+            };
+            match result {
+                Ok(handle) => return PipeConnection::new(handle, false),
+                Err(e) if e.code() == ERROR_PIPE_BUSY.to_hresult() => {
+                    let remaining = deadline.saturating_duration_since(Instant::now());
+                    let ms = u32::try_from(remaining.as_millis()).unwrap_or(u32::MAX);
+                    // SAFETY: WaitNamedPipeW blocks until an instance is
+                    // free or the timeout elapses.
+                    if ms == 0 || !unsafe { WaitNamedPipeW(&pipe_name, ms) }.as_bool() {
+                        return Err(IpcError::Timeout);
+                    }
+                }
+                Err(e) => return Err(std::io::Error::from(e).into()),
+            }
         }
+    }
 
-        let reader = duplicate_handle_as_file(self.handle)?;
-        let mut buf_reader = BufReader::new(reader);
-        let mut line = String::new();
-        buf_reader.read_line(&mut line)?;
+    /// Uses `WaitNamedPipeW` with a 1 ms timeout instead of `CreateFileW`.
+    /// This avoids consuming a pipe connection — it only checks whether the
+    /// pipe exists without actually connecting to it.
+    fn is_listening(&self) -> bool {
+        let pipe_name = HSTRING::from(&self.name);
 
-        let command: Command = serde_json::from_str(line.trim())?;
-        Ok(command)
+        // SAFETY: WaitNamedPipeW checks whether a pipe instance is available.
+        // A timeout of 1 ms means we return almost immediately.
+        // Returns BOOL — as_bool() converts to a native Rust bool.
+        unsafe { WaitNamedPipeW(&pipe_name, 1).as_bool() }
     }
+}
 
-    /// Sends a response back to the connected client and disconnects.
-    pub fn send_response(&self, response: &Response) -> WindowResult<()> {
-        let mut writer = duplicate_handle_as_file(self.handle)?;
-        let json = serde_json::to_string(response)?;
-        writeln!(writer, "{json}")?;
-        writer.flush()?;
+/// The daemon side of the named pipe.
+///
+/// Each `accept()` creates a fresh pipe instance and waits for a client
+/// on it, so other clients can connect while one is being served.
+pub struct PipeListener {
+    name: HSTRING,
+}
 
-        // SAFETY: FlushFileBuffers blocks until the client has read all
-        // data from the pipe. Without this, DisconnectNamedPipe would
-        // discard unread data, causing the client to get error 233.
-        unsafe {
-            let _ = FlushFileBuffers(self.handle);
+impl IpcListener for PipeListener {
+    type Connection = PipeConnection;
+
+    fn accept(&mut self) -> Result<Self::Connection, IpcError> {
+        loop {
+            // SAFETY: CreateNamedPipeW creates a new named pipe instance.
+            // We pass valid parameters and check for INVALID_HANDLE_VALUE.
+            let handle = unsafe {
+                CreateNamedPipeW(
+                    &self.name,
+                    PIPE_ACCESS_DUPLEX,
+                    PIPE_TYPE_BYTE | PIPE_READMODE_BYTE | PIPE_WAIT,
+                    PIPE_UNLIMITED_INSTANCES,
+                    512, // output buffer size
+                    512, // input buffer size
+                    0,   // default timeout
+                    None,
+                )
+            };
+
+            if handle == INVALID_HANDLE_VALUE {
+                return Err(std::io::Error::last_os_error().into());
+            }
+
+            // SAFETY: ConnectNamedPipe blocks until a client connects.
+            // ERROR_PIPE_CONNECTED means one connected before the call.
+            match unsafe { ConnectNamedPipe(handle, None) } {
+                Ok(()) => return PipeConnection::new(handle, true),
+                Err(e) if e.code() == ERROR_PIPE_CONNECTED.to_hresult() => {
+                    return PipeConnection::new(handle, true);
+                }
+                Err(e) => {
+                    // The client vanished; wait for the next one.
+                    mosaico_core::log_debug!("ConnectNamedPipe failed: {e}");
+                    // SAFETY: the instance is ours and no longer needed.
+                    unsafe {
+                        let _ = CloseHandle(handle);
+                    }
+                }
+            }
         }
+    }
+}
 
-        // SAFETY: DisconnectNamedPipe disconnects the server side so it
-        // can accept new connections.
-        unsafe {
-            DisconnectNamedPipe(self.handle)?;
+/// One end of a named pipe connection.
+///
+/// Reads and writes go through duplicated handles wrapped as files; the
+/// original handle is closed on drop, after the daemon side has flushed
+/// and disconnected.
+pub struct PipeConnection {
+    handle: HANDLE,
+    reader: BufReader<std::fs::File>,
+    writer: std::fs::File,
+    read_timeout: Option<Duration>,
+    /// Whether this is the daemon's end of the pipe.
+    server: bool,
+}
+
+// SAFETY: the pipe handle is a kernel object usable from any thread.
+// A connection is moved to a streaming thread for subscriptions and is
+// never used from two threads at once.
+unsafe impl Send for PipeConnection {}
+
+impl PipeConnection {
+    fn new(handle: HANDLE, server: bool) -> Result<Self, IpcError> {
+        let guard = HandleGuard(handle);
+        let reader = BufReader::new(duplicate_handle_as_file(handle)?);
+        let writer = duplicate_handle_as_file(handle)?;
+        std::mem::forget(guard);
+        Ok(Self {
+            handle,
+            reader,
+            writer,
+            read_timeout: None,
+            server,
+        })
+    }
+
+    /// Polls until the pipe has data to read or `timeout` elapses.
+    ///
+    /// Synchronous pipe reads cannot time out, so this peeks instead. A
+    /// failed peek (e.g. a broken pipe) returns `Ok` and leaves the error
+    /// to the read itself.
+    fn wait_readable(&self, timeout: Duration) -> Result<(), IpcError> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            let mut available = 0u32;
+            // SAFETY: PeekNamedPipe only reports how many bytes are
+            // buffered; it does not consume them.
+            let peeked =
+                unsafe { PeekNamedPipe(self.handle, None, 0, None, Some(&mut available), None) };
+            if peeked.is_err() || available > 0 {
+                return Ok(());
+            }
+            if Instant::now() >= deadline {
+                return Err(IpcError::Timeout);
+            }
+            std::thread::sleep(Duration::from_millis(POLL_INTERVAL_MS));
         }
+    }
+}
 
+impl IpcConnection for PipeConnection {
+    fn write_line(&mut self, line: &str) -> Result<(), IpcError> {
+        write_line_to(&mut self.writer, line)
+    }
+
+    fn read_line(&mut self) -> Result<Option<String>, IpcError> {
+        if let Some(timeout) = self.read_timeout
+            && self.reader.buffer().is_empty()
+        {
+            self.wait_readable(timeout)?;
+        }
+        read_line_from(&mut self.reader)
+    }
+
+    fn set_read_timeout(&mut self, timeout: Option<Duration>) -> Result<(), IpcError> {
+        self.read_timeout = timeout;
         Ok(())
     }
 }
 
-impl Drop for PipeServer {
+impl Drop for PipeConnection {
     fn drop(&mut self) {
-        // SAFETY: CloseHandle releases the pipe handle when the server
-        // is dropped.
+        if self.server {
+            // SAFETY: FlushFileBuffers blocks until the client has read all
+            // data from the pipe. Without this, DisconnectNamedPipe would
+            // discard unread data, causing the client to get error 233.
+            // DisconnectNamedPipe then ends the client's connection.
+            unsafe {
+                let _ = FlushFileBuffers(self.handle);
+                let _ = DisconnectNamedPipe(self.handle);
+            }
+        }
+        // SAFETY: CloseHandle releases the original pipe handle; the
+        // duplicates are closed by their files.
         unsafe {
             let _ = CloseHandle(self.handle);
         }
@@ -123,62 +273,47 @@ impl Drop for HandleGuard {
 
 /// Sends a command to the daemon over the named pipe and returns the response.
 ///
-/// This is used by the CLI (client side). The pipe handle is closed
-/// automatically when the guard goes out of scope, even on error paths.
+/// This is used by the CLI (client side).
 pub fn send_command(command: &Command) -> WindowResult<Response> {
-    let pipe_name = HSTRING::from(PIPE_NAME);
-
-    // SAFETY: CreateFileW opens an existing named pipe as a client.
-    let handle = unsafe {
-        CreateFileW(
-            &pipe_name,
-            GENERIC_READ_WRITE,
-            FILE_SHARE_NONE,
-            None,
-            OPEN_EXISTING,
-            Default::default(),
-            None,
-        )?
-    };
-
-    let _guard = HandleGuard(handle);
-
-    let json = serde_json::to_string(command)?;
-
-    // Write the command
-    let mut writer = duplicate_handle_as_file(handle)?;
-    writeln!(writer, "{json}")?;
-    writer.flush()?;
+    Ok(ipc::send_command(
+        &NamedPipeTransport::default(),
+        command,
+        DEFAULT_TIMEOUT,
+    )?)
+}
 
-    // Read the response
-    let reader = duplicate_handle_as_file(handle)?;
-    let mut buf_reader = BufReader::new(reader);
-    let mut response_line = String::new();
-    buf_reader.read_line(&mut response_line)?;
+/// Asks the daemon for its protocol version and capabilities.
+pub fn hello() -> WindowResult<HelloInfo> {
+    Ok(ipc::hello(&NamedPipeTransport::default(), DEFAULT_TIMEOUT)?)
+}
 
-    let response: Response = serde_json::from_str(response_line.trim())?;
-    Ok(response)
+/// Subscribes to daemon events and calls `on_event` for each one.
+///
+/// An empty `topics` list subscribes to every topic. Blocks until the
+/// daemon closes the stream (e.g. on shutdown) or `on_event` returns
+/// `false`.
+pub fn subscribe(
+    topics: Vec<EventTopic>,
+    on_event: impl FnMut(DaemonEvent) -> bool,
+) -> WindowResult<()> {
+    Ok(ipc::subscribe(
+        &NamedPipeTransport::default(),
+        topics,
+        DEFAULT_TIMEOUT,
+        on_event,
+    )?)
 }
 
 /// Checks if the daemon's named pipe exists (i.e. the daemon is running).
-///
-/// Uses `WaitNamedPipeW` with a 1 ms timeout instead of `CreateFileW`.
-/// This avoids consuming a pipe connection — it only checks whether the
-/// pipe exists without actually connecting to it.
 pub fn is_daemon_running() -> bool {
-    let pipe_name = HSTRING::from(PIPE_NAME);
-
-    // SAFETY: WaitNamedPipeW checks whether a pipe instance is available.
-    // A timeout of 1 ms means we return almost immediately.
-    // Returns BOOL — as_bool() converts to a native Rust bool.
-    unsafe { WaitNamedPipeW(&pipe_name, 1).as_bool() }
+    NamedPipeTransport::default().is_listening()
 }
 
 /// Duplicates a HANDLE and wraps it as a `std::fs::File`.
 ///
 /// We duplicate instead of converting directly so that the original handle
 /// and the File can be closed independently — avoids double-close bugs.
-fn duplicate_handle_as_file(handle: HANDLE) -> WindowResult<std::fs::File> {
+fn duplicate_handle_as_file(handle: HANDLE) -> std::io::Result<std::fs::File> {
     let mut dup = HANDLE::default();
 
     // SAFETY: DuplicateHandle creates a copy of the handle. The duplicate
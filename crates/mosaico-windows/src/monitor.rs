@@ -4,17 +4,11 @@ use mosaico_core::{Rect, WindowResult};
 use windows::Win32::Foundation::{HWND, LPARAM, RECT};
 use windows::Win32::Graphics::Gdi::{
     EnumDisplayMonitors, GetMonitorInfoW, HDC, HMONITOR, MONITOR_DEFAULTTOPRIMARY, MONITORINFO,
-    MonitorFromWindow,
+    MONITORINFOEXW, MonitorFromWindow,
 };
 use windows::core::BOOL;
 
-/// Information about a connected monitor.
-pub struct MonitorInfo {
-    /// Unique identifier (HMONITOR cast to usize).
-    pub id: usize,
-    /// Work area excluding the taskbar and docked toolbars.
-    pub work_area: Rect,
-}
+pub use mosaico_core::tiling::MonitorInfo;
 
 /// Returns the monitor ID for the monitor containing the given window.
 ///
@@ -61,11 +55,8 @@ unsafe extern "system" fn monitor_enum_callback(
     // SAFETY: lparam is a pointer to our Vec<MonitorInfo>.
     let monitors = unsafe { &mut *(lparam.0 as *mut Vec<MonitorInfo>) };
 
-    if let Ok(work_area) = work_area_for_monitor(hmonitor) {
-        monitors.push(MonitorInfo {
-            id: hmonitor.0 as usize,
-            work_area,
-        });
+    if let Ok(info) = monitor_info(hmonitor) {
+        monitors.push(info);
     }
 
     BOOL(1) // Continue enumeration
@@ -77,20 +68,34 @@ pub(crate) fn rect_from_win32(r: &RECT) -> Rect {
     Rect::new(r.left, r.top, r.right - r.left, r.bottom - r.top)
 }
 
-/// Queries the work area for a given monitor handle.
-fn work_area_for_monitor(monitor: HMONITOR) -> WindowResult<Rect> {
-    let mut info = MONITORINFO {
-        cbSize: mem::size_of::<MONITORINFO>() as u32,
+/// Queries the work area, bounds and device name for a monitor handle.
+fn monitor_info(monitor: HMONITOR) -> WindowResult<MonitorInfo> {
+    let mut info = MONITORINFOEXW {
+        monitorInfo: MONITORINFO {
+            cbSize: mem::size_of::<MONITORINFOEXW>() as u32,
+            ..Default::default()
+        },
         ..Default::default()
     };
 
-    // SAFETY: GetMonitorInfoW fills the MONITORINFO struct with
-    // monitor dimensions. We set cbSize as required by the API.
-    let success = unsafe { GetMonitorInfoW(monitor, &mut info) };
+    // SAFETY: GetMonitorInfoW fills the MONITORINFOEXW struct with
+    // monitor dimensions and the device name. cbSize tells it the
+    // extended struct is being passed, as required by the API.
+    let success = unsafe { GetMonitorInfoW(monitor, &mut info.monitorInfo) };
 
     if !success.as_bool() {
         return Err("Failed to get monitor info".into());
     }
 
-    Ok(rect_from_win32(&info.rcWork))
+    let len = info
+        .szDevice
+        .iter()
+        .position(|&c| c == 0)
+        .unwrap_or(info.szDevice.len());
+    Ok(MonitorInfo {
+        id: monitor.0 as usize,
+        work_area: rect_from_win32(&info.monitorInfo.rcWork),
+        name: String::from_utf16_lossy(&info.szDevice[..len]),
+        bounds: rect_from_win32(&info.monitorInfo.rcMonitor),
+    })
 }
@@ -0,0 +1,75 @@
+//! Win32 implementation of the tiling [`Desktop`] queries.
+
+use mosaico_core::window::Window as WindowTrait;
+use mosaico_core::{Desktop, Rect};
+
+use crate::monitor;
+use crate::window::Window;
+
+/// Answers the tiling state machine's window queries via Win32.
+pub(super) struct Win32Desktop {
+    /// Whether the current process (mosaico) is running elevated.
+    ///
+    /// When `false`, elevated windows are not manageable because
+    /// `SetWindowPos` silently fails across the UIPI boundary.
+    pub(super) self_elevated: bool,
+}
+
+impl Desktop for Win32Desktop {
+    fn is_manageable(&self, hwnd: usize) -> bool {
+        let window = Window::from_raw(hwnd);
+        if !window.is_visible() || !window.is_app_window() || window.is_cloaked() {
+            return false;
+        }
+        // When mosaico runs as a regular user, SetWindowPos silently fails
+        // on windows owned by elevated processes (UIPI). Skip them.
+        if !self.self_elevated && window.is_elevated() {
+            let title = window.title().unwrap_or_default();
+            let class = window.class().unwrap_or_default();
+            mosaico_core::log_info!(
+                "~skip 0x{:X} [{}] \"{}\" (elevated, mosaico is not)",
+                hwnd,
+                class,
+                title,
+            );
+            return false;
+        }
+        true
+    }
+
+    fn class(&self, hwnd: usize) -> String {
+        Window::from_raw(hwnd).class().unwrap_or_default()
+    }
+
+    fn title(&self, hwnd: usize) -> String {
+        Window::from_raw(hwnd).title().unwrap_or_default()
+    }
+
+    fn exe_path(&self, hwnd: usize) -> String {
+        Window::from_raw(hwnd).exe_path().unwrap_or_default()
+    }
+
+    fn is_visible(&self, hwnd: usize) -> bool {
+        Window::from_raw(hwnd).is_visible()
+    }
+
+    fn is_minimized(&self, hwnd: usize) -> bool {
+        Window::from_raw(hwnd).is_minimized()
+    }
+
+    fn is_maximized(&self, hwnd: usize) -> bool {
+        Window::from_raw(hwnd).is_maximized()
+    }
+
+    fn rect(&self, hwnd: usize) -> Option<Rect> {
+        Window::from_raw(hwnd).rect().ok()
+    }
+
+    fn monitor_id(&self, hwnd: usize) -> usize {
+        monitor::monitor_id_for_window(hwnd)
+    }
+
+    fn owner(&self, hwnd: usize) -> Option<usize> {
+        Window::from_raw(hwnd).owner()
+    }
+}
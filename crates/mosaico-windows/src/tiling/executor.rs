@@ -0,0 +1,144 @@
+//! Executes tiling [`Effect`]s against Win32.
+
+use mosaico_core::Effect;
+use mosaico_core::config::HidingBehaviour;
+use mosaico_core::window::Window as WindowTrait;
+use windows::Win32::Foundation::{HWND, LPARAM, WPARAM};
+use windows::Win32::UI::WindowsAndMessaging::{PostMessageW, SetCursorPos, WM_CLOSE};
+
+use crate::border::Color;
+use crate::frame;
+use crate::window::Window;
+
+use super::TilingManager;
+
+impl TilingManager {
+    /// Applies the effects returned by the state machine, in order.
+    pub(super) fn execute(&mut self, effects: Vec<Effect>) {
+        self.changed |= !effects.is_empty();
+        for effect in effects {
+            self.execute_one(effect);
+        }
+    }
+
+    fn execute_one(&self, effect: Effect) {
+        match effect {
+            Effect::SetRect { hwnd, rect } => {
+                let window = Window::from_raw(hwnd);
+                // Always call set_rect for maximized windows — it clears
+                // WS_MAXIMIZE which SetWindowPos alone doesn't remove.
+                if !window.is_maximized() && window.rect().ok().as_ref() == Some(&rect) {
+                    return;
+                }
+                if let Err(e) = window.set_rect(&rect) {
+                    eprintln!("Failed to position window 0x{hwnd:X}: {e}");
+                }
+                window.invalidate();
+            }
+            Effect::Hide { hwnd, hiding } => {
+                let win = Window::from_raw(hwnd);
+                match hiding {
+                    HidingBehaviour::Cloak => win.cloak(),
+                    HidingBehaviour::Hide => win.hide(),
+                    HidingBehaviour::Minimize => win.minimize(),
+                }
+            }
+            Effect::Show { hwnd, hiding } => {
+                let win = Window::from_raw(hwnd);
+                match hiding {
+                    HidingBehaviour::Cloak => win.uncloak(),
+                    HidingBehaviour::Hide | HidingBehaviour::Minimize => win.show(),
+                }
+            }
+            Effect::Restore { hwnd } => {
+                let win = Window::from_raw(hwnd);
+                win.uncloak();
+                win.force_show();
+            }
+            Effect::Focus { hwnd } => Window::from_raw(hwnd).set_foreground(),
+            Effect::MoveCursor { hwnd } => move_cursor_to_window(hwnd),
+            Effect::ShowBorder { hwnd, monocle } => self.show_border(hwnd, monocle),
+            Effect::HideBorder => {
+                if let Some(border) = &self.border {
+                    border.hide();
+                }
+            }
+            Effect::Close { hwnd } => {
+                // Send WM_CLOSE so the window can handle its own shutdown gracefully.
+                // SAFETY: PostMessageW sends WM_CLOSE to a valid HWND owned by this process's focused window.
+                unsafe {
+                    let _ =
+                        PostMessageW(Some(HWND(hwnd as *mut _)), WM_CLOSE, WPARAM(0), LPARAM(0));
+                }
+            }
+            Effect::Minimize { hwnd } => {
+                // ShowWindow(SW_MINIMIZE) fires EVENT_SYSTEM_MINIMIZESTART which
+                // the event loop translates to WindowEvent::Minimized. That event
+                // removes the window from the active workspace and re-tiles.
+                Window::from_raw(hwnd).minimize();
+            }
+            Effect::SetCorners { hwnd, style } => {
+                frame::set_corner_preference(Window::from_raw(hwnd).hwnd(), style);
+            }
+            Effect::ResetCorners { hwnd } => {
+                frame::reset_corner_preference(Window::from_raw(hwnd).hwnd());
+            }
+        }
+    }
+
+    fn show_border(&self, hwnd: usize, monocle: bool) {
+        let Some(border) = &self.border else {
+            return;
+        };
+        let window = Window::from_raw(hwnd);
+        // Hide the border when the focused window is maximized or minimized —
+        // the border would be invisible or orphaned on screen.
+        if window.is_maximized() || window.is_minimized() {
+            border.hide();
+            return;
+        }
+        let Ok(rect) = window.rect() else {
+            return;
+        };
+        let config = self.state.border_config();
+        let hex = if monocle {
+            &config.monocle
+        } else {
+            &config.focused
+        };
+        let color = Color::from_hex(hex).unwrap_or(Color {
+            r: 0,
+            g: 0xB4,
+            b: 0xD8,
+        });
+        mosaico_core::log_debug!(
+            "border.show 0x{:X} at ({},{} {}x{}) mon={}",
+            hwnd,
+            rect.x,
+            rect.y,
+            rect.width,
+            rect.height,
+            self.state.focused_monitor()
+        );
+        border.show(
+            &rect,
+            color,
+            config.width,
+            config.corner_style.border_radius(),
+            window.hwnd(),
+        );
+    }
+}
+
+/// Moves the cursor to the center of the given window.
+fn move_cursor_to_window(hwnd: usize) {
+    let Ok(rect) = Window::from_raw(hwnd).rect() else {
+        return;
+    };
+    let cx = rect.x + rect.width / 2;
+    let cy = rect.y + rect.height / 2;
+    // SAFETY: SetCursorPos is safe to call with screen coordinates.
+    unsafe {
+        let _ = SetCursorPos(cx, cy);
+    }
+}
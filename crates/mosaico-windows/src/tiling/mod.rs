@@ -1,206 +1,102 @@
-mod display;
-mod event_handler;
-mod focus;
-mod helpers;
-mod layout;
-mod lifecycle;
-mod navigation;
-mod navigation_helpers;
-mod workspace;
+//! Win32 front end for the platform-agnostic tiling state machine.
+//!
+//! All tiling decisions live in [`mosaico_core::TilingState`]. This
+//! module feeds it events and actions, answers its window queries via
+//! [`Win32Desktop`], and executes the returned effects.
 
-use std::collections::HashSet;
-use std::time::Instant;
+mod desktop;
+mod executor;
 
-use mosaico_core::action::MAX_WORKSPACES;
-use mosaico_core::config::{BorderConfig, HidingBehaviour, LayoutConfig, WindowRule};
-use mosaico_core::{Action, Rect, WindowResult, Workspace};
+use mosaico_core::config::{
+    BorderConfig, Config, LayoutConfig, MonitorConfig, WindowRule, WorkspacesConfig,
+};
+use mosaico_core::{Action, LayoutDefinition, TilingState, WindowEvent, WindowResult};
 
 use crate::bar::BarState;
 use crate::border::Border;
 use crate::enumerate;
-use crate::frame;
-use crate::monitor;
-use crate::window::Window;
+use crate::monitor::{self, MonitorInfo};
 
-/// Per-monitor state: multiple workspaces and work area.
-pub(super) struct MonitorState {
-    pub(super) id: usize,
-    pub(super) work_area: Rect,
-    pub(super) workspaces: Vec<Workspace>,
-    pub(super) active_workspace: usize,
-}
-
-impl MonitorState {
-    fn active_ws(&self) -> &Workspace {
-        &self.workspaces[self.active_workspace]
-    }
-
-    fn active_ws_mut(&mut self) -> &mut Workspace {
-        &mut self.workspaces[self.active_workspace]
-    }
-}
-
-/// Result of resolving a directional (H/L) spatial action.
-///
-/// Used by both focus and move operations to share the spatial
-/// neighbor lookup and monitor-overflow logic.
-enum SpatialTarget {
-    /// A neighbor window on the same monitor.
-    Neighbor(usize),
-    /// The adjacent monitor when no same-monitor neighbor exists.
-    AdjacentMonitor(usize),
-}
+use desktop::Win32Desktop;
 
 /// Manages tiled windows across all connected monitors.
 pub struct TilingManager {
-    monitors: Vec<MonitorState>,
-    layout_gap: i32,
-    layout_ratio: f64,
-    rules: Vec<WindowRule>,
+    state: TilingState,
+    desktop: Win32Desktop,
     border: Option<Border>,
-    border_config: BorderConfig,
-    focused_monitor: usize,
-    focused_window: Option<usize>,
-    /// Suppresses `Moved` event handling during programmatic layout.
-    applying_layout: bool,
-    /// Tracks whether the focused window is currently maximized.
-    ///
-    /// Used to detect maximize/restore transitions from
-    /// `LocationChanged` events without calling `update_border()`
-    /// on every animation frame.
-    focused_maximized: bool,
-    /// Whether keyboard-driven focus should move the cursor.
-    mouse_follows_focus: bool,
-    /// Suppresses cursor moves after mouse-driven focus.
-    focus_from_mouse: bool,
-    /// How windows are hidden during workspace switches.
-    hiding: HidingBehaviour,
-    /// Windows hidden programmatically by workspace switching.
-    ///
-    /// Events for these hwnds are ignored until they are shown again.
-    /// This prevents `EVENT_OBJECT_HIDE` from removing windows that
-    /// were just hidden by a workspace switch.
-    hidden_by_switch: HashSet<usize>,
-    /// Cooldown timestamp suppressing focus-triggered workspace switches.
-    ///
-    /// Win32 events fired during a workspace switch (e.g. by
-    /// `set_foreground()` or `SetWindowLongW` inside `set_rect`)
-    /// are queued asynchronously and may arrive after the switch
-    /// completes.  Checking a boolean guard is insufficient because
-    /// the flag is cleared before these deferred events are processed.
-    ///
-    /// Instead we record the `Instant` when the switch finishes and
-    /// suppress focus-triggered switches until a short cooldown
-    /// elapses, preventing infinite workspace-switching loops.
-    ws_switch_cooldown: Option<Instant>,
-    /// Whether the current process (mosaico) is running elevated.
-    ///
-    /// When `false`, elevated windows are skipped in `is_tileable`
-    /// because `SetWindowPos` silently fails across the UIPI boundary.
-    self_elevated: bool,
-    /// Windows that `try_adopt` already rejected via `is_tileable`.
-    ///
-    /// Prevents re-evaluating expensive Win32 queries (visibility,
-    /// elevation, class, title) on every deferred event for windows
-    /// that will never be managed (e.g. elevated Visual Studio).
-    /// Cleared on rule reload; entries removed on `Destroyed`.
-    adopt_rejected: HashSet<usize>,
+    /// Whether any effects ran since the last [`Self::take_changed`].
+    changed: bool,
 }
 
 impl TilingManager {
-    /// Creates a new tiling manager with the given layout, rules, and borders.
-    #[allow(clippy::too_many_arguments)]
+    /// Creates a new tiling manager with the given layout, workspaces,
+    /// per-monitor overrides, custom layouts, rules, and borders.
     pub fn new(
         layout_config: &LayoutConfig,
+        workspaces_config: &WorkspacesConfig,
+        monitor_configs: &[MonitorConfig],
+        layouts: Vec<LayoutDefinition>,
         rules: Vec<WindowRule>,
         border_config: BorderConfig,
         mouse_follows_focus: bool,
     ) -> WindowResult<Self> {
-        let monitors: Vec<MonitorState> = monitor::enumerate_monitors()?
-            .into_iter()
-            .map(|info| MonitorState {
-                id: info.id,
-                work_area: info.work_area,
-                workspaces: (0..MAX_WORKSPACES)
-                    .map(|i| {
-                        let ws_num = i + 1;
-                        let kind = layout_config
-                            .workspaces
-                            .get(&ws_num)
-                            .copied()
-                            .unwrap_or(layout_config.default);
-                        Workspace::with_layout(kind)
-                    })
-                    .collect(),
-                active_workspace: 0,
-            })
-            .collect();
-
-        let border = Border::new().ok();
-        let self_elevated = crate::process::is_current_process_elevated();
-
-        let mut manager = Self {
-            monitors,
-            layout_gap: layout_config.gap,
-            layout_ratio: layout_config.ratio,
+        let monitors = monitor::enumerate_monitors()?;
+        let mut state = TilingState::new(
+            layout_config,
+            workspaces_config,
+            monitor_configs,
             rules,
-            border,
             border_config,
-            focused_monitor: 0,
-            focused_window: None,
-            focused_maximized: false,
             mouse_follows_focus,
-            focus_from_mouse: false,
-            applying_layout: false,
-            hiding: layout_config.hiding,
-            hidden_by_switch: HashSet::new(),
-            ws_switch_cooldown: None,
-            self_elevated,
-            adopt_rejected: HashSet::new(),
+            monitors,
+        );
+        state.set_custom_layouts(layouts);
+        let desktop = Win32Desktop {
+            self_elevated: crate::process::is_current_process_elevated(),
         };
 
-        for win in enumerate::enumerate_windows()? {
-            let hwnd = win.hwnd().0 as usize;
-            if manager.is_tileable(hwnd)
-                && let Some(idx) = manager.monitor_index_for(hwnd)
-            {
-                manager.monitors[idx].active_ws_mut().add(hwnd);
-                frame::set_corner_preference(win.hwnd(), manager.border_config.corner_style);
-            }
-        }
+        let mut manager = Self {
+            state,
+            desktop,
+            border: Border::new().ok(),
+            changed: false,
+        };
 
-        manager.retile_all();
-        manager.focus_initial();
+        let handles: Vec<usize> = enumerate::enumerate_windows()?
+            .iter()
+            .map(|win| win.hwnd().0 as usize)
+            .collect();
+        let effects = manager.state.adopt_existing(&manager.desktop, &handles);
+        manager.execute(effects);
         Ok(manager)
     }
 
     /// Executes a user-triggered action.
     pub fn handle_action(&mut self, action: &Action) {
-        match action {
-            Action::Focus(dir) => {
-                self.focus_from_mouse = false;
-                self.focus_direction(*dir);
-            }
-            Action::Move(dir) => self.move_direction(*dir),
-            Action::Retile => self.retile_all(),
-            Action::ToggleMonocle => self.toggle_monocle(),
-            Action::CloseFocused => self.close_focused(),
-            Action::MinimizeFocused => self.minimize_focused(),
-            Action::CycleLayout => self.cycle_layout(),
-            Action::GoToWorkspace(n) => self.goto_workspace(*n),
-            Action::SendToWorkspace(n) => self.send_to_workspace(*n),
-            // TogglePause is handled by the daemon before reaching here.
-            Action::TogglePause => {}
-        }
+        let effects = self.state.handle_action(&self.desktop, action);
+        self.execute(effects);
+    }
+
+    /// Processes a window event and updates the layout.
+    pub fn handle_event(&mut self, event: &WindowEvent) {
+        let effects = self.state.handle_event(&self.desktop, event);
+        self.execute(effects);
+    }
+
+    /// Returns whether any effects ran since the last call, i.e. whether
+    /// the layout may have changed, and resets the flag.
+    pub fn take_changed(&mut self) -> bool {
+        std::mem::take(&mut self.changed)
     }
 
     /// Returns the total number of managed windows.
     pub fn window_count(&self) -> usize {
-        self.monitors
-            .iter()
-            .flat_map(|m| &m.workspaces)
-            .map(|ws| ws.len())
-            .sum()
+        self.state.window_count()
+    }
+
+    /// Returns a typed snapshot of monitors, workspaces and windows.
+    pub fn snapshot(&self) -> mosaico_core::StateSnapshot {
+        self.state.snapshot(&self.desktop)
     }
 
     /// Returns a formatted dump of the daemon's internal workspace state.
@@ -209,12 +105,13 @@ impl TilingManager {
 
         let mut out = String::new();
 
-        for (mi, mon) in self.monitors.iter().enumerate() {
+        for (mi, mon) in self.state.monitors().iter().enumerate() {
             let _ = writeln!(
                 out,
-                "Monitor {} (id={}, work_area={}x{}+{}+{}, active_ws={}):",
+                "Monitor {} (id={}, name={}, work_area={}x{}+{}+{}, active_ws={}):",
                 mi,
                 mon.id,
+                mon.name,
                 mon.work_area.width,
                 mon.work_area.height,
                 mon.work_area.x,
@@ -233,7 +130,7 @@ impl TilingManager {
                     ws.len(),
                     if ws.len() == 1 { "" } else { "s" },
                 );
-                for &hwnd in ws.handles() {
+                for hwnd in ws.windows() {
                     let win = crate::window::Window::from_raw(hwnd);
                     let title = mosaico_core::Window::title(&win).unwrap_or_default();
                     let class = mosaico_core::Window::class(&win).unwrap_or_default();
@@ -248,16 +145,27 @@ impl TilingManager {
                     };
                     let _ = writeln!(
                         out,
-                        "    0x{:X}  visible={}  minimized={}  cloaked={}  elevated={}  rect={}  class={:?}  title={:?}",
-                        hwnd, visible, minimized, cloaked, elevated, rect_str, class, title,
+                        "    0x{:X}  floating={}  visible={}  minimized={}  cloaked={}  elevated={}  rect={}  class={:?}  title={:?}",
+                        hwnd,
+                        ws.is_floating(hwnd),
+                        visible,
+                        minimized,
+                        cloaked,
+                        elevated,
+                        rect_str,
+                        class,
+                        title,
                     );
                 }
+                if let Ok(tree) = serde_json::to_string(ws.tree()) {
+                    let _ = writeln!(out, "    tree: {tree}");
+                }
             }
         }
 
-        let _ = writeln!(out, "Self elevated: {}", self.self_elevated);
-        let _ = writeln!(out, "Focused monitor: {}", self.focused_monitor);
-        let _ = match self.focused_window {
+        let _ = writeln!(out, "Self elevated: {}", self.desktop.self_elevated);
+        let _ = writeln!(out, "Focused monitor: {}", self.state.focused_monitor());
+        let _ = match self.state.focused_window() {
             Some(h) => writeln!(out, "Focused window: 0x{:X}", h),
             None => writeln!(out, "Focused window: None"),
         };
@@ -265,20 +173,26 @@ impl TilingManager {
         out
     }
 
+    /// Returns each monitor's `bar` override from `[[monitor]]` blocks.
+    pub fn monitor_bars(&self) -> Vec<Option<bool>> {
+        self.state.monitor_bars()
+    }
+
     /// Returns a snapshot of bar state for each monitor.
     pub fn bar_states(&self, update_text: &str, paused: bool) -> Vec<BarState> {
-        self.monitors
+        self.state
+            .monitors()
             .iter()
             .enumerate()
             .map(|(i, m)| BarState {
                 active_workspace: m.active_workspace,
-                workspace_count: m.workspaces.len(),
+                workspace_labels: self.state.workspace_labels(i),
                 layout_name: m.active_ws().layout_kind().name().into(),
                 monocle: m.active_ws().monocle(),
                 cpu_usage: 0,
                 update_text: update_text.to_string(),
-                focused_hwnd: if i == self.focused_monitor {
-                    self.focused_window
+                focused_hwnd: if i == self.state.focused_monitor() {
+                    self.state.focused_window()
                 } else {
                     None
                 },
@@ -287,8 +201,87 @@ impl TilingManager {
             })
             .collect()
     }
-}
 
-#[cfg(test)]
-#[path = "tests.rs"]
-mod tests;
+    /// Adjusts monitor work areas by subtracting bar height from the top.
+    ///
+    /// Only monitors whose index appears in `bar_monitors` are adjusted.
+    /// An empty slice means all monitors.
+    pub fn adjust_work_areas_for_bar(&mut self, bar_height: i32, bar_monitors: &[usize]) {
+        let effects = self
+            .state
+            .adjust_work_areas_for_bar(&self.desktop, bar_height, bar_monitors);
+        self.execute(effects);
+    }
+
+    /// Resets work areas to the OS values, then applies the bar offset.
+    ///
+    /// Used when bar config changes to avoid accumulating offsets.
+    pub fn reset_and_adjust_work_areas(&mut self, bar_height: i32, bar_monitors: &[usize]) {
+        let os_monitors = monitor::enumerate_monitors().unwrap_or_default();
+        let effects = self.state.reset_and_adjust_work_areas(
+            &self.desktop,
+            &os_monitors,
+            bar_height,
+            bar_monitors,
+        );
+        self.execute(effects);
+    }
+
+    /// Rebuilds internal monitor state after a display configuration change.
+    pub fn handle_display_change(
+        &mut self,
+        new_monitors: Vec<MonitorInfo>,
+        bar_height: i32,
+        bar_monitor_indices: &[usize],
+    ) {
+        let effects = self.state.handle_display_change(
+            &self.desktop,
+            new_monitors,
+            bar_height,
+            bar_monitor_indices,
+        );
+        self.execute(effects);
+    }
+
+    /// Returns the current monitor IDs for debounce comparison.
+    pub fn monitor_ids(&self) -> Vec<usize> {
+        self.state.monitor_ids()
+    }
+
+    /// Shows all windows across every workspace and monitor.
+    ///
+    /// Called on daemon shutdown so that windows hidden by workspace
+    /// switching are restored and not left invisible.
+    pub fn restore_all_windows(&mut self) {
+        let effects = self.state.restore_all_windows();
+        self.execute(effects);
+    }
+
+    /// Applies a new layout and border config, then retiles all windows.
+    pub fn reload_config(&mut self, config: &Config) {
+        let effects = self.state.reload_config(&self.desktop, config);
+        self.execute(effects);
+    }
+
+    /// Replaces the window rules and removes windows that should no
+    /// longer be managed under the new rule set.
+    pub fn reload_rules(&mut self, rules: Vec<WindowRule>) {
+        let effects = self.state.reload_rules(&self.desktop, rules);
+        self.execute(effects);
+    }
+
+    /// Replaces the custom layouts and retiles all windows.
+    pub fn reload_layouts(&mut self, layouts: Vec<LayoutDefinition>) {
+        let effects = self.state.reload_layouts(&self.desktop, layouts);
+        self.execute(effects);
+    }
+
+    /// Re-positions the focus border to match the current window rect.
+    ///
+    /// Call after work areas change (e.g. bar adjustment at startup) so
+    /// the border reflects the final window position, not the pre-adjustment one.
+    pub fn refresh_border(&mut self) {
+        let effects = self.state.refresh_border();
+        self.execute(effects);
+    }
+}
@@ -285,6 +285,39 @@ impl mosaico_core::Window for Window {
         }
     }
 
+    fn exe_path(&self) -> WindowResult<String> {
+        use windows::Win32::Foundation::CloseHandle;
+        use windows::Win32::System::Threading::{
+            OpenProcess, PROCESS_NAME_WIN32, PROCESS_QUERY_LIMITED_INFORMATION,
+            QueryFullProcessImageNameW,
+        };
+        use windows::Win32::UI::WindowsAndMessaging::GetWindowThreadProcessId;
+        use windows::core::PWSTR;
+
+        // SAFETY: Queries the owning process's image name. Limited query
+        // rights are enough even for elevated processes. The process
+        // handle is closed before returning.
+        unsafe {
+            let mut pid = 0u32;
+            GetWindowThreadProcessId(self.hwnd, Some(&mut pid));
+            if pid == 0 {
+                return Err("window has no owning process".into());
+            }
+            let process = OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, false, pid)?;
+            let mut buffer = [0u16; 1024];
+            let mut length = buffer.len() as u32;
+            let result = QueryFullProcessImageNameW(
+                process,
+                PROCESS_NAME_WIN32,
+                PWSTR(buffer.as_mut_ptr()),
+                &mut length,
+            );
+            let _ = CloseHandle(process);
+            result?;
+            Ok(String::from_utf16_lossy(&buffer[..length as usize]))
+        }
+    }
+
     fn rect(&self) -> WindowResult<Rect> {
         let frame = frame::visible_rect(self.hwnd)?;
         Ok(crate::monitor::rect_from_win32(&frame))
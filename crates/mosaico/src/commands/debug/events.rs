@@ -1,10 +1,34 @@
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
 use std::sync::mpsc;
+use std::time::Instant;
 
-use mosaico_core::Window;
+use clap::Args;
+
+use mosaico_core::trace::{TraceEntry, TraceRecord, TraceWindow};
+use mosaico_core::{Rect, Window, WindowEvent};
 use mosaico_windows::Window as WinWindow;
 
+/// Arguments for the `debug events` subcommand.
+#[derive(Args)]
+pub struct EventsArgs {
+    /// Also record the events to a JSON-lines trace file for replay
+    #[arg(long, value_name = "FILE")]
+    record: Option<PathBuf>,
+}
+
 /// Watches window events in real time. Press Ctrl+C to stop.
-pub fn execute() {
+pub fn execute(args: &EventsArgs) {
+    let mut recorder = match args.record.as_deref().map(Recorder::create).transpose() {
+        Ok(recorder) => recorder,
+        Err(e) => {
+            eprintln!("Failed to start recording: {e}");
+            return;
+        }
+    };
+
     println!("Watching window events (press Ctrl+C to stop)...\n");
 
     let (tx, rx) = mpsc::channel();
@@ -37,6 +61,12 @@ pub fn execute() {
                 let window = WinWindow::from_raw(event.hwnd());
                 let title = window.title().unwrap_or_default();
                 println!("{event} \"{title}\"");
+                if let Some(rec) = recorder.as_mut()
+                    && let Err(e) = rec.record(&event)
+                {
+                    eprintln!("Recording stopped: {e}");
+                    recorder = None;
+                }
             }
             Err(mpsc::RecvTimeoutError::Timeout) => continue,
             Err(mpsc::RecvTimeoutError::Disconnected) => break,
@@ -44,4 +74,91 @@ pub fn execute() {
     }
 
     event_loop.stop();
+
+    if let Some(path) = &args.record {
+        println!("\nTrace written to {}", path.display());
+    }
+}
+
+/// Writes a replayable trace (see `mosaico_core::trace`).
+///
+/// Starts with the connected monitors and every existing window, then
+/// records each event preceded by the window's state whenever it
+/// changed, so a replay sees the same answers the daemon would have.
+struct Recorder {
+    out: BufWriter<File>,
+    start: Instant,
+    self_elevated: bool,
+    seen: HashMap<usize, TraceWindow>,
+}
+
+impl Recorder {
+    fn create(path: &Path) -> io::Result<Self> {
+        // Record physical pixels, as the daemon sees them.
+        mosaico_windows::dpi::enable_dpi_awareness();
+
+        let mut recorder = Self {
+            out: BufWriter::new(File::create(path)?),
+            start: Instant::now(),
+            self_elevated: mosaico_windows::process::is_current_process_elevated(),
+            seen: HashMap::new(),
+        };
+        recorder.write_monitors()?;
+        for window in mosaico_windows::enumerate_windows().unwrap_or_default() {
+            recorder.write_window(window.hwnd().0 as usize)?;
+        }
+        Ok(recorder)
+    }
+
+    fn record(&mut self, event: &WindowEvent) -> io::Result<()> {
+        match event {
+            WindowEvent::DisplayChanged | WindowEvent::WorkAreaChanged => {
+                self.write_monitors()?;
+            }
+            WindowEvent::Destroyed { hwnd } => {
+                self.seen.remove(hwnd);
+            }
+            other => self.write_window(other.hwnd())?,
+        }
+        self.write(TraceRecord::Event(event.clone()))
+    }
+
+    fn write_monitors(&mut self) -> io::Result<()> {
+        let monitors = mosaico_windows::monitor::enumerate_monitors().unwrap_or_default();
+        self.write(TraceRecord::Monitors { monitors })
+    }
+
+    /// Writes the window's state if it changed since it was last written.
+    fn write_window(&mut self, hwnd: usize) -> io::Result<()> {
+        let window = WinWindow::from_raw(hwnd);
+        if !window.is_valid() {
+            return Ok(());
+        }
+        let state = TraceWindow {
+            hwnd,
+            class: window.class().unwrap_or_default(),
+            title: window.title().unwrap_or_default(),
+            exe_path: window.exe_path().unwrap_or_default(),
+            rect: window.rect().unwrap_or(Rect::new(0, 0, 0, 0)),
+            visible: window.is_visible(),
+            minimized: window.is_minimized(),
+            maximized: window.is_maximized(),
+            cloaked: window.is_cloaked(),
+            // Elevated windows are unmanageable unless mosaico is elevated too.
+            app_window: window.is_app_window() && (self.self_elevated || !window.is_elevated()),
+            owner: window.owner(),
+        };
+        if self.seen.get(&hwnd) == Some(&state) {
+            return Ok(());
+        }
+        self.seen.insert(hwnd, state.clone());
+        self.write(TraceRecord::Window(state))
+    }
+
+    fn write(&mut self, record: TraceRecord) -> io::Result<()> {
+        let at_ms = self.start.elapsed().as_millis() as u64;
+        writeln!(self.out, "{}", TraceEntry::new(at_ms, record).to_line())?;
+        // Flush every line so the trace survives Ctrl+C.
+        self.out.flush()
+    }
 }
@@ -1,5 +1,5 @@
 use mosaico_core::Window;
-use mosaico_core::config::{load_merged_rules, should_manage};
+use mosaico_core::config::{WindowProps, load_merged_rules, should_manage};
 use mosaico_windows::{frame, monitor};
 
 pub fn execute() {
@@ -11,10 +11,11 @@ pub fn execute() {
     for window in &windows {
         let title = window.title().unwrap_or_default();
         let class = window.class().unwrap_or_default();
+        let exe_path = window.exe_path().unwrap_or_default();
         let rect = window.rect().unwrap_or(mosaico_core::Rect::new(0, 0, 0, 0));
         let hwnd = window.hwnd().0 as usize;
         let monitor_num = monitor_index_for(&monitors, hwnd);
-        let managed = if should_manage(&class, &title, &rules) {
+        let managed = if should_manage(&WindowProps::new(&class, &title, &exe_path), &rules) {
             "yes"
         } else {
             "no"
@@ -37,6 +38,10 @@ pub fn execute() {
 
         println!("  0x{hwnd:X}  {display_title}");
         println!("       Class: {class}");
+        println!(
+            "       Process: {}",
+            mosaico_core::window::process_name(&exe_path)
+        );
         println!("       Managed: {managed}  Monitor: {monitor_num}  State: {state}");
         println!(
             "       Rect: {}x{} at ({}, {})  Border: {border}",
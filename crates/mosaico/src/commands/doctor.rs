@@ -20,6 +20,7 @@ pub fn execute() {
     doctor_runtime::check_rules_cache_age();
     check_user_rules_file();
     check_bar_file();
+    check_layouts_file();
     doctor_runtime::check_autostart();
     doctor_runtime::check_daemon();
     doctor_runtime::check_monitors();
@@ -140,3 +141,9 @@ fn check_bar_file() {
         config::try_load_bar().map(|_| ())
     });
 }
+
+fn check_layouts_file() {
+    check_toml_file("layouts.toml", config::layouts_path(), || {
+        config::try_load_layouts().map(|_| ())
+    });
+}
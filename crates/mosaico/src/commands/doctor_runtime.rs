@@ -3,6 +3,8 @@
 //! These checks inspect live system state (daemon process, monitors)
 //! rather than static configuration files.
 
+use mosaico_core::ipc::{IpcError, PROTOCOL_VERSION};
+
 const OK: &str = "\x1b[32m[ok]\x1b[0m";
 const WARN: &str = "\x1b[33m[warn]\x1b[0m";
 const FAIL: &str = "\x1b[31m[fail]\x1b[0m";
@@ -50,6 +52,7 @@ pub fn check_daemon() {
         } else {
             println!("  {OK} Daemon is running");
         }
+        check_protocol();
         return;
     }
     if let Ok(Some(pid)) = mosaico_core::pid::read_pid_file() {
@@ -64,6 +67,27 @@ pub fn check_daemon() {
     }
 }
 
+/// Warns when the running daemon speaks another protocol version, e.g.
+/// after `mosaico update` replaced the binary but not the daemon.
+fn check_protocol() {
+    match mosaico_windows::ipc::hello() {
+        Ok(hello) if hello.protocol == PROTOCOL_VERSION => {
+            println!("  {OK} Daemon version {}", hello.daemon_version);
+        }
+        Ok(hello) => {
+            let mismatch = IpcError::VersionMismatch {
+                client: PROTOCOL_VERSION,
+                daemon: hello.protocol,
+            };
+            println!(
+                "  {WARN} Daemon version {}: {mismatch}",
+                hello.daemon_version
+            );
+        }
+        Err(e) => println!("  {WARN} Daemon did not answer the handshake: {e}"),
+    }
+}
+
 pub fn check_monitors() {
     match mosaico_windows::monitor::enumerate_monitors() {
         Ok(monitors) if monitors.is_empty() => {
@@ -72,10 +96,10 @@ pub fn check_monitors() {
         Ok(monitors) => {
             println!("  {OK} {} monitor(s) detected", monitors.len());
             for (i, m) in monitors.iter().enumerate() {
-                let wa = &m.work_area;
+                let b = &m.bounds;
                 println!(
-                    "       Monitor {i}: {}x{} at ({}, {})",
-                    wa.width, wa.height, wa.x, wa.y
+                    "       Monitor {i}: {} {}x{} at ({}, {})",
+                    m.name, b.width, b.height, b.x, b.y
                 );
             }
         }
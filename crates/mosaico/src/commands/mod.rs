@@ -7,7 +7,10 @@ pub mod doctor;
 mod doctor_runtime;
 pub mod init;
 pub mod pause;
+pub mod query;
+pub mod rules;
 pub mod start;
 pub mod status;
 pub mod stop;
+pub mod subscribe;
 pub mod update;
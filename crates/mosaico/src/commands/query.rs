@@ -0,0 +1,143 @@
+use clap::ValueEnum;
+use serde::Serialize;
+
+use mosaico_client::{Client, ClientError, IpcError};
+use mosaico_core::query::{MonitorSnapshot, WindowSnapshot, WorkspaceSnapshot};
+use mosaico_core::{Rect, StateSnapshot};
+
+/// The part of the daemon's state `mosaico query` prints.
+#[derive(Clone, Copy, ValueEnum)]
+pub enum QueryScope {
+    /// Monitors with their bounds and work areas
+    Monitors,
+    /// Workspaces on every monitor
+    Workspaces,
+    /// Managed windows on every workspace
+    Windows,
+    /// The focused monitor and window
+    Focused,
+}
+
+/// Sends a Query command to the daemon and prints the requested state.
+pub fn execute(scope: Option<QueryScope>, json: bool) {
+    let state = match Client::new().query() {
+        Ok(state) => state,
+        Err(ClientError::Ipc(IpcError::NotRunning)) => {
+            eprintln!("Mosaico is not running.");
+            std::process::exit(1);
+        }
+        Err(ClientError::Daemon { message, .. }) => {
+            eprintln!("Error: {message}");
+            std::process::exit(1);
+        }
+        Err(e) => {
+            eprintln!("Failed to send command: {e}");
+            std::process::exit(1);
+        }
+    };
+
+    match (scope, json) {
+        (None, true) => print_json(&state),
+        (None, false) => print_state(&state),
+        (Some(QueryScope::Monitors), true) => print_json(&state.monitors),
+        (Some(QueryScope::Monitors), false) => state.monitors.iter().for_each(print_monitor),
+        (Some(QueryScope::Workspaces), true) => print_json(&state.workspaces()),
+        (Some(QueryScope::Workspaces), false) => {
+            state.workspaces().into_iter().for_each(print_workspace);
+        }
+        (Some(QueryScope::Windows), true) => print_json(&state.windows()),
+        (Some(QueryScope::Windows), false) => state.windows().into_iter().for_each(print_window),
+        (Some(QueryScope::Focused), true) => print_json(&state.focused()),
+        (Some(QueryScope::Focused), false) => {
+            let focused = state.focused();
+            println!("Monitor {}", focused.monitor);
+            match &focused.window {
+                Some(window) => print_window(window),
+                None => println!("No focused window"),
+            }
+        }
+    }
+}
+
+fn print_json(value: &impl Serialize) {
+    match serde_json::to_string_pretty(value) {
+        Ok(json) => println!("{json}"),
+        Err(e) => {
+            eprintln!("Failed to serialize state: {e}");
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Prints every monitor with its visible and non-empty workspaces.
+fn print_state(state: &StateSnapshot) {
+    for mon in &state.monitors {
+        print_monitor(mon);
+        for ws in mon
+            .workspaces
+            .iter()
+            .filter(|ws| ws.visible || !ws.windows.is_empty())
+        {
+            print!("  ");
+            print_workspace(ws);
+            for window in &ws.windows {
+                print!("    ");
+                print_window(window);
+            }
+        }
+    }
+    match state.focused_window {
+        Some(hwnd) => println!(
+            "Focused: monitor {}, window 0x{hwnd:X}",
+            state.focused_monitor
+        ),
+        None => println!("Focused: monitor {}", state.focused_monitor),
+    }
+}
+
+fn print_monitor(mon: &MonitorSnapshot) {
+    println!(
+        "Monitor {}  {}  bounds {}  work area {}  workspace {}",
+        mon.index,
+        mon.name,
+        rect(&mon.bounds),
+        rect(&mon.work_area),
+        mon.active_workspace
+    );
+}
+
+fn print_workspace(ws: &WorkspaceSnapshot) {
+    let label = if ws.label == ws.number.to_string() {
+        String::new()
+    } else {
+        format!(" \"{}\"", ws.label)
+    };
+    println!(
+        "Monitor {} workspace {}{label}  layout {}{}{}  {} window{}",
+        ws.monitor,
+        ws.number,
+        ws.layout,
+        if ws.monocle { ", monocle" } else { "" },
+        if ws.visible { ", visible" } else { "" },
+        ws.windows.len(),
+        if ws.windows.len() == 1 { "" } else { "s" },
+    );
+}
+
+fn print_window(window: &WindowSnapshot) {
+    println!(
+        "0x{:X}  monitor {} workspace {}  {}{}  [{}] {} \"{}\"",
+        window.hwnd,
+        window.monitor,
+        window.workspace,
+        window.rect.as_ref().map_or("N/A".into(), rect),
+        if window.floating { "  floating" } else { "" },
+        window.class,
+        window.process,
+        window.title
+    );
+}
+
+fn rect(r: &Rect) -> String {
+    format!("{}x{}+{}+{}", r.width, r.height, r.x, r.y)
+}
@@ -0,0 +1,132 @@
+use std::path::{Path, PathBuf};
+
+use clap::Args;
+
+use mosaico_core::config::{
+    RuleSource, SourcedRule, WindowProps, explain_rules, load_sourced_rules, parse_rule_tests,
+    sourced_rules, validate_rules,
+};
+
+/// Arguments for the `rules explain` subcommand.
+#[derive(Args)]
+pub struct ExplainArgs {
+    /// Window class name
+    #[arg(long, default_value = "")]
+    class: String,
+    /// Window title
+    #[arg(long, default_value = "")]
+    title: String,
+    /// Executable name (e.g. firefox.exe); derived from --exe-path if omitted
+    #[arg(long)]
+    process: Option<String>,
+    /// Full executable path
+    #[arg(long, default_value = "")]
+    exe_path: String,
+    /// List every rule checked before the match
+    #[arg(long, short)]
+    verbose: bool,
+}
+
+/// Arguments for the `rules test` subcommand.
+#[derive(Args)]
+pub struct TestArgs {
+    /// Fixture file with `[[case]]` entries
+    fixture: PathBuf,
+    /// Test this rules file instead of the installed user and community rules
+    #[arg(long, value_name = "FILE")]
+    rules: Option<PathBuf>,
+}
+
+/// Shows which rule decides how a window is handled, and why.
+pub fn explain(args: &ExplainArgs) {
+    let rules = load_sourced_rules();
+    let mut window = WindowProps::new(&args.class, &args.title, &args.exe_path);
+    if let Some(process) = &args.process {
+        window.process.clone_from(process);
+    }
+
+    println!(
+        "Window: class \"{}\", title \"{}\", process \"{}\"",
+        window.class, window.title, window.process
+    );
+    let explanation = explain_rules(&window, &rules);
+    if args.verbose {
+        for rule in explanation.checked {
+            println!("  no match  {}", describe(rule));
+        }
+    }
+    match explanation.matched {
+        Some(rule) => {
+            println!(
+                "Checked {} rules before the match.",
+                explanation.checked.len()
+            );
+            println!("Matched:  {}", describe(rule));
+        }
+        None => println!("No rule matched ({} rules checked).", rules.len()),
+    }
+    let outcome = match explanation.matched {
+        Some(rule) if !rule.rule.manage => "not managed",
+        Some(rule) if rule.rule.float => "managed, floating",
+        Some(_) => "managed",
+        None => "managed (default)",
+    };
+    println!("Result:   {outcome}");
+}
+
+/// Runs a fixture of windows against the rules and reports mismatches.
+///
+/// Exits with status 1 if any case fails.
+pub fn test(args: &TestArgs) {
+    let cases = match read(&args.fixture).and_then(|content| parse_rule_tests(&content)) {
+        Ok(cases) => cases,
+        Err(e) => {
+            eprintln!("{}: {e}", args.fixture.display());
+            std::process::exit(1);
+        }
+    };
+    let rules = match &args.rules {
+        Some(path) => match read(path).and_then(|content| validate_rules(&content)) {
+            Ok(rules) => sourced_rules(RuleSource::Community, rules),
+            Err(e) => {
+                eprintln!("{}: {e}", path.display());
+                std::process::exit(1);
+            }
+        },
+        None => load_sourced_rules(),
+    };
+
+    let mut failed = 0;
+    for (i, case) in cases.iter().enumerate() {
+        match case.check(&rules) {
+            Ok(()) => println!(
+                "  pass  case #{}  [{}] \"{}\"",
+                i + 1,
+                case.class,
+                case.title
+            ),
+            Err(e) => {
+                failed += 1;
+                println!(
+                    "  FAIL  case #{}  [{}] \"{}\": {e}",
+                    i + 1,
+                    case.class,
+                    case.title
+                );
+            }
+        }
+    }
+    println!("\n{} passed, {failed} failed", cases.len() - failed);
+    if failed > 0 {
+        std::process::exit(1);
+    }
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    std::fs::read_to_string(path).map_err(|e| e.to_string())
+}
+
+/// Formats a rule as `file #N  { conditions }`.
+fn describe(rule: &SourcedRule) -> String {
+    format!("{} #{}  {}", rule.source, rule.index + 1, rule.rule.matcher)
+}
@@ -1,6 +1,25 @@
+use mosaico_client::{Client, IpcError};
+use mosaico_core::ipc::PROTOCOL_VERSION;
+
 pub fn execute() {
-    if mosaico_windows::ipc::is_daemon_running() {
-        println!("Mosaico is running.");
+    let client = Client::new();
+    if client.is_running() {
+        match client.hello() {
+            Ok(hello) if hello.protocol == PROTOCOL_VERSION => {
+                println!("Mosaico is running (v{}).", hello.daemon_version);
+            }
+            Ok(hello) => {
+                let mismatch = IpcError::VersionMismatch {
+                    client: PROTOCOL_VERSION,
+                    daemon: hello.protocol,
+                };
+                println!(
+                    "Mosaico v{} is running, but {mismatch}.",
+                    hello.daemon_version
+                );
+            }
+            Err(e) => println!("Mosaico is running, but {e}."),
+        }
         return;
     }
 
@@ -1,24 +1,21 @@
-use mosaico_core::ipc::ResponseStatus;
+use mosaico_client::{Client, ClientError, IpcError};
 
 pub fn execute() {
     // Try graceful shutdown via IPC first.
-    if mosaico_windows::ipc::is_daemon_running() {
-        let command = mosaico_core::Command::Stop;
-        match mosaico_windows::ipc::send_command(&command) {
-            Ok(response) if response.status == ResponseStatus::Ok => {
-                println!("Mosaico stopped. {}", response.message.unwrap_or_default());
-                let _ = mosaico_core::pid::remove_pid_file();
-                return;
-            }
-            Ok(response) => {
-                eprintln!(
-                    "Error: {}",
-                    response.message.unwrap_or("unknown error".into())
-                );
-                return;
-            }
-            Err(e) => eprintln!("IPC failed: {e}"),
+    match Client::new().stop() {
+        Ok(message) => {
+            println!("Mosaico stopped. {}", message.unwrap_or_default());
+            let _ = mosaico_core::pid::remove_pid_file();
+            return;
+        }
+        Err(ClientError::Daemon { message, .. }) => {
+            eprintln!("Error: {message}");
+            return;
         }
+        // Nobody is listening; the PID check below tells whether the
+        // process is gone too.
+        Err(ClientError::Ipc(IpcError::NotRunning)) => {}
+        Err(e) => eprintln!("IPC failed: {e}"),
     }
 
     // Fallback: the IPC pipe is gone but the process may still be
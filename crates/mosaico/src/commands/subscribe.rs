@@ -0,0 +1,34 @@
+use mosaico_client::{Client, ClientError, IpcError};
+use mosaico_core::EventTopic;
+
+/// Streams daemon events to stdout as newline-delimited JSON.
+///
+/// Runs until the daemon stops or stdout is closed.
+pub fn execute(topics: Vec<EventTopic>) {
+    let result = Client::new().subscribe(topics, |event| {
+        serde_json::to_string(&event).is_ok_and(|json| writeln_stdout(&json))
+    });
+
+    match result {
+        Ok(()) => {}
+        Err(ClientError::Ipc(IpcError::NotRunning)) => {
+            eprintln!("Mosaico is not running.");
+            std::process::exit(1);
+        }
+        Err(e) => {
+            eprintln!("Subscription failed: {e}");
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Writes one line, returning `false` once stdout is closed (e.g. the
+/// reading end of a pipe exited).
+fn writeln_stdout(line: &str) -> bool {
+    use std::io::Write;
+
+    let mut stdout = std::io::stdout().lock();
+    writeln!(stdout, "{line}")
+        .and_then(|()| stdout.flush())
+        .is_ok()
+}
@@ -2,8 +2,8 @@ mod commands;
 
 use clap::{Parser, Subcommand};
 
-use mosaico_core::Action;
 use mosaico_core::action::Direction;
+use mosaico_core::{Action, LayoutKind, MonitorTarget, WorkspaceRef};
 
 #[derive(Parser)]
 #[command(
@@ -52,6 +52,27 @@ enum Commands {
         #[command(subcommand)]
         command: DebugCommands,
     },
+    /// Print the daemon's monitors, workspaces and windows
+    Query {
+        /// What to show (default: everything)
+        #[arg(value_enum)]
+        scope: Option<commands::query::QueryScope>,
+        /// Print JSON for scripts instead of text
+        #[arg(long)]
+        json: bool,
+    },
+    /// Stream daemon events as newline-delimited JSON
+    Subscribe {
+        /// Topics to receive (default: all): focus-changed,
+        /// workspace-changed, layout-changed, window-managed,
+        /// window-unmanaged, config-reloaded, paused
+        topics: Vec<mosaico_core::EventTopic>,
+    },
+    /// Inspect and test window rules
+    Rules {
+        #[command(subcommand)]
+        command: RulesCommands,
+    },
     /// Pause all mosaico hotkeys until unpaused
     Pause,
     /// Resume all mosaico hotkeys
@@ -91,23 +112,71 @@ enum ActionCommands {
         #[command(subcommand)]
         direction: DirectionCommands,
     },
+    /// Move the divider next to the focused window in the given direction
+    Resize {
+        #[command(subcommand)]
+        direction: DirectionCommands,
+    },
     /// Re-apply the current layout
     Retile,
     /// Toggle monocle mode (focused window fills the monitor)
     ToggleMonocle,
+    /// Toggle the focused window between tiled and floating
+    ToggleFloat,
     /// Close the currently focused window
     CloseFocused,
+    /// Add one window to the master pane
+    IncreaseMaster,
+    /// Remove one window from the master pane
+    DecreaseMaster,
+    /// Swap the focused window into the master slot
+    PromoteToMaster,
     /// Cycle to the next layout on the focused workspace
     CycleLayout,
-    /// Switch to workspace N (1-8) on the focused monitor
+    /// Cycle to the previous layout on the focused workspace
+    CycleLayoutPrev,
+    /// Switch the focused workspace to the given layout
+    SetLayout {
+        /// Layout name (bsp, vertical-stack, horizontal-stack, three-column,
+        /// grid, columns, rows)
+        layout: LayoutKind,
+    },
+    /// Switch to a workspace on the focused monitor
     GoToWorkspace {
-        /// Workspace number (1-8)
-        n: u8,
+        /// Workspace number (1-255) or configured name
+        workspace: WorkspaceRef,
     },
-    /// Send the focused window to workspace N (1-8)
+    /// Switch to the next workspace on the focused monitor
+    GoToWorkspaceNext,
+    /// Switch to the previous workspace on the focused monitor
+    GoToWorkspacePrev,
+    /// Switch back to the previously visible workspace
+    GoToWorkspaceLast,
+    /// Send the focused window to a workspace
     SendToWorkspace {
-        /// Workspace number (1-8)
-        n: u8,
+        /// Workspace number (1-255) or configured name
+        workspace: WorkspaceRef,
+    },
+    /// Send the focused window to another monitor
+    SendToMonitor {
+        /// Direction (left, right, up, down) or monitor number (1, 2, ...)
+        monitor: MonitorTarget,
+        /// Workspace on that monitor (defaults to its visible one)
+        #[arg(long)]
+        workspace: Option<WorkspaceRef>,
+        /// Move focus along with the window
+        #[arg(long)]
+        follow: bool,
+    },
+    /// Move the visible workspace to another monitor
+    MoveWorkspaceToMonitor {
+        /// Direction (left, right, up, down) or monitor number (1, 2, ...)
+        monitor: MonitorTarget,
+    },
+    /// Swap the visible workspace with the neighboring monitor's
+    SwapWorkspacesWithMonitor {
+        #[command(subcommand)]
+        direction: DirectionCommands,
     },
 }
 
@@ -116,13 +185,21 @@ enum DebugCommands {
     /// List all visible windows
     List,
     /// Watch window events in real time
-    Events,
+    Events(commands::debug::events::EventsArgs),
     /// Move a window to a specific position and size
     Move(commands::debug::move_window::MoveArgs),
     /// Dump the daemon's internal workspace state
     State,
 }
 
+#[derive(Subcommand)]
+enum RulesCommands {
+    /// Show which rule matches a window and why
+    Explain(commands::rules::ExplainArgs),
+    /// Check the rules against a fixture of windows and expected outcomes
+    Test(commands::rules::TestArgs),
+}
+
 #[derive(Subcommand)]
 enum AutostartCommands {
     /// Enable autostart on Windows boot
@@ -165,20 +242,51 @@ fn main() {
             let action = match action {
                 ActionCommands::Focus { direction: d } => Action::Focus(direction(d)),
                 ActionCommands::Move { direction: d } => Action::Move(direction(d)),
+                ActionCommands::Resize { direction: d } => Action::Resize(direction(d)),
                 ActionCommands::Retile => Action::Retile,
                 ActionCommands::ToggleMonocle => Action::ToggleMonocle,
+                ActionCommands::ToggleFloat => Action::ToggleFloat,
                 ActionCommands::CloseFocused => Action::CloseFocused,
+                ActionCommands::IncreaseMaster => Action::IncreaseMaster,
+                ActionCommands::DecreaseMaster => Action::DecreaseMaster,
+                ActionCommands::PromoteToMaster => Action::PromoteToMaster,
                 ActionCommands::CycleLayout => Action::CycleLayout,
-                ActionCommands::GoToWorkspace { n } => Action::GoToWorkspace(n),
-                ActionCommands::SendToWorkspace { n } => Action::SendToWorkspace(n),
+                ActionCommands::CycleLayoutPrev => Action::CycleLayoutPrev,
+                ActionCommands::SetLayout { layout } => Action::SetLayout(layout),
+                ActionCommands::GoToWorkspace { workspace } => Action::GoToWorkspace(workspace),
+                ActionCommands::GoToWorkspaceNext => Action::GoToWorkspaceNext,
+                ActionCommands::GoToWorkspacePrev => Action::GoToWorkspacePrev,
+                ActionCommands::GoToWorkspaceLast => Action::GoToWorkspaceLast,
+                ActionCommands::SendToWorkspace { workspace } => Action::SendToWorkspace(workspace),
+                ActionCommands::SendToMonitor {
+                    monitor,
+                    workspace,
+                    follow,
+                } => Action::SendToMonitor {
+                    monitor,
+                    workspace,
+                    follow,
+                },
+                ActionCommands::MoveWorkspaceToMonitor { monitor } => {
+                    Action::MoveWorkspaceToMonitor(monitor)
+                }
+                ActionCommands::SwapWorkspacesWithMonitor { direction: d } => {
+                    Action::SwapWorkspacesWithMonitor(direction(d))
+                }
             };
             commands::action::execute(action);
         }
         Commands::Debug { command } => match command {
             DebugCommands::List => commands::debug::list::execute(),
-            DebugCommands::Events => commands::debug::events::execute(),
+            DebugCommands::Events(args) => commands::debug::events::execute(&args),
             DebugCommands::Move(args) => commands::debug::move_window::execute(&args),
             DebugCommands::State => commands::debug::state::execute(),
         },
+        Commands::Query { scope, json } => commands::query::execute(scope, json),
+        Commands::Subscribe { topics } => commands::subscribe::execute(topics),
+        Commands::Rules { command } => match command {
+            RulesCommands::Explain(args) => commands::rules::explain(&args),
+            RulesCommands::Test(args) => commands::rules::test(&args),
+        },
     }
 }